@@ -14,6 +14,12 @@ set -e
   echo '```'
 ) >src/components/CheckSiteHelp.md
 
+(
+  echo '```text'
+  cargo run -- check-dir --help
+  echo '```'
+) >src/components/CheckDirHelp.md
+
 (
   echo '```text'
   cargo run -- check --help
@@ -4,15 +4,16 @@ extern crate alloc;
 
 mod entry;
 mod error;
+mod source_size;
 
-pub use self::{entry::Entry, error::CssError};
+pub use self::{entry::Entry, error::CssError, source_size::is_source_size_list};
 use alloc::sync::Arc;
 use core::{convert::Infallible, str};
 use itertools::Itertools;
 use lightningcss::{
     error::{Error, ParserError},
     rules::CssRule,
-    stylesheet::{ParserOptions, StyleSheet},
+    stylesheet::{ParserOptions, StyleAttribute, StyleSheet},
     values::url::Url,
     visit_types,
     visitor::{Visit, VisitTypes, Visitor},
@@ -50,16 +51,55 @@ pub fn parse(source: &[u8]) -> Result<(Vec<Entry>, Vec<String>), CssError> {
                 !url.is_empty() && !url.starts_with('#')
             })
             .collect(),
-        warnings
-            .read()?
-            .iter()
-            .map(format_error)
-            .unique()
-            .sorted()
+        format_warnings(&warnings)?,
+    ))
+}
+
+/// Extracts URLs from a declaration list, such as a `style` attribute, together
+/// with syntax error messages.
+///
+/// Invalid declarations are skipped. Unlike [`parse`], fragment-only URLs are
+/// kept as they refer to elements in documents containing the declarations.
+pub fn parse_declarations(source: &str) -> Result<(Vec<String>, Vec<String>), CssError> {
+    let warnings = Arc::new(RwLock::new(vec![]));
+    let mut attribute = StyleAttribute::parse(
+        source,
+        ParserOptions {
+            error_recovery: true,
+            warnings: Some(warnings.clone()),
+            ..Default::default()
+        },
+    )
+    .map_err(|error| CssError::Syntax(format_error(&error)))?;
+    let mut visitor = UrlVisitor::default();
+
+    attribute.visit(&mut visitor)?;
+
+    Ok((
+        visitor
+            .entries
+            .into_iter()
+            .filter_map(|entry| match entry {
+                Entry::Import(_) => None,
+                Entry::Url(url) => (!url.is_empty()).then_some(url),
+            })
             .collect(),
+        format_warnings(&warnings)?,
     ))
 }
 
+fn format_warnings(
+    warnings: &RwLock<Vec<Error<ParserError<'_>>>>,
+) -> Result<Vec<String>, CssError> {
+    Ok(warnings
+        .read()?
+        .iter()
+        .map(format_error)
+        .unique()
+        .sorted()
+        .collect())
+}
+
 fn format_error(error: &Error<ParserError<'_>>) -> String {
     if let Some(location) = &error.loc {
         format!(
@@ -294,4 +334,41 @@ mod tests {
             "invalid utf-8 sequence of 1 bytes from index 6"
         );
     }
+
+    #[test]
+    fn parse_declarations_with_urls() {
+        assert_eq!(
+            parse_declarations(
+                "background: url(foo.png); fill: url(#bar); clip-path: url(#baz); \
+                 marker-end: url(\"#qux\")"
+            )
+            .unwrap()
+            .0,
+            vec![
+                "foo.png".to_owned(),
+                "#bar".to_owned(),
+                "#baz".to_owned(),
+                "#qux".to_owned()
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_declarations_with_unknown_property() {
+        assert_eq!(
+            parse_declarations("foo: url(#bar)").unwrap(),
+            (vec!["#bar".to_owned()], vec![])
+        );
+    }
+
+    #[test]
+    fn parse_declarations_with_invalid_declaration() {
+        assert_eq!(
+            parse_declarations("fill: url(#foo); color red; mask: url(#bar)").unwrap(),
+            (
+                vec!["#foo".to_owned(), "#bar".to_owned()],
+                vec!["Unexpected token Ident(\"red\") at 1:23".to_owned()]
+            )
+        );
+    }
 }
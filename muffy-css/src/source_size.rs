@@ -0,0 +1,136 @@
+use cssparser::{Delimiter, Parser, ParserInput};
+use lightningcss::{
+    media_query::MediaCondition,
+    stylesheet::ParserOptions,
+    traits::{Parse, ParseWithOptions, TrySign},
+    values::length::Length,
+};
+
+const AUTO_KEYWORD: &str = "auto";
+
+/// Returns `true` if a value is a valid source size list of a `sizes`
+/// attribute.
+///
+/// Every source size but the last one has a media condition. The list can start
+/// with the `auto` keyword.
+pub fn is_source_size_list(source: &str) -> bool {
+    let mut input = ParserInput::new(source);
+    let mut parser = Parser::new(&mut input);
+    let options = ParserOptions::default();
+    let mut sizes = vec![];
+
+    loop {
+        let Ok(size) = parser.parse_until_before(Delimiter::Comma, |parser| {
+            Ok(
+                if sizes.is_empty()
+                    && parser
+                        .try_parse(|parser| parser.expect_ident_matching(AUTO_KEYWORD))
+                        .is_ok()
+                {
+                    Size::Auto
+                } else {
+                    let condition = parser
+                        .try_parse(|parser| MediaCondition::parse_with_options(parser, &options))
+                        .is_ok();
+                    let length = Length::parse(parser)?;
+
+                    if length.is_sign_negative() {
+                        Size::Invalid
+                    } else if condition {
+                        Size::Conditional
+                    } else {
+                        Size::Unconditional
+                    }
+                },
+            )
+        }) else {
+            return false;
+        };
+
+        sizes.push(size);
+
+        if parser.next().is_err() {
+            break;
+        }
+    }
+
+    match sizes.as_slice() {
+        [Size::Auto] => true,
+        [Size::Auto, sizes @ ..] | sizes => match sizes {
+            [conditional @ .., Size::Unconditional] => conditional
+                .iter()
+                .all(|size| matches!(size, Size::Conditional)),
+            _ => false,
+        },
+    }
+}
+
+enum Size {
+    Auto,
+    Conditional,
+    Invalid,
+    Unconditional,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_length() {
+        assert!(is_source_size_list("100vw"));
+        assert!(is_source_size_list("500px"));
+        assert!(is_source_size_list("calc(100vw - 2em)"));
+    }
+
+    #[test]
+    fn validate_media_conditions() {
+        assert!(is_source_size_list("(max-width: 600px) 480px, 800px"));
+        assert!(is_source_size_list(
+            "(min-width: 1000px) and (orientation: landscape) 50vw, (width < 500px) 100vw, 33vw"
+        ));
+        assert!(is_source_size_list("not (min-width: 600px) 100vw, 50vw"));
+    }
+
+    #[test]
+    fn validate_auto() {
+        assert!(is_source_size_list("auto"));
+        assert!(is_source_size_list("auto, (max-width: 600px) 480px, 800px"));
+        assert!(!is_source_size_list("100vw, auto"));
+    }
+
+    #[test]
+    fn reject_empty_list() {
+        assert!(!is_source_size_list(""));
+        assert!(!is_source_size_list(" "));
+    }
+
+    #[test]
+    fn reject_invalid_media_condition() {
+        assert!(!is_source_size_list("(max-width 600px) 480px, 800px"));
+        assert!(!is_source_size_list("screen 480px, 800px"));
+    }
+
+    #[test]
+    fn reject_missing_media_condition() {
+        assert!(!is_source_size_list("480px, 800px"));
+    }
+
+    #[test]
+    fn reject_media_condition_of_last_size() {
+        assert!(!is_source_size_list("(max-width: 600px) 480px"));
+    }
+
+    #[test]
+    fn reject_invalid_length() {
+        assert!(!is_source_size_list("50%"));
+        assert!(!is_source_size_list("-100px"));
+        assert!(!is_source_size_list("(max-width: 600px) foo, 800px"));
+        assert!(!is_source_size_list("100vw 200px"));
+    }
+
+    #[test]
+    fn reject_trailing_comma() {
+        assert!(!is_source_size_list("(max-width: 600px) 480px, 800px,"));
+    }
+}
@@ -1,10 +1,14 @@
 //! Documents.
 
+mod comment;
 mod element;
 mod namespace;
 mod node;
+mod parse_error;
+mod span;
 
-pub use self::{element::*, namespace::*, node::*};
+pub use self::{comment::*, element::*, namespace::*, node::*, parse_error::*, span::*};
+use crate::dom::Dom;
 use alloc::sync::Arc;
 use core::ops::Deref;
 use markup5ever_rcdom::NodeData;
@@ -13,7 +17,7 @@ use markup5ever_rcdom::NodeData;
 #[derive(Debug, Eq, PartialEq)]
 pub struct Document {
     children: Vec<Arc<Node>>,
-    errors: Vec<String>,
+    errors: Vec<ParseError>,
 }
 
 impl Document {
@@ -31,12 +35,12 @@ impl Document {
     }
 
     /// Returns parse errors.
-    pub fn errors(&self) -> impl Iterator<Item = &str> {
-        self.errors.iter().map(String::as_str)
+    pub fn errors(&self) -> impl Iterator<Item = &ParseError> {
+        self.errors.iter()
     }
 
     /// Sets parse errors.
-    pub fn set_errors(mut self, errors: Vec<String>) -> Self {
+    pub fn set_errors(mut self, errors: Vec<ParseError>) -> Self {
         self.errors = errors;
         self
     }
@@ -61,14 +65,16 @@ impl Document {
         }
     }
 
-    pub(crate) fn from_markup5ever(node: &markup5ever_rcdom::Node) -> Self {
+    pub(crate) fn from_markup5ever(dom: &Dom) -> Self {
+        let node = dom.document();
+
         debug_assert!(matches!(node.data, NodeData::Document));
 
         Self::new(
             node.children
                 .borrow()
                 .iter()
-                .flat_map(|node| Node::from_markup5ever(node))
+                .flat_map(|node| Node::from_markup5ever(node, dom))
                 .map(Arc::new)
                 .collect(),
         )
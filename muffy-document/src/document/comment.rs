@@ -0,0 +1,44 @@
+use super::{node::Node, span::Span};
+
+/// A comment.
+#[derive(Debug, Eq)]
+pub struct Comment {
+    text: String,
+    span: Option<Span>,
+}
+
+impl Comment {
+    /// Creates a comment.
+    pub const fn new(text: String) -> Self {
+        Self { text, span: None }
+    }
+
+    /// Returns a text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns a span in a source.
+    pub const fn span(&self) -> Option<Span> {
+        self.span
+    }
+
+    /// Sets a span in a source.
+    pub const fn set_span(mut self, span: Option<Span>) -> Self {
+        self.span = span;
+        self
+    }
+}
+
+// Spans are excluded as they are not part of document structures.
+impl PartialEq for Comment {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+    }
+}
+
+impl From<Comment> for Node {
+    fn from(comment: Comment) -> Self {
+        Self::Comment(comment)
+    }
+}
@@ -1,14 +1,15 @@
-use super::node::Node;
+use super::{node::Node, span::Span};
 use alloc::sync::Arc;
 use core::ops::Deref;
 
 /// An element.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq)]
 pub struct Element {
     name: String,
     namespace: Option<String>,
     attributes: Vec<(String, String)>,
     children: Vec<Arc<Node>>,
+    span: Option<Span>,
 }
 
 impl Element {
@@ -23,6 +24,7 @@ impl Element {
             namespace: None,
             attributes,
             children,
+            span: None,
         }
     }
 
@@ -53,6 +55,27 @@ impl Element {
     pub fn children(&self) -> impl Iterator<Item = &Node> {
         self.children.iter().map(Deref::deref)
     }
+
+    /// Returns a span of a start tag in a source.
+    pub const fn span(&self) -> Option<Span> {
+        self.span
+    }
+
+    /// Sets a span of a start tag in a source.
+    pub const fn set_span(mut self, span: Option<Span>) -> Self {
+        self.span = span;
+        self
+    }
+}
+
+// Spans are excluded as they are not part of document structures.
+impl PartialEq for Element {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.namespace == other.namespace
+            && self.attributes == other.attributes
+            && self.children == other.children
+    }
 }
 
 impl From<Element> for Node {
@@ -27,6 +27,13 @@ pub fn namespace_prefix(namespace: &str) -> Option<&'static str> {
         .find_map(|(other, prefix)| (*other == namespace).then_some(*prefix))
 }
 
+/// Returns a namespace of a canonical prefix.
+pub fn prefix_namespace(prefix: &str) -> Option<&'static str> {
+    NAMESPACE_PREFIXES
+        .iter()
+        .find_map(|(namespace, other)| (*other == prefix).then_some(*namespace))
+}
+
 pub(crate) fn qualify_element_name(name: &QualName) -> String {
     qualify_name(name, DEFAULT_NAMESPACES)
 }
@@ -79,4 +86,13 @@ mod tests {
     fn resolve_no_prefix_of_unknown_namespace() {
         assert_eq!(namespace_prefix("http://foo.example/"), None);
     }
+
+    #[test]
+    fn resolve_namespace_of_canonical_prefix() {
+        assert_eq!(
+            prefix_namespace("xlink"),
+            Some("http://www.w3.org/1999/xlink")
+        );
+        assert_eq!(prefix_namespace("foo"), None);
+    }
 }
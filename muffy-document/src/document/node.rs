@@ -1,14 +1,18 @@
 use super::{
+    comment::Comment,
     element::Element,
     namespace::{qualify_attribute_name, qualify_element_name},
 };
+use crate::dom::Dom;
 use alloc::sync::Arc;
 use html5ever::ns;
-use markup5ever_rcdom::NodeData;
+use markup5ever_rcdom::{Handle, NodeData};
 
 /// A node.
 #[derive(Debug, Eq, PartialEq)]
 pub enum Node {
+    /// A comment.
+    Comment(Comment),
     /// An element.
     Element(Element),
     /// A text.
@@ -16,7 +20,7 @@ pub enum Node {
 }
 
 impl Node {
-    pub(crate) fn from_markup5ever(node: &markup5ever_rcdom::Node) -> Option<Self> {
+    pub(crate) fn from_markup5ever(node: &Handle, dom: &Dom) -> Option<Self> {
         match &node.data {
             NodeData::Element { name, attrs, .. } => Some(Self::Element(
                 Element::new(
@@ -37,15 +41,18 @@ impl Node {
                     node.children
                         .borrow()
                         .iter()
-                        .flat_map(|node| Self::from_markup5ever(node))
+                        .flat_map(|node| Self::from_markup5ever(node, dom))
                         .map(Arc::new)
                         .collect(),
                 )
-                .set_namespace((!name.ns.is_empty()).then(|| name.ns.to_string())),
+                .set_namespace((!name.ns.is_empty()).then(|| name.ns.to_string()))
+                .set_span(dom.span(node)),
             )),
             NodeData::Text { contents } => Some(Self::Text(contents.borrow().to_string())),
-            NodeData::Comment { .. }
-            | NodeData::Document
+            NodeData::Comment { contents } => Some(Self::Comment(
+                Comment::new(contents.to_string()).set_span(dom.span(node)),
+            )),
+            NodeData::Document
             | NodeData::Doctype { .. }
             | NodeData::ProcessingInstruction { .. } => None,
         }
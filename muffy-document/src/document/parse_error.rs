@@ -0,0 +1,61 @@
+use core::fmt::{self, Display, Formatter};
+
+/// A parse error.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ParseError {
+    line: Option<usize>,
+    message: String,
+}
+
+impl ParseError {
+    /// Creates a parse error.
+    pub const fn new(message: String) -> Self {
+        Self {
+            line: None,
+            message,
+        }
+    }
+
+    /// Returns a message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns a line number starting from 1.
+    pub const fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    /// Sets a line number.
+    pub const fn set_line(mut self, line: Option<usize>) -> Self {
+        self.line = line;
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "{}", self.message)
+    }
+}
+
+impl From<&str> for ParseError {
+    fn from(message: &str) -> Self {
+        Self::new(message.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn display() {
+        assert_eq!(ParseError::from("foo").to_string(), "foo");
+        assert_eq!(
+            ParseError::from("foo").set_line(Some(42)).to_string(),
+            "foo"
+        );
+    }
+}
@@ -0,0 +1,55 @@
+/// A position in a source.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Position {
+    line: usize,
+    column: usize,
+}
+
+impl Position {
+    /// Creates a position.
+    pub const fn new(line: usize, column: usize) -> Self {
+        Self { line, column }
+    }
+
+    /// Returns a line number starting from 1.
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns a column number in characters starting from 1.
+    pub const fn column(&self) -> usize {
+        self.column
+    }
+}
+
+impl Default for Position {
+    fn default() -> Self {
+        Self::new(1, 1)
+    }
+}
+
+/// A span of a start tag in a source.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Span {
+    start: Position,
+    end: Position,
+}
+
+impl Span {
+    /// Creates a span.
+    pub const fn new(start: Position, end: Position) -> Self {
+        Self { start, end }
+    }
+
+    /// Returns a start position.
+    pub const fn start(&self) -> Position {
+        self.start
+    }
+
+    /// Returns an end position exclusive of the span.
+    pub const fn end(&self) -> Position {
+        self.end
+    }
+}
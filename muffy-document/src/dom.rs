@@ -0,0 +1,458 @@
+use crate::document::{ParseError, Position, Span};
+use alloc::{borrow::Cow, rc::Rc};
+use core::cell::{Cell, RefCell};
+use html5ever::{
+    Attribute, ExpandedName, LocalName, QualName, local_name, ns,
+    tendril::StrTendril,
+    tree_builder::{ElementFlags, NodeOrText, QuirksMode, TreeSink},
+};
+use markup5ever_rcdom::{Handle, Node, RcDom};
+use std::collections::HashMap;
+
+// Elements whose contents are not parsed as markup in HTML.
+const RAW_TEXT_ELEMENTS: &[&str] = &[
+    "iframe",
+    "noembed",
+    "noframes",
+    "noscript",
+    "plaintext",
+    "script",
+    "style",
+    "textarea",
+    "title",
+    "xmp",
+];
+
+/// A DOM that records source spans of elements and comments.
+pub(crate) struct Dom<'a> {
+    dom: RcDom,
+    locator: RefCell<Locator<'a>>,
+    line: Cell<Option<u64>>,
+    tokens: bool,
+    start_tag: RefCell<Option<StartTag>>,
+    errors: RefCell<Vec<ParseError>>,
+    spans: RefCell<HashMap<*const Node, (Handle, Span)>>,
+}
+
+// A start tag token being processed and the last element created from it.
+struct StartTag {
+    name: LocalName,
+    element: Option<(QualName, Handle)>,
+}
+
+impl<'a> Dom<'a> {
+    pub fn new(source: &'a [u8]) -> Self {
+        Self {
+            dom: Default::default(),
+            locator: Locator::new(source).into(),
+            line: Default::default(),
+            tokens: false,
+            start_tag: Default::default(),
+            errors: Default::default(),
+            spans: Default::default(),
+        }
+    }
+
+    // HTML parsers notify lines only when they change from the first line.
+    pub fn set_line(self, line: u64) -> Self {
+        self.line.set(Some(line));
+        self
+    }
+
+    // Locates only elements created from start tag tokens notified by
+    // `start_tag` and `end_start_tag`. Otherwise, every element is located on
+    // its creation.
+    pub const fn track_tokens(mut self) -> Self {
+        self.tokens = true;
+        self
+    }
+
+    pub fn start_tag(&self, name: LocalName) {
+        *self.start_tag.borrow_mut() = Some(StartTag {
+            name,
+            element: None,
+        });
+    }
+
+    // Parsers create implied elements and clones of formatting elements before
+    // an element of a start tag. So the last element with the same name is the
+    // one created from the start tag.
+    pub fn end_start_tag(&self) {
+        if let Some(StartTag {
+            element: Some((name, handle)),
+            ..
+        }) = self.start_tag.take()
+            && let Some(span) = self.locator.borrow_mut().locate(&name, self.line.get())
+        {
+            self.insert_span(handle, span);
+        }
+    }
+
+    pub const fn document(&self) -> &Handle {
+        &self.dom.document
+    }
+
+    pub fn errors(&self) -> Vec<ParseError> {
+        self.errors.borrow().clone()
+    }
+
+    pub fn span(&self, node: &Handle) -> Option<Span> {
+        self.spans
+            .borrow()
+            .get(&Rc::as_ptr(node))
+            .map(|(_, span)| *span)
+    }
+
+    fn insert_span(&self, handle: Handle, span: Span) {
+        self.spans
+            .borrow_mut()
+            .insert(Rc::as_ptr(&handle), (handle, span));
+    }
+}
+
+impl TreeSink for Dom<'_> {
+    type Handle = Handle;
+    type Output = Self;
+    type ElemName<'b>
+        = ExpandedName<'b>
+    where
+        Self: 'b;
+
+    fn finish(self) -> Self {
+        self
+    }
+
+    fn parse_error(&self, message: Cow<'static, str>) {
+        self.errors.borrow_mut().push(
+            ParseError::new(message.into()).set_line(self.line.get().map(|line| line as usize)),
+        );
+    }
+
+    fn get_document(&self) -> Handle {
+        self.dom.get_document()
+    }
+
+    fn elem_name<'b>(&'b self, target: &'b Handle) -> ExpandedName<'b> {
+        self.dom.elem_name(target)
+    }
+
+    fn create_element(
+        &self,
+        name: QualName,
+        attributes: Vec<Attribute>,
+        flags: ElementFlags,
+    ) -> Handle {
+        let handle = self.dom.create_element(name.clone(), attributes, flags);
+
+        if !self.tokens {
+            if let Some(span) = self.locator.borrow_mut().locate(&name, self.line.get()) {
+                self.insert_span(handle.clone(), span);
+            }
+        } else if let Some(start_tag) = self.start_tag.borrow_mut().as_mut()
+            && Locator::matches_name(&name, start_tag.name.as_bytes())
+        {
+            start_tag.element = Some((name, handle.clone()));
+        }
+
+        handle
+    }
+
+    fn create_comment(&self, text: StrTendril) -> Handle {
+        let span = self.locator.borrow_mut().locate_comment();
+        let handle = self.dom.create_comment(text);
+
+        if let Some(span) = span {
+            self.insert_span(handle.clone(), span);
+        }
+
+        handle
+    }
+
+    fn create_pi(&self, target: StrTendril, data: StrTendril) -> Handle {
+        self.dom.create_pi(target, data)
+    }
+
+    fn append(&self, parent: &Handle, child: NodeOrText<Handle>) {
+        self.dom.append(parent, child);
+    }
+
+    fn append_based_on_parent_node(
+        &self,
+        element: &Handle,
+        previous_element: &Handle,
+        child: NodeOrText<Handle>,
+    ) {
+        self.dom
+            .append_based_on_parent_node(element, previous_element, child);
+    }
+
+    fn append_doctype_to_document(
+        &self,
+        name: StrTendril,
+        public_id: StrTendril,
+        system_id: StrTendril,
+    ) {
+        self.dom
+            .append_doctype_to_document(name, public_id, system_id);
+    }
+
+    fn mark_script_already_started(&self, node: &Handle) {
+        self.dom.mark_script_already_started(node);
+    }
+
+    fn pop(&self, node: &Handle) {
+        self.dom.pop(node);
+    }
+
+    fn get_template_contents(&self, target: &Handle) -> Handle {
+        self.dom.get_template_contents(target)
+    }
+
+    fn same_node(&self, one: &Handle, other: &Handle) -> bool {
+        self.dom.same_node(one, other)
+    }
+
+    fn set_quirks_mode(&self, mode: QuirksMode) {
+        self.dom.set_quirks_mode(mode);
+    }
+
+    fn append_before_sibling(&self, sibling: &Handle, node: NodeOrText<Handle>) {
+        self.dom.append_before_sibling(sibling, node);
+    }
+
+    fn add_attrs_if_missing(&self, target: &Handle, attributes: Vec<Attribute>) {
+        self.dom.add_attrs_if_missing(target, attributes);
+    }
+
+    fn associate_with_form(
+        &self,
+        target: &Handle,
+        form: &Handle,
+        nodes: (&Handle, Option<&Handle>),
+    ) {
+        self.dom.associate_with_form(target, form, nodes);
+    }
+
+    fn remove_from_parent(&self, target: &Handle) {
+        self.dom.remove_from_parent(target);
+    }
+
+    fn reparent_children(&self, node: &Handle, parent: &Handle) {
+        self.dom.reparent_children(node, parent);
+    }
+
+    fn is_mathml_annotation_xml_integration_point(&self, handle: &Handle) -> bool {
+        self.dom.is_mathml_annotation_xml_integration_point(handle)
+    }
+
+    fn set_current_line(&self, line: u64) {
+        self.line.set(Some(line));
+    }
+
+    fn allow_declarative_shadow_roots(&self, parent: &Handle) -> bool {
+        self.dom.allow_declarative_shadow_roots(parent)
+    }
+
+    fn attach_declarative_shadow(
+        &self,
+        location: &Handle,
+        template: &Handle,
+        attributes: &[Attribute],
+    ) -> bool {
+        self.dom
+            .attach_declarative_shadow(location, template, attributes)
+    }
+
+    fn maybe_clone_an_option_into_selectedcontent(&self, option: &Handle) {
+        self.dom.maybe_clone_an_option_into_selectedcontent(option);
+    }
+}
+
+// Parsers create elements in the order of their start tags in sources. So we
+// scan start tags forward to locate the elements.
+struct Locator<'a> {
+    source: &'a [u8],
+    offset: usize,
+    position: Position,
+    comment_offset: usize,
+    comment_position: Position,
+}
+
+impl<'a> Locator<'a> {
+    fn new(source: &'a [u8]) -> Self {
+        Self {
+            source,
+            offset: 0,
+            position: Default::default(),
+            comment_offset: 0,
+            comment_position: Default::default(),
+        }
+    }
+
+    // Comments have their own cursor so that comments not found in a source,
+    // such as bogus ones, never make us skip start tags of elements.
+    fn locate_comment(&mut self) -> Option<Span> {
+        let (mut offset, mut position) = if self.comment_offset > self.offset {
+            (self.comment_offset, self.comment_position)
+        } else {
+            (self.offset, self.position)
+        };
+
+        loop {
+            let index = offset
+                + self.source[offset..]
+                    .iter()
+                    .position(|&byte| byte == b'<')?;
+            position = self.advance(position, offset, index);
+            offset = index;
+
+            let rest = &self.source[offset..];
+            let end = if rest.starts_with(b"<!--") {
+                let end = self.find(offset, b"-->");
+                let end_position = self.advance(position, offset, end);
+
+                self.comment_offset = end;
+                self.comment_position = end_position;
+
+                return Some(Span::new(position, end_position));
+            } else if rest.starts_with(b"<![CDATA[") {
+                self.find(offset, b"]]>")
+            } else {
+                offset + 1
+            };
+
+            position = self.advance(position, offset, end);
+            offset = end;
+        }
+    }
+
+    // A line limit is a line where a start tag of an element ends. Implied
+    // elements without start tags are not found before the line.
+    fn locate(&mut self, name: &QualName, line: Option<u64>) -> Option<Span> {
+        let mut offset = self.offset;
+        let mut position = self.position;
+
+        loop {
+            let index = offset
+                + self.source[offset..]
+                    .iter()
+                    .position(|&byte| byte == b'<')?;
+            position = self.advance(position, offset, index);
+            offset = index;
+
+            if line.is_some_and(|line| position.line() as u64 > line) {
+                return None;
+            }
+
+            let rest = &self.source[offset..];
+            let skipped = if rest.starts_with(b"<!--") {
+                Some(self.find(offset, b"-->"))
+            } else if rest.starts_with(b"<![CDATA[") {
+                Some(self.find(offset, b"]]>"))
+            } else if rest.starts_with(b"<!") || rest.starts_with(b"<?") || rest.starts_with(b"</")
+            {
+                Some(self.find(offset, b">"))
+            } else {
+                None
+            };
+
+            if let Some(index) = skipped {
+                position = self.advance(position, offset, index);
+                offset = index;
+                continue;
+            }
+
+            let tag_name = &rest[1..][..rest[1..]
+                .iter()
+                .position(|&byte| byte.is_ascii_whitespace() || byte == b'/' || byte == b'>')
+                .unwrap_or(rest.len() - 1)];
+
+            if tag_name.is_empty() || !Self::matches_name(name, tag_name) {
+                position = self.advance(position, offset, offset + 1);
+                offset += 1;
+                continue;
+            }
+
+            let start = position;
+            let end = self.find_tag_end(offset);
+            let end_position = self.advance(position, offset, end);
+
+            self.offset = end;
+            self.position = end_position;
+
+            if name.ns == ns!(html) && RAW_TEXT_ELEMENTS.contains(&&*name.local) {
+                self.skip_raw_text(&name.local);
+            }
+
+            return Some(Span::new(start, end_position));
+        }
+    }
+
+    fn matches_name(name: &QualName, tag_name: &[u8]) -> bool {
+        let local_name = if let Some(prefix) = &name.prefix {
+            let Some(rest) = tag_name
+                .strip_prefix(prefix.as_bytes())
+                .and_then(|rest| rest.strip_prefix(b":"))
+            else {
+                return false;
+            };
+
+            rest
+        } else {
+            tag_name
+        };
+
+        local_name.eq_ignore_ascii_case(name.local.as_bytes())
+            || name.local == local_name!("image") && local_name.eq_ignore_ascii_case(b"img")
+    }
+
+    // Returns an offset just after a pattern or the end of a source.
+    fn find(&self, offset: usize, pattern: &[u8]) -> usize {
+        self.source[offset..]
+            .windows(pattern.len())
+            .position(|window| window == pattern)
+            .map_or(self.source.len(), |index| offset + index + pattern.len())
+    }
+
+    fn find_tag_end(&self, offset: usize) -> usize {
+        let mut quote = None;
+
+        for (index, &byte) in self.source.iter().enumerate().skip(offset) {
+            match (quote, byte) {
+                (None, b'"' | b'\'') => quote = Some(byte),
+                (None, b'>') => return index + 1,
+                (Some(other), _) if other == byte => quote = None,
+                _ => {}
+            }
+        }
+
+        self.source.len()
+    }
+
+    fn skip_raw_text(&mut self, name: &str) {
+        let end = self.source[self.offset..]
+            .windows(name.len() + 2)
+            .position(|window| {
+                window.starts_with(b"</") && window[2..].eq_ignore_ascii_case(name.as_bytes())
+            })
+            .map_or(self.source.len(), |index| self.offset + index);
+
+        self.position = self.advance(self.position, self.offset, end);
+        self.offset = end;
+    }
+
+    fn advance(&self, mut position: Position, start: usize, end: usize) -> Position {
+        for &byte in &self.source[start..end] {
+            position = if byte == b'\n' {
+                Position::new(position.line() + 1, 1)
+            } else if byte & 0b1100_0000 == 0b1000_0000 {
+                // Skip continuation bytes of UTF-8 characters.
+                position
+            } else {
+                Position::new(position.line(), position.column() + 1)
+            };
+        }
+
+        position
+    }
+}
@@ -1,8 +1,10 @@
 //! HTML documents.
 
-use crate::document::Document;
-use html5ever::{parse_document, tendril::TendrilSink};
-use markup5ever_rcdom::RcDom;
+mod parser;
+
+use self::parser::Parser;
+use crate::{document::Document, dom::Dom};
+use html5ever::tendril::{TendrilSink, stream::Utf8LossyDecoder};
 use std::io;
 
 /// Parses an HTML document.
@@ -11,17 +13,16 @@ pub fn parse(source: &str) -> Result<Document, io::Error> {
 }
 
 /// Parses an HTML document from bytes.
-pub fn parse_bytes(mut source: &[u8]) -> Result<Document, io::Error> {
-    parse_document(RcDom::default(), Default::default())
-        .from_utf8()
-        .read_from(&mut source)
-        .map(|dom| Document::from_markup5ever(&dom.document))
+pub fn parse_bytes(source: &[u8]) -> Result<Document, io::Error> {
+    Utf8LossyDecoder::new(Parser::new(Dom::new(source).set_line(1)))
+        .read_from(&mut { source })
+        .map(|dom| Document::from_markup5ever(&dom).set_errors(dom.errors()))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::document::{Element, Node};
+    use crate::document::{Comment, Element, Node, ParseError, Position, Span};
     use alloc::sync::Arc;
     use pretty_assertions::assert_eq;
 
@@ -51,6 +52,31 @@ mod tests {
         Arc::new(Node::Text(value.into()))
     }
 
+    fn comment(value: &str) -> Arc<Node> {
+        Arc::new(Node::Comment(Comment::new(value.into())))
+    }
+
+    fn spans<'a>(nodes: impl IntoIterator<Item = &'a Node>) -> Vec<(&'a str, Option<Span>)> {
+        nodes
+            .into_iter()
+            .flat_map(|node| match node {
+                Node::Element(element) => [(element.name(), element.span())]
+                    .into_iter()
+                    .chain(spans(element.children()))
+                    .collect(),
+                Node::Comment(comment) => vec![("#comment", comment.span())],
+                Node::Text(_) => vec![],
+            })
+            .collect()
+    }
+
+    fn span(start: (usize, usize), end: (usize, usize)) -> Option<Span> {
+        Some(Span::new(
+            Position::new(start.0, start.1),
+            Position::new(end.0, end.1),
+        ))
+    }
+
     #[test]
     fn parse_empty_string() {
         assert_eq!(
@@ -64,6 +90,7 @@ mod tests {
                     element(XHTML_NAMESPACE, "body", vec![], vec![]),
                 ],
             )])
+            .set_errors(vec![ParseError::from("Unexpected token").set_line(Some(1))])
         );
     }
 
@@ -85,6 +112,7 @@ mod tests {
                     ),
                 ],
             )])
+            .set_errors(vec![ParseError::from("Unexpected token").set_line(Some(1))])
         );
     }
 
@@ -111,6 +139,7 @@ mod tests {
                     ),
                 ],
             )])
+            .set_errors(vec![ParseError::from("Unexpected token").set_line(Some(1))])
         );
     }
 
@@ -156,11 +185,12 @@ mod tests {
                     ),
                 ],
             )])
+            .set_errors(vec![ParseError::from("Unexpected token").set_line(Some(1))])
         );
     }
 
     #[test]
-    fn ignore_comments() {
+    fn parse_comments() {
         assert_eq!(
             parse("<html><body><!-- comment --><p>Hello</p></body></html>").unwrap(),
             Document::new(vec![element(
@@ -173,10 +203,169 @@ mod tests {
                         XHTML_NAMESPACE,
                         "body",
                         vec![],
-                        vec![element(XHTML_NAMESPACE, "p", vec![], vec![text("Hello")])],
+                        vec![
+                            comment(" comment "),
+                            element(XHTML_NAMESPACE, "p", vec![], vec![text("Hello")]),
+                        ],
                     ),
                 ],
             )])
+            .set_errors(vec![ParseError::from("Unexpected token").set_line(Some(1))])
+        );
+    }
+
+    #[test]
+    fn parse_without_errors() {
+        assert_eq!(
+            parse("<!DOCTYPE html><html><body></body></html>")
+                .unwrap()
+                .errors()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn report_parse_errors() {
+        assert_eq!(
+            parse(concat!(
+                "<!DOCTYPE html><html><body>\n",
+                "<p a=\"1\" a=\"2\">\n",
+                "</span></body></html>",
+            ))
+            .unwrap()
+            .errors()
+            .collect::<Vec<_>>(),
+            vec![
+                &ParseError::from("Duplicate attribute").set_line(Some(2)),
+                &ParseError::from("Found special tag while closing generic tag").set_line(Some(3)),
+            ]
+        );
+    }
+
+    #[test]
+    fn record_element_spans() {
+        assert_eq!(
+            spans(
+                parse(concat!(
+                    "<!DOCTYPE html>\n",
+                    "<html>\n",
+                    "<body>\n",
+                    "  <p>\n",
+                    "    <a href=\"/foo\">foo</a><img\n",
+                    "      src=\"/bar.png\">\n",
+                    "  </p>\n",
+                    "</body>\n",
+                    "</html>\n",
+                ))
+                .unwrap()
+                .children()
+            ),
+            vec![
+                ("html", span((2, 1), (2, 7))),
+                ("head", None),
+                ("body", span((3, 1), (3, 7))),
+                ("p", span((4, 3), (4, 6))),
+                ("a", span((5, 5), (5, 20))),
+                ("img", span((5, 27), (6, 22))),
+            ]
+        );
+    }
+
+    #[test]
+    fn record_element_spans_after_raw_text() {
+        assert_eq!(
+            spans(
+                parse("<script>\"<a>\"</script><!-- <a> --><a>foo</a>")
+                    .unwrap()
+                    .children()
+            ),
+            vec![
+                ("html", None),
+                ("head", None),
+                ("script", span((1, 1), (1, 9))),
+                ("#comment", span((1, 23), (1, 35))),
+                ("body", None),
+                ("a", span((1, 35), (1, 38))),
+            ]
+        );
+    }
+
+    #[test]
+    fn record_element_spans_with_multi_byte_characters() {
+        assert_eq!(
+            spans(parse("<p>あいう<a>foo</a></p>").unwrap().children()),
+            vec![
+                ("html", None),
+                ("head", None),
+                ("body", None),
+                ("p", span((1, 1), (1, 4))),
+                ("a", span((1, 7), (1, 10))),
+            ]
+        );
+    }
+
+    #[test]
+    fn skip_spans_of_cloned_elements() {
+        assert_eq!(
+            spans(parse("<b><p>x</b>y</p><b>z</b>").unwrap().children()),
+            vec![
+                ("html", None),
+                ("head", None),
+                ("body", None),
+                ("b", span((1, 1), (1, 4))),
+                ("p", span((1, 4), (1, 7))),
+                ("b", None),
+                ("b", span((1, 17), (1, 20))),
+            ]
+        );
+    }
+
+    #[test]
+    fn skip_spans_of_implied_elements() {
+        assert_eq!(
+            spans(
+                parse("<table><tr></tr></table><table><tbody></tbody></table>")
+                    .unwrap()
+                    .children()
+            ),
+            vec![
+                ("html", None),
+                ("head", None),
+                ("body", None),
+                ("table", span((1, 1), (1, 8))),
+                ("tbody", None),
+                ("tr", span((1, 8), (1, 12))),
+                ("table", span((1, 25), (1, 32))),
+                ("tbody", span((1, 32), (1, 39))),
+            ]
+        );
+    }
+
+    #[test]
+    fn record_comment_spans() {
+        assert_eq!(
+            spans(
+                parse(concat!(
+                    "<!-- foo -->\n",
+                    "<p><!-- bar --><!--\n",
+                    "baz --></p>\n",
+                    "<script><!-- qux --></script>",
+                ))
+                .unwrap()
+                .children()
+            ),
+            vec![
+                ("#comment", span((1, 1), (1, 13))),
+                ("html", None),
+                ("head", None),
+                ("body", None),
+                ("p", span((2, 1), (2, 4))),
+                ("#comment", span((2, 4), (2, 16))),
+                ("#comment", span((2, 16), (3, 8))),
+                ("script", span((4, 1), (4, 9))),
+            ]
         );
     }
 }
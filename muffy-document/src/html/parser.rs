@@ -0,0 +1,84 @@
+use crate::dom::Dom;
+use alloc::borrow::Cow;
+use html5ever::{
+    TokenizerResult,
+    tendril::{StrTendril, TendrilSink, fmt::UTF8},
+    tokenizer::{BufferQueue, StartTag, TagToken, Token, TokenSink, TokenSinkResult, Tokenizer},
+    tree_builder::{TreeBuilder, TreeSink},
+};
+use markup5ever_rcdom::Handle;
+
+/// An HTML parser notifying a DOM of start tag tokens.
+///
+/// Unlike `html5ever::parse_document`, it lets the DOM distinguish elements
+/// created from start tags in a source from implied ones.
+pub(crate) struct Parser<'a> {
+    tokenizer: Tokenizer<TokenTracker<'a>>,
+    input: BufferQueue,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(dom: Dom<'a>) -> Self {
+        Self {
+            tokenizer: Tokenizer::new(
+                TokenTracker(TreeBuilder::new(dom.track_tokens(), Default::default())),
+                Default::default(),
+            ),
+            input: Default::default(),
+        }
+    }
+
+    fn feed(&self) {
+        while !matches!(self.tokenizer.feed(&self.input), TokenizerResult::Done) {}
+    }
+}
+
+impl<'a> TendrilSink<UTF8> for Parser<'a> {
+    type Output = Dom<'a>;
+
+    fn process(&mut self, tendril: StrTendril) {
+        self.input.push_back(tendril);
+        self.feed();
+    }
+
+    fn error(&mut self, message: Cow<'static, str>) {
+        self.tokenizer.sink.0.sink.parse_error(message);
+    }
+
+    fn finish(self) -> Self::Output {
+        self.feed();
+        self.tokenizer.end();
+        self.tokenizer.sink.0.sink
+    }
+}
+
+struct TokenTracker<'a>(TreeBuilder<Handle, Dom<'a>>);
+
+impl TokenSink for TokenTracker<'_> {
+    type Handle = Handle;
+
+    fn process_token(&self, token: Token, line: u64) -> TokenSinkResult<Handle> {
+        let TagToken(tag) = &token else {
+            return self.0.process_token(token, line);
+        };
+
+        if tag.kind != StartTag {
+            return self.0.process_token(token, line);
+        }
+
+        self.0.sink.start_tag(tag.name.clone());
+        let result = self.0.process_token(token, line);
+        self.0.sink.end_start_tag();
+
+        result
+    }
+
+    fn end(&self) {
+        self.0.end();
+    }
+
+    fn adjusted_current_node_present_but_not_in_html_namespace(&self) -> bool {
+        self.0
+            .adjusted_current_node_present_but_not_in_html_namespace()
+    }
+}
@@ -3,5 +3,6 @@
 extern crate alloc;
 
 pub mod document;
+mod dom;
 pub mod html;
 pub mod xml;
@@ -1,7 +1,6 @@
 //! XML documents.
 
-use crate::document::Document;
-use markup5ever_rcdom::RcDom;
+use crate::{document::Document, dom::Dom};
 use std::io;
 use xml5ever::{driver::parse_document, tendril::TendrilSink};
 
@@ -11,25 +10,17 @@ pub fn parse(source: &str) -> Result<Document, io::Error> {
 }
 
 /// Parses an XML document from bytes.
-pub fn parse_bytes(mut source: &[u8]) -> Result<Document, io::Error> {
-    parse_document(RcDom::default(), Default::default())
+pub fn parse_bytes(source: &[u8]) -> Result<Document, io::Error> {
+    parse_document(Dom::new(source), Default::default())
         .from_utf8()
-        .read_from(&mut source)
-        .map(|dom| {
-            Document::from_markup5ever(&dom.document).set_errors(
-                dom.errors
-                    .borrow()
-                    .iter()
-                    .map(ToString::to_string)
-                    .collect(),
-            )
-        })
+        .read_from(&mut { source })
+        .map(|dom| Document::from_markup5ever(&dom).set_errors(dom.errors()))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::document::{Element, Node};
+    use crate::document::{Element, Node, Position, Span};
     use alloc::sync::Arc;
     use pretty_assertions::assert_eq;
 
@@ -100,7 +91,10 @@ mod tests {
 
         assert_eq!(document.children().count(), 1);
         assert_eq!(
-            document.errors().collect::<Vec<_>>(),
+            document
+                .errors()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
             vec!["Unexpected element in end phase"]
         );
     }
@@ -266,4 +260,34 @@ mod tests {
             Document::new(vec![element(None, "svg", vec![], vec![])])
         );
     }
+
+    #[test]
+    fn record_element_spans() {
+        let document = parse(concat!(
+            "<?xml version=\"1.0\"?>\n",
+            "<svg xmlns=\"http://www.w3.org/2000/svg\">\n",
+            "  <!-- <rect/> -->\n",
+            "  <rect\n",
+            "    width=\"1\"/>\n",
+            "</svg>\n",
+        ))
+        .unwrap();
+        let Some(Node::Element(svg)) = document.children().next() else {
+            unreachable!()
+        };
+        let Some(Node::Element(rect)) =
+            svg.children().find(|node| matches!(node, Node::Element(_)))
+        else {
+            unreachable!()
+        };
+
+        assert_eq!(
+            svg.span(),
+            Some(Span::new(Position::new(2, 1), Position::new(2, 41)))
+        );
+        assert_eq!(
+            rect.span(),
+            Some(Span::new(Position::new(4, 3), Position::new(5, 16)))
+        );
+    }
 }
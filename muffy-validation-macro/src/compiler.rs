@@ -1,5 +1,6 @@
 use crate::{
     attribute::{AttributeSet, normalize_attributes},
+    datatype::Datatype,
     error::MacroError,
     literal::Literal,
     name::class_names,
@@ -127,8 +128,8 @@ impl<'a> Compiler<'a> {
         })
     }
 
-    // TODO Validate attribute values against data patterns.
-    // TODO Validate attribute values against list patterns.
+    // Parameters and except patterns of datatypes only narrow values. So we
+    // ignore them and accept a superset of valid values.
     fn resolve_value(&self, pattern: &RncPattern) -> Result<Value, MacroError> {
         Ok(match pattern {
             RncPattern::Choice(patterns) => patterns
@@ -147,26 +148,56 @@ impl<'a> Compiler<'a> {
                 Some(literal) => Value::LiteralSet([literal].into()),
                 None => Value::Any,
             },
+            RncPattern::Data { name, .. } => {
+                Datatype::new(name).map_or(Value::Any, Value::Datatype)
+            }
+            RncPattern::List(pattern) => match self.resolve_list_item(pattern)? {
+                Value::Any => Value::Any,
+                value => Value::List(value.into()),
+            },
             RncPattern::Attribute { .. }
-            | RncPattern::Data { .. }
             | RncPattern::Element { .. }
             | RncPattern::External(_)
             | RncPattern::Grammar(_)
             | RncPattern::Group(_)
             | RncPattern::Interleave(_)
-            | RncPattern::List(_)
             | RncPattern::Many0(_)
             | RncPattern::Many1(_)
             | RncPattern::Optional(_)
             | RncPattern::Text => Value::Any,
         })
     }
+
+    // Every token of a list matches any of its item patterns regardless of
+    // their order and number.
+    fn resolve_list_item(&self, pattern: &RncPattern) -> Result<Value, MacroError> {
+        Ok(match pattern {
+            RncPattern::Choice(patterns)
+            | RncPattern::Group(patterns)
+            | RncPattern::Interleave(patterns) => patterns.iter().try_fold(
+                Value::LiteralSet(Default::default()),
+                |value, pattern| {
+                    Ok::<_, MacroError>(value.merge(&self.resolve_list_item(pattern)?))
+                },
+            )?,
+            RncPattern::Empty => Value::LiteralSet(Default::default()),
+            RncPattern::Many0(pattern)
+            | RncPattern::Many1(pattern)
+            | RncPattern::Optional(pattern) => self.resolve_list_item(pattern)?,
+            RncPattern::Name(name) => self.resolve_list_item(
+                self.definitions
+                    .get(&name.local)
+                    .ok_or_else(|| MacroError::UndefinedReference(name.local.to_string()))?,
+            )?,
+            pattern => self.resolve_value(pattern)?,
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::definition::load_grammar;
+    use crate::{definition::load_grammar, namespace::resolve_namespaces};
     use muffy_rnc::{DefinitionSet, Identifier, SchemaBody, parse_schema};
     use pretty_assertions::assert_eq;
     use std::path::Path;
@@ -176,7 +207,15 @@ mod tests {
     }
 
     fn resolve(source: &str) -> Result<Pattern, MacroError> {
-        let SchemaBody::Grammar(grammar) = parse_schema(source).unwrap().body else {
+        // Schemas of HTML declare the `w` datatype library.
+        let SchemaBody::Grammar(grammar) = resolve_namespaces(
+            parse_schema(&format!(
+                "datatypes w = \"http://whattf.org/datatype-draft\"\n{source}"
+            ))
+            .unwrap(),
+        )
+        .body
+        else {
             panic!("grammar expected");
         };
         let mut definitions = DefinitionSet::default();
@@ -349,25 +388,91 @@ mod tests {
         }
 
         #[test]
-        fn resolve_any_value_of_datatype() {
+        fn resolve_datatype() {
+            assert_eq!(
+                resolve("root = attribute foo { w:integer }").unwrap(),
+                Pattern::Attribute(["foo".into()].into(), Value::Datatype(Datatype("Integer")))
+            );
+        }
+
+        #[test]
+        fn resolve_datatype_with_parameters() {
+            assert_eq!(
+                resolve(r#"root = attribute foo { xsd:decimal { minInclusive = "0" } }"#).unwrap(),
+                Pattern::Attribute(
+                    ["foo".into()].into(),
+                    Value::Datatype(Datatype("XsdDecimal"))
+                )
+            );
+        }
+
+        #[test]
+        fn resolve_any_value_of_unconstrained_datatype() {
             assert_eq!(
-                resolve("root = attribute foo { w:language }").unwrap(),
+                resolve("root = attribute foo { w:string }").unwrap(),
                 Pattern::Attribute(["foo".into()].into(), Value::Any)
             );
         }
 
         #[test]
-        fn resolve_any_value_of_literal_alternative_to_datatype() {
+        fn resolve_literal_alternative_to_datatype() {
             assert_eq!(
                 resolve(r#"root = attribute foo { w:string "" | w:language }"#).unwrap(),
-                Pattern::Attribute(["foo".into()].into(), Value::Any)
+                Pattern::Attribute(
+                    ["foo".into()].into(),
+                    Value::Choice(
+                        [
+                            Value::Datatype(Datatype("Language")),
+                            Value::LiteralSet([Literal::CaseInsensitive(String::new())].into()),
+                        ]
+                        .into()
+                    )
+                )
             );
         }
 
         #[test]
-        fn resolve_any_value_of_list() {
+        fn resolve_list() {
             assert_eq!(
                 resolve(r#"root = attribute foo { list { w:string "bar" } }"#).unwrap(),
+                Pattern::Attribute(
+                    ["foo".into()].into(),
+                    Value::List(
+                        Value::LiteralSet([Literal::CaseInsensitive("bar".into())].into()).into()
+                    )
+                )
+            );
+        }
+
+        #[test]
+        fn resolve_list_of_items() {
+            assert_eq!(
+                resolve(concat!(
+                    r#"root = attribute foo { list { (bar, ("baz" | w:integer))+ } }"#,
+                    "\nbar = w:float"
+                ))
+                .unwrap(),
+                Pattern::Attribute(
+                    ["foo".into()].into(),
+                    Value::List(
+                        Value::Choice(
+                            [
+                                Value::Datatype(Datatype("Float")),
+                                Value::Datatype(Datatype("Integer")),
+                                Value::LiteralSet([Literal::Token("baz".into())].into()),
+                            ]
+                            .into()
+                        )
+                        .into()
+                    )
+                )
+            );
+        }
+
+        #[test]
+        fn resolve_any_value_of_list_of_text() {
+            assert_eq!(
+                resolve("root = attribute foo { list { text* } }").unwrap(),
                 Pattern::Attribute(["foo".into()].into(), Value::Any)
             );
         }
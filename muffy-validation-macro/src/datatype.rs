@@ -0,0 +1,218 @@
+use muffy_rnc::DatatypeName;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+pub const HTML_DATATYPE_LIBRARY: &str = "http://whattf.org/datatype-draft";
+pub const XSD_DATATYPE_LIBRARY: &str = "http://www.w3.org/2001/XMLSchema-datatypes";
+
+// Datatypes with constraints as libraries, names, and variants of
+// `Datatype` in the `muffy-validation` crate.
+const DATATYPES: &[(&str, &str, &str)] = &[
+    (HTML_DATATYPE_LIBRARY, "browsing-context", "BrowsingContext"),
+    (
+        HTML_DATATYPE_LIBRARY,
+        "browsing-context-or-keyword",
+        "BrowsingContextOrKeyword",
+    ),
+    (HTML_DATATYPE_LIBRARY, "circle", "Circle"),
+    (
+        HTML_DATATYPE_LIBRARY,
+        "custom-element-name",
+        "CustomElementName",
+    ),
+    (HTML_DATATYPE_LIBRARY, "date", "Date"),
+    (HTML_DATATYPE_LIBRARY, "datetime-local", "DatetimeLocal"),
+    (HTML_DATATYPE_LIBRARY, "datetime-tz", "DatetimeTz"),
+    (HTML_DATATYPE_LIBRARY, "email-address", "EmailAddress"),
+    (
+        HTML_DATATYPE_LIBRARY,
+        "email-address-list",
+        "EmailAddressList",
+    ),
+    (HTML_DATATYPE_LIBRARY, "float", "Float"),
+    (
+        HTML_DATATYPE_LIBRARY,
+        "float-non-negative",
+        "FloatNonNegative",
+    ),
+    (HTML_DATATYPE_LIBRARY, "float-positive", "FloatPositive"),
+    (HTML_DATATYPE_LIBRARY, "hash-name", "HashName"),
+    (HTML_DATATYPE_LIBRARY, "ID", "Id"),
+    (HTML_DATATYPE_LIBRARY, "IDREF", "IdRef"),
+    (HTML_DATATYPE_LIBRARY, "IDREFS", "IdRefs"),
+    (HTML_DATATYPE_LIBRARY, "integer", "Integer"),
+    (
+        HTML_DATATYPE_LIBRARY,
+        "integer-non-negative",
+        "IntegerNonNegative",
+    ),
+    (HTML_DATATYPE_LIBRARY, "integer-positive", "IntegerPositive"),
+    (HTML_DATATYPE_LIBRARY, "iri", "Iri"),
+    (HTML_DATATYPE_LIBRARY, "iri-ref", "IriRef"),
+    (
+        HTML_DATATYPE_LIBRARY,
+        "iri-ref-http-or-https",
+        "IriRefHttpOrHttps",
+    ),
+    (HTML_DATATYPE_LIBRARY, "language", "Language"),
+    (HTML_DATATYPE_LIBRARY, "meta-charset", "MetaCharset"),
+    (HTML_DATATYPE_LIBRARY, "mime-type", "MimeType"),
+    (HTML_DATATYPE_LIBRARY, "month", "Month"),
+    (HTML_DATATYPE_LIBRARY, "non-empty-string", "NonEmptyString"),
+    (HTML_DATATYPE_LIBRARY, "polyline", "Polyline"),
+    (HTML_DATATYPE_LIBRARY, "rectangle", "Rectangle"),
+    (HTML_DATATYPE_LIBRARY, "simple-color", "SimpleColor"),
+    (
+        HTML_DATATYPE_LIBRARY,
+        "string-without-line-breaks",
+        "StringWithoutLineBreaks",
+    ),
+    (HTML_DATATYPE_LIBRARY, "time", "Time"),
+    (HTML_DATATYPE_LIBRARY, "time-datetime", "TimeDatetime"),
+    (HTML_DATATYPE_LIBRARY, "week", "Week"),
+    (HTML_DATATYPE_LIBRARY, "xml-name", "XmlName"),
+    (XSD_DATATYPE_LIBRARY, "decimal", "XsdDecimal"),
+    (XSD_DATATYPE_LIBRARY, "integer", "XsdInteger"),
+    (XSD_DATATYPE_LIBRARY, "language", "XsdLanguage"),
+    (XSD_DATATYPE_LIBRARY, "NCName", "XsdNcName"),
+    (XSD_DATATYPE_LIBRARY, "NMTOKEN", "XsdNmToken"),
+    (XSD_DATATYPE_LIBRARY, "NMTOKENS", "XsdNmTokens"),
+    (
+        XSD_DATATYPE_LIBRARY,
+        "nonNegativeInteger",
+        "XsdNonNegativeInteger",
+    ),
+    (
+        XSD_DATATYPE_LIBRARY,
+        "positiveInteger",
+        "XsdPositiveInteger",
+    ),
+    (XSD_DATATYPE_LIBRARY, "unsignedLong", "XsdUnsignedLong"),
+];
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Datatype(pub &'static str);
+
+impl Datatype {
+    /// Resolves a datatype name whose prefix is replaced by a library URI.
+    /// Datatypes without any constraints or unknown to us resolve to `None`.
+    pub fn new(name: &DatatypeName) -> Option<Self> {
+        let DatatypeName::Name(name) = name else {
+            return None;
+        };
+        let library = name.prefix.as_ref()?.to_string();
+        let local = name.local.to_string();
+
+        DATATYPES
+            .iter()
+            .find(|(other_library, other_local, _)| {
+                *other_library == library && *other_local == local
+            })
+            .map(|(_, _, variant)| Self(variant))
+    }
+}
+
+pub fn generate_datatype(datatype: Datatype) -> TokenStream {
+    let variant = format_ident!("{}", datatype.0);
+
+    quote!(Datatype::#variant)
+}
+
+pub fn generate_datatype_resolution(library: &Ident, name: &Ident) -> TokenStream {
+    let arms = DATATYPES
+        .iter()
+        .map(|(other_library, other_name, variant)| {
+            let variant = format_ident!("{variant}");
+
+            quote!((#other_library, #other_name) => Some(Datatype::#variant))
+        });
+
+    quote! {
+        match (#library, #name) {
+            #(#arms,)*
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use muffy_rnc::{Identifier, Name};
+    use pretty_assertions::assert_eq;
+
+    fn datatype(library: &str, local: &str) -> DatatypeName {
+        DatatypeName::Name(Name {
+            prefix: Some(Identifier {
+                component: library.into(),
+                sub_components: vec![],
+            }),
+            local: Identifier {
+                component: local.into(),
+                sub_components: vec![],
+            },
+        })
+    }
+
+    #[test]
+    fn resolve_html_datatype() {
+        assert_eq!(
+            Datatype::new(&datatype(HTML_DATATYPE_LIBRARY, "integer-non-negative")),
+            Some(Datatype("IntegerNonNegative"))
+        );
+    }
+
+    #[test]
+    fn resolve_xsd_datatype() {
+        assert_eq!(
+            Datatype::new(&datatype(XSD_DATATYPE_LIBRARY, "integer")),
+            Some(Datatype("XsdInteger"))
+        );
+    }
+
+    #[test]
+    fn resolve_unconstrained_datatype() {
+        assert_eq!(
+            Datatype::new(&datatype(HTML_DATATYPE_LIBRARY, "string")),
+            None
+        );
+        assert_eq!(
+            Datatype::new(&datatype(XSD_DATATYPE_LIBRARY, "anyURI")),
+            None
+        );
+        assert_eq!(Datatype::new(&DatatypeName::String), None);
+    }
+
+    #[test]
+    fn resolve_unknown_datatype() {
+        assert_eq!(Datatype::new(&datatype(HTML_DATATYPE_LIBRARY, "foo")), None);
+        assert_eq!(Datatype::new(&datatype("w", "integer")), None);
+    }
+
+    #[test]
+    fn generate_variant() {
+        assert_eq!(
+            generate_datatype(Datatype("XsdNcName")).to_string(),
+            quote!(Datatype::XsdNcName).to_string()
+        );
+    }
+
+    #[test]
+    fn generate_resolution() {
+        let resolution =
+            generate_datatype_resolution(&format_ident!("library"), &format_ident!("name"))
+                .to_string();
+
+        assert!(resolution.starts_with(&quote!(match (library, name)).to_string()));
+        assert!(
+            resolution.contains(
+                &quote!((
+                "http://www.w3.org/2001/XMLSchema-datatypes",
+                "NCName"
+            ) => Some(Datatype::XsdNcName))
+                .to_string()
+            )
+        );
+        assert!(resolution.contains(&quote!(_ => None).to_string()));
+    }
+}
@@ -0,0 +1,347 @@
+use crate::{error::MacroError, name::class_names};
+use alloc::collections::{BTreeMap, BTreeSet};
+use muffy_rnc::{Identifier, NameClass, Pattern as RncPattern};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+// Elements in this definition exclude their descendants.
+const EXCLUSION_DEFINITION: &str = "normal.elem.all-inclusive";
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct NameSet {
+    names: BTreeSet<String>,
+    excepted: BTreeSet<String>,
+}
+
+impl NameSet {
+    fn new(name_class: &NameClass) -> Self {
+        match name_class {
+            NameClass::Except { base, except } => Self {
+                names: class_names(base),
+                excepted: class_names(except),
+            },
+            NameClass::Any
+            | NameClass::Choice(_)
+            | NameClass::Name(_)
+            | NameClass::Namespace(_) => Self {
+                names: class_names(name_class),
+                excepted: Default::default(),
+            },
+        }
+    }
+
+    fn any() -> Self {
+        Self {
+            names: ["*".into()].into(),
+            excepted: Default::default(),
+        }
+    }
+}
+
+// A pattern of descendants allowed in an excluding ancestor.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Descendant {
+    names: NameSet,
+    attributes: Vec<NameSet>,
+    values: BTreeMap<String, String>,
+}
+
+pub fn collect_exclusions(
+    definitions: &BTreeMap<Identifier, RncPattern>,
+) -> Result<BTreeMap<String, Vec<Descendant>>, MacroError> {
+    let Some(pattern) = definitions
+        .iter()
+        .find_map(|(name, pattern)| (name.to_string() == EXCLUSION_DEFINITION).then_some(pattern))
+    else {
+        return Err(MacroError::UndefinedReference(EXCLUSION_DEFINITION.into()));
+    };
+    let mut exclusions = BTreeMap::new();
+
+    for (name_class, pattern) in collect_elements(definitions, pattern, &mut Default::default())? {
+        let descendants = collect_elements(definitions, pattern, &mut Default::default())?
+            .into_iter()
+            .map(|(name_class, pattern)| {
+                let mut attributes = vec![];
+                let mut values = BTreeMap::new();
+
+                collect_attributes(
+                    definitions,
+                    pattern,
+                    &mut attributes,
+                    &mut values,
+                    &mut Default::default(),
+                )?;
+
+                if attributes.is_empty() {
+                    attributes.push(NameSet::any());
+                }
+
+                Ok(Descendant {
+                    names: NameSet::new(name_class),
+                    attributes,
+                    values,
+                })
+            })
+            .collect::<Result<Vec<_>, MacroError>>()?;
+
+        for name in class_names(name_class) {
+            exclusions.insert(name, descendants.clone());
+        }
+    }
+
+    Ok(exclusions)
+}
+
+pub fn generate_descendant(descendant: &Descendant) -> TokenStream {
+    let names = generate_name_set(&descendant.names);
+    let attributes = descendant.attributes.iter().map(generate_name_set);
+    let values = descendant
+        .values
+        .iter()
+        .map(|(name, value)| quote!((#name, #value)));
+
+    quote! {
+        Descendant {
+            names: #names,
+            attributes: &[#(#attributes),*],
+            values: &[#(#values),*],
+        }
+    }
+}
+
+fn generate_name_set(set: &NameSet) -> TokenStream {
+    let names = set.names.iter();
+    let excepted = set.excepted.iter();
+
+    quote!(NameSet {
+        names: &[#(#names),*],
+        excepted: &[#(#excepted),*],
+    })
+}
+
+// Collects element patterns without descending into their contents.
+fn collect_elements<'a>(
+    definitions: &'a BTreeMap<Identifier, RncPattern>,
+    pattern: &'a RncPattern,
+    visited: &mut BTreeSet<&'a Identifier>,
+) -> Result<Vec<(&'a NameClass, &'a RncPattern)>, MacroError> {
+    Ok(match pattern {
+        RncPattern::Element {
+            name_class,
+            pattern,
+        } => vec![(name_class, pattern)],
+        RncPattern::Choice(patterns)
+        | RncPattern::Group(patterns)
+        | RncPattern::Interleave(patterns) => patterns
+            .iter()
+            .map(|pattern| collect_elements(definitions, pattern, visited))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .flatten()
+            .collect(),
+        RncPattern::Many0(pattern) | RncPattern::Many1(pattern) | RncPattern::Optional(pattern) => {
+            collect_elements(definitions, pattern, visited)?
+        }
+        RncPattern::Name(name) => {
+            if !visited.insert(&name.local) {
+                return Ok(vec![]);
+            }
+
+            collect_elements(definitions, resolve(definitions, &name.local)?, visited)?
+        }
+        RncPattern::Attribute { .. }
+        | RncPattern::Data { .. }
+        | RncPattern::Empty
+        | RncPattern::External(_)
+        | RncPattern::Grammar(_)
+        | RncPattern::List(_)
+        | RncPattern::NotAllowed
+        | RncPattern::Text
+        | RncPattern::Value { .. } => vec![],
+    })
+}
+
+// Collects attribute patterns without descending into contents of elements.
+fn collect_attributes<'a>(
+    definitions: &'a BTreeMap<Identifier, RncPattern>,
+    pattern: &'a RncPattern,
+    attributes: &mut Vec<NameSet>,
+    values: &mut BTreeMap<String, String>,
+    visited: &mut BTreeSet<&'a Identifier>,
+) -> Result<(), MacroError> {
+    match pattern {
+        RncPattern::Attribute {
+            name_class,
+            pattern,
+        } => {
+            let names = NameSet::new(name_class);
+
+            if let RncPattern::Value { value, .. } = pattern.as_ref() {
+                for name in &names.names {
+                    values.insert(name.clone(), value.clone());
+                }
+            }
+
+            attributes.push(names);
+        }
+        RncPattern::Choice(patterns)
+        | RncPattern::Group(patterns)
+        | RncPattern::Interleave(patterns) => {
+            for pattern in patterns {
+                collect_attributes(definitions, pattern, attributes, values, visited)?;
+            }
+        }
+        RncPattern::Many0(pattern) | RncPattern::Many1(pattern) | RncPattern::Optional(pattern) => {
+            collect_attributes(definitions, pattern, attributes, values, visited)?
+        }
+        RncPattern::Name(name) => {
+            if visited.insert(&name.local) {
+                collect_attributes(
+                    definitions,
+                    resolve(definitions, &name.local)?,
+                    attributes,
+                    values,
+                    visited,
+                )?;
+            }
+        }
+        RncPattern::Data { .. }
+        | RncPattern::Element { .. }
+        | RncPattern::Empty
+        | RncPattern::External(_)
+        | RncPattern::Grammar(_)
+        | RncPattern::List(_)
+        | RncPattern::NotAllowed
+        | RncPattern::Text
+        | RncPattern::Value { .. } => {}
+    }
+
+    Ok(())
+}
+
+fn resolve<'a>(
+    definitions: &'a BTreeMap<Identifier, RncPattern>,
+    name: &Identifier,
+) -> Result<&'a RncPattern, MacroError> {
+    definitions
+        .get(name)
+        .ok_or_else(|| MacroError::UndefinedReference(name.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::definition::load_grammar;
+    use muffy_rnc::{DefinitionSet, SchemaBody, parse_schema};
+    use pretty_assertions::assert_eq;
+    use std::path::Path;
+
+    fn collect(source: &str) -> BTreeMap<String, Vec<Descendant>> {
+        let SchemaBody::Grammar(grammar) = parse_schema(source).unwrap().body else {
+            panic!("grammar expected");
+        };
+        let mut definitions = DefinitionSet::default();
+
+        load_grammar(
+            &grammar,
+            Path::new("."),
+            &Default::default(),
+            &mut definitions,
+        )
+        .unwrap();
+
+        collect_exclusions(&definitions.into_patterns()).unwrap()
+    }
+
+    fn name_set(names: &[&str], excepted: &[&str]) -> NameSet {
+        NameSet {
+            names: names.iter().map(|name| (*name).into()).collect(),
+            excepted: excepted.iter().map(|name| (*name).into()).collect(),
+        }
+    }
+
+    #[test]
+    fn collect_self_exclusion() {
+        assert_eq!(
+            collect(concat!(
+                "normal.elem.all-inclusive = notAllowed | dfn.elem.exclude-self\n",
+                "dfn.elem.exclude-self = element dfn { attribute * { text }* & normal.elem.exclude-dfn }\n",
+                "normal.elem.exclude-dfn = element (* - dfn) { normal.elem.exclude-dfn }",
+            )),
+            [(
+                "dfn".into(),
+                vec![Descendant {
+                    names: name_set(&["*"], &["dfn"]),
+                    attributes: vec![NameSet::any()],
+                    values: Default::default(),
+                }]
+            )]
+            .into()
+        );
+    }
+
+    #[test]
+    fn collect_exclusion_of_attributes() {
+        assert_eq!(
+            collect(concat!(
+                "normal.elem.all-inclusive = element (a | b) { foo }\n",
+                "foo = (element (* - c) { attribute (* - d) { text }* & foo }",
+                r#" | element c { attribute e { "f" } & attribute (* - e) { text }* & foo })*"#,
+            )),
+            [
+                (
+                    "a".into(),
+                    vec![
+                        Descendant {
+                            names: name_set(&["*"], &["c"]),
+                            attributes: vec![name_set(&["*"], &["d"])],
+                            values: Default::default(),
+                        },
+                        Descendant {
+                            names: name_set(&["c"], &[]),
+                            attributes: vec![name_set(&["e"], &[]), name_set(&["*"], &["e"])],
+                            values: [("e".into(), "f".into())].into(),
+                        }
+                    ]
+                ),
+                (
+                    "b".into(),
+                    vec![
+                        Descendant {
+                            names: name_set(&["*"], &["c"]),
+                            attributes: vec![name_set(&["*"], &["d"])],
+                            values: Default::default(),
+                        },
+                        Descendant {
+                            names: name_set(&["c"], &[]),
+                            attributes: vec![name_set(&["e"], &[]), name_set(&["*"], &["e"])],
+                            values: [("e".into(), "f".into())].into(),
+                        }
+                    ]
+                ),
+            ]
+            .into()
+        );
+    }
+
+    #[test]
+    fn fail_without_exclusion_definition() {
+        let SchemaBody::Grammar(grammar) = parse_schema("foo = empty").unwrap().body else {
+            panic!("grammar expected");
+        };
+        let mut definitions = DefinitionSet::default();
+
+        load_grammar(
+            &grammar,
+            Path::new("."),
+            &Default::default(),
+            &mut definitions,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            collect_exclusions(&definitions.into_patterns()),
+            Err(MacroError::UndefinedReference(_))
+        ));
+    }
+}
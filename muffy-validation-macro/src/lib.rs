@@ -5,8 +5,10 @@ extern crate alloc;
 mod attribute;
 mod compiler;
 mod content;
+mod datatype;
 mod definition;
 mod error;
+mod exclusion;
 mod literal;
 mod name;
 mod namespace;
@@ -17,8 +19,10 @@ use self::{
     attribute::AttributeSet,
     compiler::Compiler,
     content::{children, generate_content},
+    datatype::generate_datatype_resolution,
     definition::load_definitions,
     error::MacroError,
+    exclusion::{collect_exclusions, generate_descendant},
     name::class_names,
     pattern::Pattern,
     value::{Value, generate_value},
@@ -27,20 +31,69 @@ use alloc::collections::BTreeMap;
 use itertools::Itertools;
 use muffy_rnc::{NameClass, Pattern as RncPattern};
 use proc_macro::TokenStream;
-use proc_macro2::Span;
+use proc_macro2::{Ident, Span};
 use quote::{format_ident, quote};
+use syn::{Token, punctuated::Punctuated};
 
 /// Generates HTML validation functions.
 #[proc_macro]
 pub fn html(_input: TokenStream) -> TokenStream {
-    generate_validation("html", &["html.rnc"]).unwrap_or_else(|error| {
+    generate_validation("html", "Validates an HTML element.", &["html.rnc"]).unwrap_or_else(
+        |error| {
+            syn::Error::new(Span::call_site(), error)
+                .to_compile_error()
+                .into()
+        },
+    )
+}
+
+/// Generates MathML validation functions.
+#[proc_macro]
+pub fn mathml(_input: TokenStream) -> TokenStream {
+    generate_validation("mathml", "Validates a MathML element.", &["mathml.rnc"]).unwrap_or_else(
+        |error| {
+            syn::Error::new(Span::call_site(), error)
+                .to_compile_error()
+                .into()
+        },
+    )
+}
+
+/// Generates HTML exclusion validation functions.
+#[proc_macro]
+pub fn html_exclusions(_input: TokenStream) -> TokenStream {
+    generate_exclusion_validation(
+        "html",
+        "Validates an HTML element against exclusions by its ancestors.",
+        &["exclusions.rnc"],
+    )
+    .unwrap_or_else(|error| {
         syn::Error::new(Span::call_site(), error)
             .to_compile_error()
             .into()
     })
 }
 
-fn generate_validation(language: &str, files: &[&str]) -> Result<TokenStream, MacroError> {
+/// Resolves a datatype from library and name variables.
+#[proc_macro]
+pub fn datatype(input: TokenStream) -> TokenStream {
+    let arguments = syn::parse_macro_input!(
+        input with Punctuated::<Ident, Token![,]>::parse_terminated
+    );
+
+    match arguments.iter().collect::<Vec<_>>().as_slice() {
+        [library, name] => generate_datatype_resolution(library, name).into(),
+        _ => syn::Error::new(Span::call_site(), "library and name expected")
+            .to_compile_error()
+            .into(),
+    }
+}
+
+fn generate_validation(
+    language: &str,
+    documentation: &str,
+    files: &[&str],
+) -> Result<TokenStream, MacroError> {
     let definitions = load_definitions(files)?;
     let mut compiler = Compiler::new(&definitions);
     let mut element_rules = BTreeMap::<String, Vec<(Vec<AttributeSet>, Pattern)>>::new();
@@ -48,8 +101,7 @@ fn generate_validation(language: &str, files: &[&str]) -> Result<TokenStream, Ma
     for definition in definitions.values() {
         for (name_class, pattern) in collect_elements(definition) {
             // Elements of any names constrain only content models of their parents.
-            // TODO Support position-aware validation to exempt descendants of
-            // elements with unconstrained content models from name validation.
+            // Their descendants are exempted from name validation at runtime.
             let names = class_names(name_class)
                 .into_iter()
                 .filter(|name| name != "*")
@@ -119,7 +171,7 @@ fn generate_validation(language: &str, files: &[&str]) -> Result<TokenStream, Ma
                     variants: &[#(#variants),*],
                 };
 
-                validate_rule(element, ignored_attributes, ignored_elements, &RULE)
+                Some(&RULE)
             }
         };
 
@@ -166,25 +218,77 @@ fn generate_validation(language: &str, files: &[&str]) -> Result<TokenStream, Ma
         .collect::<Result<Vec<_>, MacroError>>()?;
 
     let function_name = format_ident!("validate_{language}_element");
-    let documentation = format!("Validates an {} element.", language.to_uppercase());
 
     Ok(quote! {
         #[doc = #documentation]
         pub fn #function_name(
             element: &Element,
+            ancestors: &[&Element],
             ignored_attributes: &[::regex::Regex],
             ignored_elements: &[::regex::Regex],
+            custom_elements: &::alloc::collections::BTreeMap<String, CustomElement>,
+        ) -> Result<(), MarkupError> {
+            fn find_rule(name: &str) -> Option<&'static Rule> {
+                #(#value_definitions)*
+                #(#attribute_set_definitions)*
+                #(#content_definitions)*
+
+                match name {
+                    #(#element_matches)*
+                    #(#wildcard_matches)*
+                    _ => None,
+                }
+            }
+
+            validate_element(
+                element,
+                ancestors,
+                ignored_attributes,
+                ignored_elements,
+                custom_elements,
+                find_rule,
+            )
+        }
+    }
+    .into())
+}
+
+fn generate_exclusion_validation(
+    language: &str,
+    documentation: &str,
+    files: &[&str],
+) -> Result<TokenStream, MacroError> {
+    let exclusion_matches = collect_exclusions(&load_definitions(files)?)?
+        .into_iter()
+        .map(|(name, descendants)| {
+            let descendants = descendants.iter().map(generate_descendant);
+
+            quote! {
+                #name => {
+                    const DESCENDANTS: &[Descendant] = &[#(#descendants),*];
+
+                    Some(DESCENDANTS)
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+    let function_name = format_ident!("validate_{language}_exclusions");
+
+    Ok(quote! {
+        #[doc = #documentation]
+        pub fn #function_name(
+            element: &Element,
+            ancestors: &[&Element],
+            ignored_elements: &[::regex::Regex],
         ) -> Result<(), MarkupError> {
-            #(#value_definitions)*
-            #(#attribute_set_definitions)*
-            #(#content_definitions)*
-
-            match element.name() {
-                name if ignored_elements.iter().any(|pattern| pattern.is_match(name)) => Ok(()),
-                #(#element_matches)*
-                #(#wildcard_matches)*
-                _ => Err(MarkupError::UnknownTag(element.name().to_string())),
+            fn find_descendants(name: &str) -> Option<&'static [Descendant]> {
+                match name {
+                    #(#exclusion_matches)*
+                    _ => None,
+                }
             }
+
+            validate_exclusions(element, ancestors, ignored_elements, find_descendants)
         }
     }
     .into())
@@ -1,10 +1,8 @@
+use crate::datatype::HTML_DATATYPE_LIBRARY;
 use muffy_rnc::DatatypeName;
 use proc_macro2::TokenStream;
 use quote::quote;
 
-// TODO Resolve datatype prefixes against datatypes declarations.
-const WHATTF_DATATYPE_PREFIX: &str = "w";
-
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub enum Literal {
     CaseInsensitive(String),
@@ -17,9 +15,10 @@ impl Literal {
         match name {
             None | Some(DatatypeName::Token) => Some(Self::Token(value.into())),
             Some(DatatypeName::String) => Some(Self::Exact(value.into())),
-            // `w:string` in vnu matches strings case-insensitively.
+            // `string` of the HTML datatype library in vnu matches strings
+            // case-insensitively.
             Some(DatatypeName::Name(name)) => (name.prefix.as_ref().map(ToString::to_string)
-                == Some(WHATTF_DATATYPE_PREFIX.into())
+                == Some(HTML_DATATYPE_LIBRARY.into())
                 && name.local.to_string() == "string")
                 .then(|| Self::CaseInsensitive(value.into())),
         }
@@ -37,12 +36,13 @@ pub fn generate_literal(literal: &Literal) -> TokenStream {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::datatype::XSD_DATATYPE_LIBRARY;
     use muffy_rnc::{Identifier, Name};
 
-    fn datatype(prefix: &str, local: &str) -> DatatypeName {
+    fn datatype(library: &str, local: &str) -> DatatypeName {
         DatatypeName::Name(Name {
             prefix: Some(Identifier {
-                component: prefix.into(),
+                component: library.into(),
                 sub_components: vec![],
             }),
             local: Identifier {
@@ -83,15 +83,21 @@ mod tests {
         #[test]
         fn create_case_insensitive_literal() {
             assert_eq!(
-                Literal::new(Some(&datatype("w", "string")), "foo"),
+                Literal::new(Some(&datatype(HTML_DATATYPE_LIBRARY, "string")), "foo"),
                 Some(Literal::CaseInsensitive("foo".into()))
             );
         }
 
         #[test]
         fn create_no_literal_of_unknown_datatype() {
-            assert_eq!(Literal::new(Some(&datatype("w", "language")), "foo"), None);
-            assert_eq!(Literal::new(Some(&datatype("xsd", "string")), "foo"), None);
+            assert_eq!(
+                Literal::new(Some(&datatype(HTML_DATATYPE_LIBRARY, "language")), "foo"),
+                None
+            );
+            assert_eq!(
+                Literal::new(Some(&datatype(XSD_DATATYPE_LIBRARY, "string")), "foo"),
+                None
+            );
         }
     }
 
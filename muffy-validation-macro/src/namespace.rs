@@ -1,25 +1,45 @@
+use crate::datatype::XSD_DATATYPE_LIBRARY;
 use alloc::collections::BTreeMap;
 use muffy_document::document::namespace_prefix;
 use muffy_rnc::{
-    Declaration, Grammar, GrammarContent, Identifier, Include, IncludeContent, Name, NameClass,
-    Pattern, Schema, SchemaBody, Start,
+    DatatypeName, Declaration, Grammar, GrammarContent, Identifier, Include, IncludeContent, Name,
+    NameClass, Pattern, Schema, SchemaBody, Start,
 };
 
+struct Namespaces {
+    prefixes: BTreeMap<Identifier, String>,
+    datatypes: BTreeMap<Identifier, String>,
+}
+
 pub fn resolve_namespaces(schema: Schema) -> Schema {
-    let namespaces = schema
-        .declarations
-        .iter()
-        .filter_map(|declaration| match declaration {
-            Declaration::Datatypes(_) => None,
-            Declaration::DefaultNamespace(declaration) => declaration
-                .prefix
-                .clone()
-                .map(|prefix| (prefix, declaration.uri.clone())),
+    let mut namespaces = Namespaces {
+        prefixes: Default::default(),
+        datatypes: [(identifier("xsd"), XSD_DATATYPE_LIBRARY.into())].into(),
+    };
+
+    for declaration in &schema.declarations {
+        match declaration {
+            Declaration::Datatypes(declaration) => {
+                if let Some(prefix) = &declaration.prefix {
+                    namespaces
+                        .datatypes
+                        .insert(prefix.clone(), declaration.uri.clone());
+                }
+            }
+            Declaration::DefaultNamespace(declaration) => {
+                if let Some(prefix) = &declaration.prefix {
+                    namespaces
+                        .prefixes
+                        .insert(prefix.clone(), declaration.uri.clone());
+                }
+            }
             Declaration::Namespace(declaration) => {
-                Some((declaration.prefix.clone(), declaration.uri.clone()))
+                namespaces
+                    .prefixes
+                    .insert(declaration.prefix.clone(), declaration.uri.clone());
             }
-        })
-        .collect();
+        }
+    }
 
     Schema {
         body: match schema.body {
@@ -34,7 +54,7 @@ pub fn resolve_namespaces(schema: Schema) -> Schema {
     }
 }
 
-fn resolve_grammar(grammar: Grammar, namespaces: &BTreeMap<Identifier, String>) -> Grammar {
+fn resolve_grammar(grammar: Grammar, namespaces: &Namespaces) -> Grammar {
     Grammar {
         contents: grammar
             .contents
@@ -65,7 +85,7 @@ fn resolve_grammar(grammar: Grammar, namespaces: &BTreeMap<Identifier, String>)
 
 fn resolve_include_contents(
     contents: Vec<IncludeContent>,
-    namespaces: &BTreeMap<Identifier, String>,
+    namespaces: &Namespaces,
 ) -> Vec<IncludeContent> {
     contents
         .into_iter()
@@ -88,7 +108,7 @@ fn resolve_include_contents(
         .collect()
 }
 
-fn resolve_pattern(pattern: Pattern, namespaces: &BTreeMap<Identifier, String>) -> Pattern {
+fn resolve_pattern(pattern: Pattern, namespaces: &Namespaces) -> Pattern {
     match pattern {
         Pattern::Attribute {
             name_class,
@@ -108,7 +128,7 @@ fn resolve_pattern(pattern: Pattern, namespaces: &BTreeMap<Identifier, String>)
             parameters,
             except,
         } => Pattern::Data {
-            name,
+            name: resolve_datatype_name(name, namespaces),
             parameters,
             except: except.map(|pattern| resolve_pattern(*pattern, namespaces).into()),
         },
@@ -138,19 +158,19 @@ fn resolve_pattern(pattern: Pattern, namespaces: &BTreeMap<Identifier, String>)
         Pattern::Optional(pattern) => {
             Pattern::Optional(resolve_pattern(*pattern, namespaces).into())
         }
+        Pattern::Value { name, value } => Pattern::Value {
+            name: name.map(|name| resolve_datatype_name(name, namespaces)),
+            value,
+        },
         Pattern::Empty
         | Pattern::External(_)
         | Pattern::Name(_)
         | Pattern::NotAllowed
-        | Pattern::Text
-        | Pattern::Value { .. } => pattern,
+        | Pattern::Text => pattern,
     }
 }
 
-fn resolve_name_class(
-    name_class: NameClass,
-    namespaces: &BTreeMap<Identifier, String>,
-) -> NameClass {
+fn resolve_name_class(name_class: NameClass, namespaces: &Namespaces) -> NameClass {
     match name_class {
         NameClass::Any => NameClass::Any,
         NameClass::Choice(classes) => NameClass::Choice(
@@ -164,7 +184,7 @@ fn resolve_name_class(
             except: resolve_name_class(*except, namespaces).into(),
         },
         NameClass::Name(name) => NameClass::Name(resolve_name(name, namespaces)),
-        NameClass::Namespace(Some(prefix)) => match namespaces.get(&prefix) {
+        NameClass::Namespace(Some(prefix)) => match namespaces.prefixes.get(&prefix) {
             Some(uri) if uri.is_empty() => NameClass::Namespace(None),
             Some(uri) => NameClass::Namespace(Some(canonical_prefix(uri).unwrap_or(prefix))),
             None => NameClass::Namespace(Some(prefix)),
@@ -173,11 +193,11 @@ fn resolve_name_class(
     }
 }
 
-fn resolve_name(name: Name, namespaces: &BTreeMap<Identifier, String>) -> Name {
+fn resolve_name(name: Name, namespaces: &Namespaces) -> Name {
     let Some(uri) = name
         .prefix
         .as_ref()
-        .and_then(|prefix| namespaces.get(prefix))
+        .and_then(|prefix| namespaces.prefixes.get(prefix))
     else {
         return name;
     };
@@ -197,11 +217,34 @@ fn resolve_name(name: Name, namespaces: &BTreeMap<Identifier, String>) -> Name {
     }
 }
 
+// Datatype prefixes are replaced by library URIs.
+fn resolve_datatype_name(name: DatatypeName, namespaces: &Namespaces) -> DatatypeName {
+    match name {
+        DatatypeName::Name(Name {
+            prefix: Some(prefix),
+            local,
+        }) => DatatypeName::Name(Name {
+            prefix: Some(
+                namespaces
+                    .datatypes
+                    .get(&prefix)
+                    .map_or(prefix, |library| identifier(library)),
+            ),
+            local,
+        }),
+        DatatypeName::Name(_) | DatatypeName::String | DatatypeName::Token => name,
+    }
+}
+
 fn canonical_prefix(uri: &str) -> Option<Identifier> {
-    namespace_prefix(uri).map(|prefix| Identifier {
-        component: prefix.to_owned(),
+    namespace_prefix(uri).map(identifier)
+}
+
+fn identifier(component: &str) -> Identifier {
+    Identifier {
+        component: component.into(),
         sub_components: vec![],
-    })
+    }
 }
 
 #[cfg(test)]
@@ -328,6 +371,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn resolve_datatype_prefix() {
+        assert_eq!(
+            resolve(
+                "datatypes foo = \"http://whattf.org/datatype-draft\"\nroot = attribute bar { foo:integer }"
+            ),
+            Pattern::Attribute {
+                name_class: NameClass::Name(Name {
+                    prefix: None,
+                    local: identifier("bar"),
+                }),
+                pattern: Pattern::Data {
+                    name: DatatypeName::Name(Name {
+                        prefix: Some(identifier("http://whattf.org/datatype-draft")),
+                        local: identifier("integer"),
+                    }),
+                    parameters: vec![],
+                    except: None,
+                }
+                .into(),
+            }
+        );
+    }
+
+    #[test]
+    fn resolve_built_in_datatype_prefix() {
+        assert_eq!(
+            resolve("root = attribute foo { xsd:string \"bar\" }"),
+            Pattern::Attribute {
+                name_class: NameClass::Name(Name {
+                    prefix: None,
+                    local: identifier("foo"),
+                }),
+                pattern: Pattern::Value {
+                    name: Some(DatatypeName::Name(Name {
+                        prefix: Some(identifier(XSD_DATATYPE_LIBRARY)),
+                        local: identifier("string"),
+                    })),
+                    value: "bar".into(),
+                }
+                .into(),
+            }
+        );
+    }
+
     #[test]
     fn keep_names_on_default_namespace_without_prefix() {
         assert_eq!(
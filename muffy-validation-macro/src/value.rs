@@ -1,4 +1,7 @@
-use crate::literal::{Literal, generate_literal};
+use crate::{
+    datatype::{Datatype, generate_datatype},
+    literal::{Literal, generate_literal},
+};
 use alloc::collections::BTreeSet;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -6,6 +9,9 @@ use quote::quote;
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub enum Value {
     Any,
+    Choice(BTreeSet<Self>),
+    Datatype(Datatype),
+    List(Box<Self>),
     LiteralSet(BTreeSet<Literal>),
     TokenList(BTreeSet<Literal>),
 }
@@ -13,13 +19,42 @@ pub enum Value {
 impl Value {
     pub fn merge(&self, other: &Self) -> Self {
         match (self, other) {
+            (Self::Any, _) | (_, Self::Any) => Self::Any,
+            // An empty literal set matches nothing.
+            (Self::LiteralSet(literals), value) | (value, Self::LiteralSet(literals))
+                if literals.is_empty() =>
+            {
+                value.clone()
+            }
             (Self::LiteralSet(literals), Self::LiteralSet(others)) => {
                 Self::LiteralSet(literals.union(others).cloned().collect())
             }
             (Self::TokenList(literals), Self::TokenList(others)) => {
                 Self::TokenList(literals.union(others).cloned().collect())
             }
-            _ => Self::Any,
+            (Self::Choice(values), Self::Choice(others)) => others
+                .iter()
+                .fold(Self::Choice(values.clone()), |value, other| {
+                    value.merge(other)
+                }),
+            (Self::Choice(values), other) | (other, Self::Choice(values)) => {
+                let mut values = values.clone();
+
+                if let Some(value) = values
+                    .iter()
+                    .find(|value| value.is_mergeable(other))
+                    .cloned()
+                {
+                    values.remove(&value);
+                    values.insert(value.merge(other));
+                } else {
+                    values.insert(other.clone());
+                }
+
+                Self::Choice(values)
+            }
+            (value, other) if value == other => value.clone(),
+            (value, other) => Self::Choice([value.clone(), other.clone()].into()),
         }
     }
 
@@ -29,11 +64,33 @@ impl Value {
             value => value,
         }
     }
+
+    const fn is_mergeable(&self, other: &Self) -> bool {
+        matches!(
+            (self, other),
+            (Self::LiteralSet(_), Self::LiteralSet(_)) | (Self::TokenList(_), Self::TokenList(_))
+        )
+    }
 }
 
 pub fn generate_value(value: &Value) -> TokenStream {
     match value {
         Value::Any => quote!(Value::Any),
+        Value::Choice(values) => {
+            let values = values.iter().map(generate_value);
+
+            quote!(Value::Choice(&[#(#values),*]))
+        }
+        Value::Datatype(datatype) => {
+            let datatype = generate_datatype(*datatype);
+
+            quote!(Value::Datatype(#datatype))
+        }
+        Value::List(value) => {
+            let value = generate_value(value);
+
+            quote!(Value::List(&#value))
+        }
         Value::LiteralSet(literals) => {
             let literals = generate_literals(literals);
 
@@ -87,7 +144,89 @@ mod tests {
             assert_eq!(
                 Value::TokenList([Literal::Exact("foo".into())].into())
                     .merge(&Value::LiteralSet([Literal::Exact("bar".into())].into())),
-                Value::Any
+                Value::Choice(
+                    [
+                        Value::LiteralSet([Literal::Exact("bar".into())].into()),
+                        Value::TokenList([Literal::Exact("foo".into())].into()),
+                    ]
+                    .into()
+                )
+            );
+        }
+
+        #[test]
+        fn merge_datatype_with_literals() {
+            assert_eq!(
+                Value::Datatype(Datatype("Integer"))
+                    .merge(&Value::LiteralSet([Literal::Exact("foo".into())].into())),
+                Value::Choice(
+                    [
+                        Value::Datatype(Datatype("Integer")),
+                        Value::LiteralSet([Literal::Exact("foo".into())].into()),
+                    ]
+                    .into()
+                )
+            );
+        }
+
+        #[test]
+        fn merge_literals_into_choice() {
+            assert_eq!(
+                Value::Choice(
+                    [
+                        Value::Datatype(Datatype("Integer")),
+                        Value::LiteralSet([Literal::Exact("foo".into())].into()),
+                    ]
+                    .into()
+                )
+                .merge(&Value::LiteralSet([Literal::Exact("bar".into())].into())),
+                Value::Choice(
+                    [
+                        Value::Datatype(Datatype("Integer")),
+                        Value::LiteralSet(
+                            [Literal::Exact("bar".into()), Literal::Exact("foo".into())].into()
+                        ),
+                    ]
+                    .into()
+                )
+            );
+        }
+
+        #[test]
+        fn merge_choices() {
+            assert_eq!(
+                Value::Choice(
+                    [
+                        Value::Datatype(Datatype("Integer")),
+                        Value::LiteralSet([Literal::Exact("foo".into())].into()),
+                    ]
+                    .into()
+                )
+                .merge(&Value::Choice(
+                    [
+                        Value::Datatype(Datatype("Float")),
+                        Value::LiteralSet([Literal::Exact("bar".into())].into()),
+                    ]
+                    .into()
+                )),
+                Value::Choice(
+                    [
+                        Value::Datatype(Datatype("Float")),
+                        Value::Datatype(Datatype("Integer")),
+                        Value::LiteralSet(
+                            [Literal::Exact("bar".into()), Literal::Exact("foo".into())].into()
+                        ),
+                    ]
+                    .into()
+                )
+            );
+        }
+
+        #[test]
+        fn merge_same_datatypes() {
+            assert_eq!(
+                Value::Datatype(Datatype("Integer")).merge(&Value::Datatype(Datatype("Integer"))),
+                Value::Datatype(Datatype("Integer"))
             );
         }
 
@@ -131,6 +270,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn generate_choice() {
+        assert_eq!(
+            generate_value(&Value::Choice(
+                [
+                    Value::Datatype(Datatype("Integer")),
+                    Value::LiteralSet([Literal::Exact("foo".into())].into()),
+                ]
+                .into()
+            ))
+            .to_string(),
+            quote!(Value::Choice(&[
+                Value::Datatype(Datatype::Integer),
+                Value::LiteralSet(&[Literal::Exact("foo")])
+            ]))
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn generate_list() {
+        assert_eq!(
+            generate_value(&Value::List(Value::Datatype(Datatype("Integer")).into())).to_string(),
+            quote!(Value::List(&Value::Datatype(Datatype::Integer))).to_string()
+        );
+    }
+
     #[test]
     fn generate_any_value() {
         assert_eq!(
@@ -0,0 +1,566 @@
+// Conformance of roles, states, and properties in WAI-ARIA which content models
+// of schemas cannot describe.
+
+use crate::{
+    datatype::Datatype,
+    error::{AriaError, MarkupError},
+    literal::Literal,
+    value::Value,
+};
+use alloc::collections::{BTreeMap, BTreeSet};
+use muffy_document::document::Element;
+
+const HEADING_ELEMENTS: &[&str] = &["h1", "h2", "h3", "h4", "h5", "h6"];
+const NAMING_ATTRIBUTES: &[&str] = &["aria-label", "aria-labelledby"];
+const REFERENCE_ATTRIBUTES: &[&str] = &["aria-describedby", "aria-labelledby"];
+
+const BOOLEAN: Value = Value::LiteralSet(&[Literal::Exact("false"), Literal::Exact("true")]);
+const OPTIONAL_BOOLEAN: Value = Value::LiteralSet(&[
+    Literal::Exact("false"),
+    Literal::Exact("true"),
+    Literal::Exact("undefined"),
+]);
+const TRISTATE: Value = Value::LiteralSet(&[
+    Literal::Exact("false"),
+    Literal::Exact("mixed"),
+    Literal::Exact("true"),
+    Literal::Exact("undefined"),
+]);
+const COUNT: Value = Value::Choice(&[
+    Value::Datatype(Datatype::IntegerNonNegative),
+    Value::LiteralSet(&[Literal::Exact("-1")]),
+]);
+
+// Value types of states and properties sorted by their names.
+const ATTRIBUTES: &[(&str, Value)] = &[
+    ("aria-activedescendant", Value::Datatype(Datatype::IdRef)),
+    ("aria-atomic", BOOLEAN),
+    (
+        "aria-autocomplete",
+        Value::LiteralSet(&[
+            Literal::Exact("both"),
+            Literal::Exact("inline"),
+            Literal::Exact("list"),
+            Literal::Exact("none"),
+        ]),
+    ),
+    ("aria-busy", BOOLEAN),
+    ("aria-checked", TRISTATE),
+    ("aria-colcount", COUNT),
+    ("aria-colindex", Value::Datatype(Datatype::IntegerPositive)),
+    ("aria-colspan", Value::Datatype(Datatype::IntegerPositive)),
+    ("aria-controls", Value::Datatype(Datatype::IdRefs)),
+    (
+        "aria-current",
+        Value::LiteralSet(&[
+            Literal::Exact("date"),
+            Literal::Exact("false"),
+            Literal::Exact("location"),
+            Literal::Exact("page"),
+            Literal::Exact("step"),
+            Literal::Exact("time"),
+            Literal::Exact("true"),
+        ]),
+    ),
+    ("aria-describedby", Value::Datatype(Datatype::IdRefs)),
+    ("aria-details", Value::Datatype(Datatype::IdRef)),
+    ("aria-disabled", BOOLEAN),
+    ("aria-errormessage", Value::Datatype(Datatype::IdRef)),
+    ("aria-expanded", OPTIONAL_BOOLEAN),
+    ("aria-flowto", Value::Datatype(Datatype::IdRefs)),
+    (
+        "aria-haspopup",
+        Value::LiteralSet(&[
+            Literal::Exact("dialog"),
+            Literal::Exact("false"),
+            Literal::Exact("grid"),
+            Literal::Exact("listbox"),
+            Literal::Exact("menu"),
+            Literal::Exact("tree"),
+            Literal::Exact("true"),
+        ]),
+    ),
+    ("aria-hidden", OPTIONAL_BOOLEAN),
+    (
+        "aria-invalid",
+        Value::LiteralSet(&[
+            Literal::Exact("false"),
+            Literal::Exact("grammar"),
+            Literal::Exact("spelling"),
+            Literal::Exact("true"),
+        ]),
+    ),
+    ("aria-level", Value::Datatype(Datatype::IntegerPositive)),
+    (
+        "aria-live",
+        Value::LiteralSet(&[
+            Literal::Exact("assertive"),
+            Literal::Exact("off"),
+            Literal::Exact("polite"),
+        ]),
+    ),
+    ("aria-modal", BOOLEAN),
+    ("aria-multiline", BOOLEAN),
+    ("aria-multiselectable", BOOLEAN),
+    (
+        "aria-orientation",
+        Value::LiteralSet(&[
+            Literal::Exact("horizontal"),
+            Literal::Exact("undefined"),
+            Literal::Exact("vertical"),
+        ]),
+    ),
+    ("aria-owns", Value::Datatype(Datatype::IdRefs)),
+    ("aria-posinset", Value::Datatype(Datatype::IntegerPositive)),
+    ("aria-pressed", TRISTATE),
+    ("aria-readonly", BOOLEAN),
+    (
+        "aria-relevant",
+        Value::Choice(&[
+            Value::LiteralSet(&[Literal::Token("all")]),
+            Value::List(&Value::LiteralSet(&[
+                Literal::Exact("additions"),
+                Literal::Exact("removals"),
+                Literal::Exact("text"),
+            ])),
+        ]),
+    ),
+    ("aria-required", BOOLEAN),
+    ("aria-rowcount", COUNT),
+    ("aria-rowindex", Value::Datatype(Datatype::IntegerPositive)),
+    (
+        "aria-rowspan",
+        Value::Datatype(Datatype::IntegerNonNegative),
+    ),
+    ("aria-selected", OPTIONAL_BOOLEAN),
+    ("aria-setsize", COUNT),
+    (
+        "aria-sort",
+        Value::LiteralSet(&[
+            Literal::Exact("ascending"),
+            Literal::Exact("descending"),
+            Literal::Exact("none"),
+            Literal::Exact("other"),
+        ]),
+    ),
+    ("aria-valuemax", Value::Datatype(Datatype::Float)),
+    ("aria-valuemin", Value::Datatype(Datatype::Float)),
+    ("aria-valuenow", Value::Datatype(Datatype::Float)),
+];
+
+struct Role {
+    name: &'static str,
+    required: &'static [&'static str],
+    prohibited: &'static [&'static str],
+}
+
+impl Role {
+    const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            required: &[],
+            prohibited: &[],
+        }
+    }
+
+    const fn require(mut self, names: &'static [&'static str]) -> Self {
+        self.required = names;
+        self
+    }
+
+    const fn prohibit(mut self, names: &'static [&'static str]) -> Self {
+        self.prohibited = names;
+        self
+    }
+}
+
+// Concrete roles sorted by their names.
+const ROLES: &[Role] = &[
+    Role::new("alert"),
+    Role::new("alertdialog"),
+    Role::new("application"),
+    Role::new("article"),
+    Role::new("banner"),
+    Role::new("blockquote"),
+    Role::new("button"),
+    Role::new("caption").prohibit(NAMING_ATTRIBUTES),
+    Role::new("cell"),
+    Role::new("checkbox").require(&["aria-checked"]),
+    Role::new("code").prohibit(NAMING_ATTRIBUTES),
+    Role::new("columnheader"),
+    Role::new("combobox").require(&["aria-expanded"]),
+    Role::new("complementary"),
+    Role::new("contentinfo"),
+    Role::new("definition"),
+    Role::new("deletion").prohibit(NAMING_ATTRIBUTES),
+    Role::new("dialog"),
+    Role::new("directory"),
+    Role::new("doc-abstract"),
+    Role::new("doc-acknowledgments"),
+    Role::new("doc-afterword"),
+    Role::new("doc-appendix"),
+    Role::new("doc-backlink"),
+    Role::new("doc-biblioentry"),
+    Role::new("doc-bibliography"),
+    Role::new("doc-biblioref"),
+    Role::new("doc-chapter"),
+    Role::new("doc-colophon"),
+    Role::new("doc-conclusion"),
+    Role::new("doc-cover"),
+    Role::new("doc-credit"),
+    Role::new("doc-credits"),
+    Role::new("doc-dedication"),
+    Role::new("doc-endnote"),
+    Role::new("doc-endnotes"),
+    Role::new("doc-epigraph"),
+    Role::new("doc-epilogue"),
+    Role::new("doc-errata"),
+    Role::new("doc-example"),
+    Role::new("doc-footnote"),
+    Role::new("doc-foreword"),
+    Role::new("doc-glossary"),
+    Role::new("doc-glossref"),
+    Role::new("doc-index"),
+    Role::new("doc-introduction"),
+    Role::new("doc-noteref"),
+    Role::new("doc-notice"),
+    Role::new("doc-pagebreak"),
+    Role::new("doc-pagelist"),
+    Role::new("doc-part"),
+    Role::new("doc-preface"),
+    Role::new("doc-prologue"),
+    Role::new("doc-pullquote"),
+    Role::new("doc-qna"),
+    Role::new("doc-subtitle"),
+    Role::new("doc-tip"),
+    Role::new("doc-toc"),
+    Role::new("document"),
+    Role::new("emphasis").prohibit(NAMING_ATTRIBUTES),
+    Role::new("feed"),
+    Role::new("figure"),
+    Role::new("form"),
+    Role::new("generic").prohibit(NAMING_ATTRIBUTES),
+    Role::new("graphics-document"),
+    Role::new("graphics-object"),
+    Role::new("graphics-symbol"),
+    Role::new("grid"),
+    Role::new("gridcell"),
+    Role::new("group"),
+    Role::new("heading").require(&["aria-level"]),
+    Role::new("img"),
+    Role::new("insertion").prohibit(NAMING_ATTRIBUTES),
+    Role::new("link"),
+    Role::new("list"),
+    Role::new("listbox"),
+    Role::new("listitem"),
+    Role::new("log"),
+    Role::new("main"),
+    Role::new("mark"),
+    Role::new("marquee"),
+    Role::new("math"),
+    Role::new("menu"),
+    Role::new("menubar"),
+    Role::new("menuitem"),
+    Role::new("menuitemcheckbox").require(&["aria-checked"]),
+    Role::new("menuitemradio").require(&["aria-checked"]),
+    Role::new("meter").require(&["aria-valuenow"]),
+    Role::new("navigation"),
+    Role::new("none").prohibit(NAMING_ATTRIBUTES),
+    Role::new("note"),
+    Role::new("option"),
+    Role::new("paragraph").prohibit(NAMING_ATTRIBUTES),
+    Role::new("presentation").prohibit(NAMING_ATTRIBUTES),
+    Role::new("progressbar"),
+    Role::new("radio").require(&["aria-checked"]),
+    Role::new("radiogroup"),
+    Role::new("region"),
+    Role::new("row"),
+    Role::new("rowgroup"),
+    Role::new("rowheader"),
+    Role::new("scrollbar").require(&["aria-valuenow"]),
+    Role::new("search"),
+    Role::new("searchbox"),
+    Role::new("separator"),
+    Role::new("slider").require(&["aria-valuenow"]),
+    Role::new("spinbutton"),
+    Role::new("status"),
+    Role::new("strong").prohibit(NAMING_ATTRIBUTES),
+    Role::new("subscript").prohibit(NAMING_ATTRIBUTES),
+    Role::new("suggestion"),
+    Role::new("superscript").prohibit(NAMING_ATTRIBUTES),
+    Role::new("switch").require(&["aria-checked"]),
+    Role::new("tab"),
+    Role::new("table"),
+    Role::new("tablist"),
+    Role::new("tabpanel"),
+    Role::new("term"),
+    Role::new("textbox"),
+    Role::new("time"),
+    Role::new("timer"),
+    Role::new("toolbar"),
+    Role::new("tooltip"),
+    Role::new("tree"),
+    Role::new("treegrid"),
+    Role::new("treeitem"),
+];
+
+/// Validates roles, states, and properties of an element.
+///
+/// ID references are resolved by a predicate of whether an ID exists in the
+/// document.
+pub fn validate_aria_element(
+    element: &Element,
+    has_id: impl Fn(&str) -> bool,
+) -> Result<(), MarkupError> {
+    let role = find_role(element);
+    let mut invalid_attributes = BTreeMap::<String, BTreeSet<AriaError>>::new();
+
+    for (name, value) in element.attributes() {
+        let mut errors = BTreeSet::new();
+
+        if find_attribute(name).is_some_and(|attribute| !attribute.matches(value)) {
+            errors.insert(AriaError::InvalidValue);
+        }
+
+        if role.is_some_and(|role| role.prohibited.contains(&name)) {
+            errors.insert(AriaError::Prohibited);
+        }
+
+        if REFERENCE_ATTRIBUTES.contains(&name)
+            && value.split_ascii_whitespace().any(|id| !has_id(id))
+        {
+            errors.insert(AriaError::UndefinedReference);
+        }
+
+        if !errors.is_empty() {
+            invalid_attributes
+                .entry(name.into())
+                .or_default()
+                .extend(errors);
+        }
+    }
+
+    let missing_attributes = role
+        .into_iter()
+        .flat_map(|role| role.required)
+        .filter(|name| {
+            !element.attributes().any(|(other, _)| other == **name)
+                && !has_implicit_attribute(element, name)
+        })
+        .map(|name| (*name).into())
+        .collect::<BTreeSet<_>>();
+
+    if invalid_attributes.is_empty() && missing_attributes.is_empty() {
+        Ok(())
+    } else {
+        Err(MarkupError::InvalidAria {
+            invalid_attributes,
+            missing_attributes,
+        })
+    }
+}
+
+// Browsers use the first role they recognize.
+fn find_role(element: &Element) -> Option<&'static Role> {
+    element
+        .attributes()
+        .find(|(name, _)| *name == "role")?
+        .1
+        .split_ascii_whitespace()
+        .find_map(|name| {
+            ROLES
+                .binary_search_by(|role| role.name.cmp(name))
+                .ok()
+                .map(|index| &ROLES[index])
+        })
+}
+
+fn find_attribute(name: &str) -> Option<&'static Value> {
+    ATTRIBUTES
+        .binary_search_by(|(other, _)| other.cmp(&name))
+        .ok()
+        .map(|index| &ATTRIBUTES[index].1)
+}
+
+// Native elements provide some states and properties by themselves.
+fn has_implicit_attribute(element: &Element, name: &str) -> bool {
+    let input_type = || {
+        element
+            .attributes()
+            .find(|(name, _)| *name == "type")
+            .map(|(_, value)| value.to_ascii_lowercase())
+    };
+
+    match name {
+        "aria-checked" => {
+            element.name() == "input"
+                && input_type().is_some_and(|value| matches!(value.as_str(), "checkbox" | "radio"))
+        }
+        "aria-level" => HEADING_ELEMENTS.contains(&element.name()),
+        "aria-valuenow" => match element.name() {
+            "input" => {
+                input_type().is_some_and(|value| matches!(value.as_str(), "number" | "range"))
+            }
+            "meter" | "progress" => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn create_element(name: &str, attributes: Vec<(&str, &str)>) -> Element {
+        Element::new(
+            name.into(),
+            attributes
+                .into_iter()
+                .map(|(name, value)| (name.into(), value.into()))
+                .collect(),
+            vec![],
+        )
+    }
+
+    fn validate(element: &Element) -> Result<(), MarkupError> {
+        validate_aria_element(element, |id| id == "foo")
+    }
+
+    fn invalid_aria(
+        invalid_attributes: &[(&str, AriaError)],
+        missing_attributes: &[&str],
+    ) -> MarkupError {
+        MarkupError::InvalidAria {
+            invalid_attributes: invalid_attributes
+                .iter()
+                .map(|(name, error)| ((*name).into(), [error.clone()].into()))
+                .collect(),
+            missing_attributes: missing_attributes
+                .iter()
+                .map(|name| (*name).into())
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn sort_tables() {
+        assert!(ATTRIBUTES.is_sorted_by_key(|(name, _)| *name));
+        assert!(ROLES.is_sorted_by_key(|role| role.name));
+    }
+
+    #[test]
+    fn validate_valid_element() {
+        assert_eq!(
+            validate(&create_element(
+                "div",
+                vec![
+                    ("role", "checkbox"),
+                    ("aria-checked", "mixed"),
+                    ("aria-labelledby", "foo"),
+                ],
+            )),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn validate_missing_attribute() {
+        assert_eq!(
+            validate(&create_element("div", vec![("role", "checkbox")])),
+            Err(invalid_aria(&[], &["aria-checked"]))
+        );
+    }
+
+    #[test]
+    fn validate_implicit_attributes() {
+        assert_eq!(
+            validate(&create_element(
+                "input",
+                vec![("type", "Checkbox"), ("role", "switch")],
+            )),
+            Ok(())
+        );
+        assert_eq!(
+            validate(&create_element("h2", vec![("role", "heading")])),
+            Ok(())
+        );
+        assert_eq!(
+            validate(&create_element("div", vec![("role", "heading")])),
+            Err(invalid_aria(&[], &["aria-level"]))
+        );
+    }
+
+    #[test]
+    fn validate_first_recognized_role() {
+        assert_eq!(
+            validate(&create_element("div", vec![("role", "foo slider button")])),
+            Err(invalid_aria(&[], &["aria-valuenow"]))
+        );
+    }
+
+    #[test]
+    fn validate_prohibited_attribute() {
+        assert_eq!(
+            validate(&create_element(
+                "span",
+                vec![("role", "presentation"), ("aria-label", "foo")],
+            )),
+            Err(invalid_aria(&[("aria-label", AriaError::Prohibited)], &[]))
+        );
+    }
+
+    #[test]
+    fn validate_invalid_values() {
+        assert_eq!(
+            validate(&create_element(
+                "div",
+                vec![
+                    ("aria-hidden", "yes"),
+                    ("aria-level", "0"),
+                    ("aria-label", "foo"),
+                ],
+            )),
+            Err(invalid_aria(
+                &[
+                    ("aria-hidden", AriaError::InvalidValue),
+                    ("aria-level", AriaError::InvalidValue),
+                ],
+                &[]
+            ))
+        );
+    }
+
+    #[test]
+    fn validate_relevant_tokens() {
+        for value in ["all", "additions text", "removals"] {
+            assert_eq!(
+                validate(&create_element("div", vec![("aria-relevant", value)])),
+                Ok(())
+            );
+        }
+
+        assert_eq!(
+            validate(&create_element("div", vec![("aria-relevant", "all text")])),
+            Err(invalid_aria(
+                &[("aria-relevant", AriaError::InvalidValue)],
+                &[]
+            ))
+        );
+    }
+
+    #[test]
+    fn validate_undefined_references() {
+        assert_eq!(
+            validate(&create_element(
+                "div",
+                vec![("aria-describedby", "foo bar"), ("aria-labelledby", "foo"),],
+            )),
+            Err(invalid_aria(
+                &[("aria-describedby", AriaError::UndefinedReference)],
+                &[]
+            ))
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+
+/// A content category.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ContentCategory {
+    /// Flow content.
+    Flow,
+    /// Phrasing content.
+    Phrasing,
+}
+
+impl ContentCategory {
+    /// Returns a name of a built-in element whose rule represents the
+    /// category.
+    pub(crate) const fn element_name(self) -> &'static str {
+        match self {
+            Self::Flow => "div",
+            Self::Phrasing => "span",
+        }
+    }
+}
+
+/// A custom element declaration.
+///
+/// Custom elements are validated by rules of built-in elements of their
+/// content models merged with their own attributes.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CustomElement {
+    attributes: BTreeMap<String, Option<BTreeSet<String>>>,
+    category: ContentCategory,
+    content: ContentCategory,
+}
+
+impl CustomElement {
+    /// Creates a custom element declaration.
+    ///
+    /// By default, an element is phrasing content and has flow content as its
+    /// children.
+    pub const fn new() -> Self {
+        Self {
+            attributes: BTreeMap::new(),
+            category: ContentCategory::Phrasing,
+            content: ContentCategory::Flow,
+        }
+    }
+
+    /// Returns attributes with their allowed values.
+    ///
+    /// Attributes without value sets allow any values.
+    pub const fn attributes(&self) -> &BTreeMap<String, Option<BTreeSet<String>>> {
+        &self.attributes
+    }
+
+    /// Returns a content category the element belongs to.
+    pub const fn category(&self) -> ContentCategory {
+        self.category
+    }
+
+    /// Returns a content category of children.
+    pub const fn content(&self) -> ContentCategory {
+        self.content
+    }
+
+    /// Sets attributes.
+    pub fn set_attributes(
+        mut self,
+        attributes: BTreeMap<String, Option<BTreeSet<String>>>,
+    ) -> Self {
+        self.attributes = attributes;
+        self
+    }
+
+    /// Sets a content category the element belongs to.
+    pub const fn set_category(mut self, category: ContentCategory) -> Self {
+        self.category = category;
+        self
+    }
+
+    /// Sets a content category of children.
+    pub const fn set_content(mut self, content: ContentCategory) -> Self {
+        self.content = content;
+        self
+    }
+}
+
+impl Default for CustomElement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
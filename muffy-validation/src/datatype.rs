@@ -0,0 +1,338 @@
+mod name;
+mod number;
+mod text;
+mod time;
+
+use self::{
+    name::{is_custom_element_name, is_name, is_nc_name, is_nm_token},
+    number::{
+        is_decimal, is_float, is_float_list, is_integer, is_non_negative_float,
+        is_non_negative_integer, is_xsd_integer, is_xsd_non_negative_integer,
+        is_xsd_positive_integer, parse_float,
+    },
+    text::{is_email_address, is_iri, is_iri_reference, is_language_tag, is_mime_type},
+    time::{
+        is_date, is_global_date_time, is_local_date_time, is_month, is_time, is_time_datetime,
+        is_week,
+    },
+};
+use muffy_validation_macro::datatype;
+
+const BROWSING_CONTEXT_KEYWORDS: &[&str] = &["_blank", "_parent", "_self", "_top"];
+
+/// A datatype of attribute values.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Datatype {
+    // HTML datatypes in the `http://whattf.org/datatype-draft` library.
+    BrowsingContext,
+    BrowsingContextOrKeyword,
+    Circle,
+    CustomElementName,
+    Date,
+    DatetimeLocal,
+    DatetimeTz,
+    EmailAddress,
+    EmailAddressList,
+    Float,
+    FloatNonNegative,
+    FloatPositive,
+    HashName,
+    Id,
+    IdRef,
+    IdRefs,
+    Integer,
+    IntegerNonNegative,
+    IntegerPositive,
+    Iri,
+    IriRef,
+    IriRefHttpOrHttps,
+    Language,
+    MetaCharset,
+    MimeType,
+    Month,
+    NonEmptyString,
+    Polyline,
+    Rectangle,
+    SimpleColor,
+    StringWithoutLineBreaks,
+    Time,
+    TimeDatetime,
+    Week,
+    XmlName,
+    // XML Schema datatypes whose values can be surrounded by whitespace.
+    XsdDecimal,
+    XsdInteger,
+    XsdLanguage,
+    XsdNcName,
+    XsdNmToken,
+    XsdNmTokens,
+    XsdNonNegativeInteger,
+    XsdPositiveInteger,
+    XsdUnsignedLong,
+}
+
+impl Datatype {
+    /// Resolves a datatype in a library. Datatypes without any constraints or
+    /// unknown to us resolve to `None`.
+    pub fn from_name(library: &str, name: &str) -> Option<Self> {
+        datatype!(library, name)
+    }
+
+    pub fn matches(&self, value: &str) -> bool {
+        match self {
+            Self::BrowsingContext => !value.is_empty() && !value.starts_with('_'),
+            Self::BrowsingContextOrKeyword => {
+                Self::BrowsingContext.matches(value)
+                    || BROWSING_CONTEXT_KEYWORDS
+                        .iter()
+                        .any(|keyword| value.eq_ignore_ascii_case(keyword))
+            }
+            Self::Circle => is_float_list(
+                value,
+                |numbers| matches!(numbers, [_, _, radius] if *radius > 0.0),
+            ),
+            Self::CustomElementName => is_custom_element_name(value),
+            Self::Date => is_date(value),
+            Self::DatetimeLocal => is_local_date_time(value),
+            Self::DatetimeTz => is_global_date_time(value),
+            Self::EmailAddress => is_email_address(value),
+            Self::EmailAddressList => value
+                .split(',')
+                .all(|address| is_email_address(address.trim_ascii())),
+            Self::Float => is_float(value),
+            Self::FloatNonNegative => is_non_negative_float(value),
+            Self::FloatPositive => {
+                is_non_negative_float(value) && parse_float(value).is_some_and(|value| value > 0.0)
+            }
+            Self::HashName => value.len() > 1 && value.starts_with('#'),
+            Self::Id | Self::IdRef => {
+                !value.is_empty()
+                    && !value.contains(|character: char| character.is_ascii_whitespace())
+            }
+            Self::IdRefs => value.split_ascii_whitespace().next().is_some(),
+            Self::Integer => is_integer(value),
+            Self::IntegerNonNegative => is_non_negative_integer(value),
+            Self::IntegerPositive => {
+                is_non_negative_integer(value) && value.bytes().any(|byte| byte != b'0')
+            }
+            Self::Iri => is_iri(value.trim_ascii()),
+            Self::IriRef => is_iri_reference(value.trim_ascii()),
+            Self::IriRefHttpOrHttps => {
+                let value = value.trim_ascii();
+
+                is_iri_reference(value)
+                    && (!is_iri(value)
+                        || ["http:", "https:"].iter().any(|scheme| {
+                            value
+                                .get(..scheme.len())
+                                .is_some_and(|prefix| prefix.eq_ignore_ascii_case(scheme))
+                        }))
+            }
+            Self::Language => is_language_tag(value),
+            Self::MetaCharset => value.eq_ignore_ascii_case("utf-8"),
+            Self::MimeType => is_mime_type(value),
+            Self::Month => is_month(value),
+            Self::NonEmptyString => !value.is_empty(),
+            Self::Polyline => is_float_list(value, |numbers| {
+                numbers.len() >= 6 && numbers.len().is_multiple_of(2)
+            }),
+            Self::Rectangle => is_float_list(
+                value,
+                |numbers| matches!(numbers, [left, top, right, bottom] if left < right && top < bottom),
+            ),
+            Self::SimpleColor => {
+                value.len() == 7
+                    && value.starts_with('#')
+                    && value[1..].bytes().all(|byte| byte.is_ascii_hexdigit())
+            }
+            Self::StringWithoutLineBreaks => !value.contains(['\n', '\r']),
+            Self::Time => is_time(value),
+            Self::TimeDatetime => is_time_datetime(value),
+            Self::Week => is_week(value),
+            Self::XmlName => is_name(value),
+            Self::XsdDecimal => is_decimal(value.trim_ascii()),
+            Self::XsdInteger => is_xsd_integer(value.trim_ascii()),
+            Self::XsdLanguage => {
+                let mut subtags = value.trim_ascii().split('-');
+
+                subtags.next().is_some_and(|subtag| {
+                    (1..=8).contains(&subtag.len())
+                        && subtag.bytes().all(|byte| byte.is_ascii_alphabetic())
+                }) && subtags.all(|subtag| {
+                    (1..=8).contains(&subtag.len())
+                        && subtag.bytes().all(|byte| byte.is_ascii_alphanumeric())
+                })
+            }
+            Self::XsdNcName => is_nc_name(value.trim_ascii()),
+            Self::XsdNmToken => is_nm_token(value.trim_ascii()),
+            Self::XsdNmTokens => {
+                value.split_ascii_whitespace().next().is_some()
+                    && value.split_ascii_whitespace().all(is_nm_token)
+            }
+            Self::XsdNonNegativeInteger => is_xsd_non_negative_integer(value.trim_ascii()),
+            Self::XsdPositiveInteger => is_xsd_positive_integer(value.trim_ascii()),
+            Self::XsdUnsignedLong => {
+                let value = value.trim_ascii();
+
+                is_xsd_non_negative_integer(value)
+                    && value
+                        .strip_prefix(['+', '-'])
+                        .unwrap_or(value)
+                        .parse::<u64>()
+                        .is_ok()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HTML_DATATYPE_LIBRARY: &str = "http://whattf.org/datatype-draft";
+    const XSD_DATATYPE_LIBRARY: &str = "http://www.w3.org/2001/XMLSchema-datatypes";
+
+    #[test]
+    fn resolve_datatype_name() {
+        assert!(matches!(
+            Datatype::from_name(HTML_DATATYPE_LIBRARY, "integer-non-negative"),
+            Some(Datatype::IntegerNonNegative)
+        ));
+        assert!(matches!(
+            Datatype::from_name(XSD_DATATYPE_LIBRARY, "integer"),
+            Some(Datatype::XsdInteger)
+        ));
+        assert!(Datatype::from_name(XSD_DATATYPE_LIBRARY, "anyURI").is_none());
+        assert!(Datatype::from_name("http://foo.example/", "integer").is_none());
+    }
+
+    #[test]
+    fn match_browsing_context() {
+        assert!(Datatype::BrowsingContext.matches("foo"));
+        assert!(!Datatype::BrowsingContext.matches(""));
+        assert!(!Datatype::BrowsingContext.matches("_foo"));
+        assert!(!Datatype::BrowsingContext.matches("_blank"));
+    }
+
+    #[test]
+    fn match_browsing_context_or_keyword() {
+        assert!(Datatype::BrowsingContextOrKeyword.matches("foo"));
+        assert!(Datatype::BrowsingContextOrKeyword.matches("_blank"));
+        assert!(Datatype::BrowsingContextOrKeyword.matches("_TOP"));
+        assert!(!Datatype::BrowsingContextOrKeyword.matches("_foo"));
+    }
+
+    #[test]
+    fn match_area_coordinates() {
+        assert!(Datatype::Circle.matches("1,2,3"));
+        assert!(Datatype::Circle.matches("1.5, 2, 3"));
+        assert!(!Datatype::Circle.matches("1,2,0"));
+        assert!(!Datatype::Circle.matches("1,2"));
+        assert!(Datatype::Rectangle.matches("0,0,10,20"));
+        assert!(!Datatype::Rectangle.matches("10,0,0,20"));
+        assert!(!Datatype::Rectangle.matches("0,0,10"));
+        assert!(Datatype::Polyline.matches("0,0,10,0,10,10"));
+        assert!(!Datatype::Polyline.matches("0,0,10,0,10"));
+        assert!(!Datatype::Polyline.matches("0,0,10,0"));
+    }
+
+    #[test]
+    fn match_email_address_list() {
+        assert!(Datatype::EmailAddressList.matches("foo@bar.com"));
+        assert!(Datatype::EmailAddressList.matches("foo@bar.com, baz@qux.com"));
+        assert!(!Datatype::EmailAddressList.matches("foo@bar.com, baz"));
+    }
+
+    #[test]
+    fn match_hash_name() {
+        assert!(Datatype::HashName.matches("#foo"));
+        assert!(!Datatype::HashName.matches("#"));
+        assert!(!Datatype::HashName.matches("foo"));
+    }
+
+    #[test]
+    fn match_id() {
+        assert!(Datatype::Id.matches("foo"));
+        assert!(Datatype::Id.matches("1"));
+        assert!(!Datatype::Id.matches(""));
+        assert!(!Datatype::Id.matches("foo bar"));
+        assert!(Datatype::IdRefs.matches("foo bar"));
+        assert!(!Datatype::IdRefs.matches(" "));
+    }
+
+    #[test]
+    fn match_integers() {
+        assert!(Datatype::IntegerPositive.matches("42"));
+        assert!(!Datatype::IntegerPositive.matches("0"));
+        assert!(!Datatype::IntegerPositive.matches("00"));
+        assert!(Datatype::IntegerNonNegative.matches("0"));
+        assert!(!Datatype::IntegerNonNegative.matches("-1"));
+    }
+
+    #[test]
+    fn match_floats() {
+        assert!(Datatype::FloatPositive.matches("0.5"));
+        assert!(!Datatype::FloatPositive.matches("0"));
+        assert!(!Datatype::FloatPositive.matches("-1"));
+        assert!(Datatype::FloatNonNegative.matches("0"));
+        assert!(!Datatype::FloatNonNegative.matches("-0.5"));
+    }
+
+    #[test]
+    fn match_iri_reference_of_http_or_https() {
+        assert!(Datatype::IriRefHttpOrHttps.matches("https://foo.com"));
+        assert!(Datatype::IriRefHttpOrHttps.matches("HTTP://foo.com"));
+        assert!(Datatype::IriRefHttpOrHttps.matches("/foo"));
+        assert!(!Datatype::IriRefHttpOrHttps.matches("ftp://foo.com"));
+    }
+
+    #[test]
+    fn match_meta_charset() {
+        assert!(Datatype::MetaCharset.matches("utf-8"));
+        assert!(Datatype::MetaCharset.matches("UTF-8"));
+        assert!(!Datatype::MetaCharset.matches("shift_jis"));
+    }
+
+    #[test]
+    fn match_simple_color() {
+        assert!(Datatype::SimpleColor.matches("#00ff7F"));
+        assert!(!Datatype::SimpleColor.matches("#fff"));
+        assert!(!Datatype::SimpleColor.matches("red"));
+        assert!(!Datatype::SimpleColor.matches("#00ff7g"));
+    }
+
+    #[test]
+    fn match_strings() {
+        assert!(Datatype::NonEmptyString.matches(" "));
+        assert!(!Datatype::NonEmptyString.matches(""));
+        assert!(Datatype::StringWithoutLineBreaks.matches("foo bar"));
+        assert!(!Datatype::StringWithoutLineBreaks.matches("foo\nbar"));
+    }
+
+    #[test]
+    fn match_xsd_language() {
+        assert!(Datatype::XsdLanguage.matches("en"));
+        assert!(Datatype::XsdLanguage.matches(" en-US "));
+        assert!(!Datatype::XsdLanguage.matches("en_US"));
+        assert!(!Datatype::XsdLanguage.matches("1en"));
+    }
+
+    #[test]
+    fn match_xsd_integers() {
+        assert!(Datatype::XsdNonNegativeInteger.matches(" +0 "));
+        assert!(!Datatype::XsdNonNegativeInteger.matches("-1"));
+        assert!(Datatype::XsdPositiveInteger.matches("1"));
+        assert!(!Datatype::XsdPositiveInteger.matches("-0"));
+        assert!(Datatype::XsdUnsignedLong.matches("18446744073709551615"));
+        assert!(!Datatype::XsdUnsignedLong.matches("18446744073709551616"));
+    }
+
+    #[test]
+    fn match_xsd_names() {
+        assert!(Datatype::XsdNcName.matches("foo"));
+        assert!(!Datatype::XsdNcName.matches("foo:bar"));
+        assert!(Datatype::XsdNmToken.matches("1foo"));
+        assert!(Datatype::XsdNmTokens.matches("foo bar"));
+        assert!(!Datatype::XsdNmTokens.matches(""));
+    }
+}
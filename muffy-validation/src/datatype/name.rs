@@ -0,0 +1,95 @@
+// Names in the XML and HTML standards. Non-ASCII characters are accepted
+// without checking their ranges.
+
+const RESERVED_CUSTOM_ELEMENT_NAMES: &[&str] = &[
+    "annotation-xml",
+    "color-profile",
+    "font-face",
+    "font-face-format",
+    "font-face-name",
+    "font-face-src",
+    "font-face-uri",
+    "missing-glyph",
+];
+
+pub fn is_name(value: &str) -> bool {
+    let mut characters = value.chars();
+
+    characters
+        .next()
+        .is_some_and(|character| is_name_start_character(character) || character == ':')
+        && characters.all(|character| is_name_character(character) || character == ':')
+}
+
+pub fn is_nc_name(value: &str) -> bool {
+    let mut characters = value.chars();
+
+    characters.next().is_some_and(is_name_start_character) && characters.all(is_name_character)
+}
+
+pub fn is_nm_token(value: &str) -> bool {
+    !value.is_empty()
+        && value
+            .chars()
+            .all(|character| is_name_character(character) || character == ':')
+}
+
+pub fn is_custom_element_name(value: &str) -> bool {
+    value.starts_with(|character: char| character.is_ascii_lowercase())
+        && value.contains('-')
+        && value.chars().all(|character| {
+            matches!(character, '-' | '.' | '_' | '0'..='9' | 'a'..='z') || !character.is_ascii()
+        })
+        && !RESERVED_CUSTOM_ELEMENT_NAMES.contains(&value)
+}
+
+const fn is_name_start_character(character: char) -> bool {
+    character.is_ascii_alphabetic() || character == '_' || !character.is_ascii()
+}
+
+const fn is_name_character(character: char) -> bool {
+    is_name_start_character(character)
+        || character.is_ascii_digit()
+        || matches!(character, '-' | '.')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_name() {
+        assert!(is_name("foo"));
+        assert!(is_name("foo:bar"));
+        assert!(is_name("_foo-1.2"));
+        assert!(is_name("日本"));
+        assert!(!is_name(""));
+        assert!(!is_name("1foo"));
+        assert!(!is_name("foo bar"));
+    }
+
+    #[test]
+    fn check_nc_name() {
+        assert!(is_nc_name("foo"));
+        assert!(!is_nc_name("foo:bar"));
+        assert!(!is_nc_name("-foo"));
+    }
+
+    #[test]
+    fn check_nm_token() {
+        assert!(is_nm_token("1foo"));
+        assert!(is_nm_token("-foo:bar"));
+        assert!(!is_nm_token(""));
+        assert!(!is_nm_token("foo bar"));
+    }
+
+    #[test]
+    fn check_custom_element_name() {
+        assert!(is_custom_element_name("foo-bar"));
+        assert!(is_custom_element_name("math-α"));
+        assert!(!is_custom_element_name("foo"));
+        assert!(!is_custom_element_name("Foo-bar"));
+        assert!(!is_custom_element_name("-foo"));
+        assert!(!is_custom_element_name("font-face"));
+    }
+}
@@ -0,0 +1,145 @@
+pub fn is_integer(value: &str) -> bool {
+    is_non_negative_integer(value.strip_prefix('-').unwrap_or(value))
+}
+
+pub fn is_non_negative_integer(value: &str) -> bool {
+    is_digits(value)
+}
+
+pub fn is_float(value: &str) -> bool {
+    is_non_negative_float(value.strip_prefix('-').unwrap_or(value))
+}
+
+pub fn is_non_negative_float(value: &str) -> bool {
+    let (mantissa, exponent) = value
+        .split_once(['e', 'E'])
+        .map_or((value, None), |(mantissa, exponent)| {
+            (mantissa, Some(exponent))
+        });
+
+    (match mantissa.split_once('.') {
+        Some((integer, fraction)) => {
+            (integer.is_empty() || is_digits(integer)) && is_digits(fraction)
+        }
+        None => is_digits(mantissa),
+    }) && exponent
+        .is_none_or(|exponent| is_digits(exponent.strip_prefix(['+', '-']).unwrap_or(exponent)))
+}
+
+pub fn parse_float(value: &str) -> Option<f64> {
+    if is_float(value) {
+        value.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Parses a list of floating-point numbers separated by commas.
+pub fn is_float_list(value: &str, predicate: impl Fn(&[f64]) -> bool) -> bool {
+    value
+        .split(',')
+        .map(|number| parse_float(number.trim_ascii()))
+        .collect::<Option<Vec<_>>>()
+        .is_some_and(|numbers| predicate(&numbers))
+}
+
+pub fn is_xsd_integer(value: &str) -> bool {
+    is_digits(value.strip_prefix(['+', '-']).unwrap_or(value))
+}
+
+pub fn is_xsd_non_negative_integer(value: &str) -> bool {
+    is_xsd_integer(value) && (!value.starts_with('-') || is_zero(&value[1..]))
+}
+
+pub fn is_xsd_positive_integer(value: &str) -> bool {
+    is_xsd_integer(value) && !value.starts_with('-') && !is_zero(value.trim_start_matches('+'))
+}
+
+pub fn is_decimal(value: &str) -> bool {
+    let value = value.strip_prefix(['+', '-']).unwrap_or(value);
+
+    match value.split_once('.') {
+        Some((integer, fraction)) => {
+            (!integer.is_empty() || !fraction.is_empty())
+                && (integer.is_empty() || is_digits(integer))
+                && (fraction.is_empty() || is_digits(fraction))
+        }
+        None => is_digits(value),
+    }
+}
+
+fn is_digits(value: &str) -> bool {
+    !value.is_empty() && value.bytes().all(|byte| byte.is_ascii_digit())
+}
+
+fn is_zero(value: &str) -> bool {
+    value.bytes().all(|byte| byte == b'0')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_integer() {
+        assert!(is_integer("0"));
+        assert!(is_integer("42"));
+        assert!(is_integer("-42"));
+        assert!(!is_integer(""));
+        assert!(!is_integer("-"));
+        assert!(!is_integer("+42"));
+        assert!(!is_integer(" 42"));
+        assert!(!is_integer("4.2"));
+        assert!(!is_integer("x"));
+    }
+
+    #[test]
+    fn check_float() {
+        assert!(is_float("0"));
+        assert!(is_float("-1.5"));
+        assert!(is_float(".5"));
+        assert!(is_float("1e10"));
+        assert!(is_float("1.5E-10"));
+        assert!(!is_float(""));
+        assert!(!is_float("1."));
+        assert!(!is_float("+1"));
+        assert!(!is_float("1e"));
+        assert!(!is_float("abc"));
+        assert!(!is_float("Infinity"));
+    }
+
+    #[test]
+    fn check_float_list() {
+        assert!(is_float_list("1, 2,3", |numbers| numbers == [1.0, 2.0, 3.0]));
+        assert!(!is_float_list("1,,3", |_| true));
+        assert!(!is_float_list("", |_| true));
+    }
+
+    #[test]
+    fn check_xsd_integer() {
+        assert!(is_xsd_integer("+42"));
+        assert!(is_xsd_integer("-42"));
+        assert!(!is_xsd_integer("+"));
+        assert!(!is_xsd_integer("4.2"));
+    }
+
+    #[test]
+    fn check_xsd_signed_integers() {
+        assert!(is_xsd_non_negative_integer("0"));
+        assert!(is_xsd_non_negative_integer("-0"));
+        assert!(!is_xsd_non_negative_integer("-1"));
+        assert!(is_xsd_positive_integer("+1"));
+        assert!(!is_xsd_positive_integer("0"));
+        assert!(!is_xsd_positive_integer("-1"));
+    }
+
+    #[test]
+    fn check_decimal() {
+        assert!(is_decimal("42"));
+        assert!(is_decimal("-4.2"));
+        assert!(is_decimal("+4."));
+        assert!(is_decimal(".2"));
+        assert!(!is_decimal("."));
+        assert!(!is_decimal("1e2"));
+    }
+}
@@ -0,0 +1,142 @@
+use regex::Regex;
+use std::sync::LazyLock;
+use url::Url;
+
+const TOKEN_PATTERN: &str = r"[!#$%&'*+.^_`|~0-9a-z-]+";
+
+static BASE_URL: LazyLock<Url> =
+    LazyLock::new(|| Url::parse("http://localhost/").expect("valid base URL"));
+
+// A well-formed language tag of BCP 47. Tags are not checked against the
+// subtag registry.
+static LANGUAGE_TAG: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(concat!(
+        "(?i)^(?:",
+        "(?:[a-z]{2,3}(?:-[a-z]{3}){0,3}|[a-z]{4}|[a-z]{5,8})",
+        "(?:-[a-z]{4})?",
+        "(?:-(?:[a-z]{2}|[0-9]{3}))?",
+        "(?:-(?:[a-z0-9]{5,8}|[0-9][a-z0-9]{3}))*",
+        "(?:-[0-9a-wy-z](?:-[a-z0-9]{2,8})+)*",
+        "(?:-x(?:-[a-z0-9]{1,8})+)?",
+        "|x(?:-[a-z0-9]{1,8})+",
+        "|i-[a-z]{3,8}",
+        "|en-gb-oed|sgn-be-fr|sgn-be-nl|sgn-ch-de",
+        ")$",
+    ))
+    .expect("valid language tag pattern")
+});
+
+// A valid email address of the HTML standard.
+static EMAIL_ADDRESS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(concat!(
+        r"^[a-zA-Z0-9.!#$%&'*+/=?^_`{|}~-]+@",
+        r"[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?",
+        r"(?:\.[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?)*$",
+    ))
+    .expect("valid email address pattern")
+});
+
+static MIME_TYPE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r#"(?i)^{TOKEN_PATTERN}/{TOKEN_PATTERN}(?:[ \t]*;[ \t]*{TOKEN_PATTERN}=(?:{TOKEN_PATTERN}|"(?:[^"\\]|\\.)*"))*$"#
+    ))
+    .expect("valid MIME type pattern")
+});
+
+pub fn is_iri(value: &str) -> bool {
+    !contains_whitespace(value) && Url::parse(value).is_ok()
+}
+
+// URLs can be surrounded by spaces in HTML.
+pub fn is_iri_reference(value: &str) -> bool {
+    let value = value.trim_ascii();
+
+    !value.is_empty() && !contains_whitespace(value) && BASE_URL.join(value).is_ok()
+}
+
+pub fn is_language_tag(value: &str) -> bool {
+    LANGUAGE_TAG.is_match(value)
+}
+
+pub fn is_email_address(value: &str) -> bool {
+    EMAIL_ADDRESS.is_match(value)
+}
+
+pub fn is_mime_type(value: &str) -> bool {
+    MIME_TYPE.is_match(value)
+}
+
+fn contains_whitespace(value: &str) -> bool {
+    value.contains(|character: char| character.is_ascii_whitespace())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_iri() {
+        assert!(is_iri("https://foo.com/bar?baz#qux"));
+        assert!(is_iri("mailto:foo@bar.com"));
+        assert!(!is_iri("/foo"));
+        assert!(!is_iri("https://foo.com/foo bar"));
+        assert!(!is_iri("http://"));
+    }
+
+    #[test]
+    fn check_iri_reference() {
+        assert!(is_iri_reference("https://foo.com"));
+        assert!(is_iri_reference("/foo"));
+        assert!(is_iri_reference("../foo?bar"));
+        assert!(is_iri_reference("#foo"));
+        assert!(!is_iri_reference(""));
+        assert!(!is_iri_reference("/foo bar"));
+        assert!(!is_iri_reference("http://[foo]"));
+    }
+
+    #[test]
+    fn check_iri_reference_surrounded_by_whitespace() {
+        assert!(is_iri_reference(" /foo "));
+        assert!(is_iri_reference("\t\nhttps://foo.com\r\n"));
+        assert!(!is_iri_reference(" "));
+        assert!(!is_iri_reference(" /foo bar "));
+    }
+
+    #[test]
+    fn check_language_tag() {
+        assert!(is_language_tag("en"));
+        assert!(is_language_tag("en-US"));
+        assert!(is_language_tag("zh-Hant-TW"));
+        assert!(is_language_tag("es-419"));
+        assert!(is_language_tag("de-CH-1996"));
+        assert!(is_language_tag("en-a-bbb-x-ccc"));
+        assert!(is_language_tag("x-private"));
+        assert!(is_language_tag("i-klingon"));
+        assert!(!is_language_tag(""));
+        assert!(!is_language_tag("e"));
+        assert!(!is_language_tag("en_US"));
+        assert!(!is_language_tag("en-"));
+        assert!(!is_language_tag("english language"));
+    }
+
+    #[test]
+    fn check_email_address() {
+        assert!(is_email_address("foo@bar.com"));
+        assert!(is_email_address("foo.bar+baz@qux"));
+        assert!(!is_email_address("foo"));
+        assert!(!is_email_address("foo@"));
+        assert!(!is_email_address("foo@-bar.com"));
+        assert!(!is_email_address("foo bar@baz.com"));
+    }
+
+    #[test]
+    fn check_mime_type() {
+        assert!(is_mime_type("text/html"));
+        assert!(is_mime_type("Text/HTML; charset=utf-8"));
+        assert!(is_mime_type(r#"text/plain;format="flowed""#));
+        assert!(!is_mime_type("text"));
+        assert!(!is_mime_type("text/"));
+        assert!(!is_mime_type(" text/html"));
+        assert!(!is_mime_type("text/html;"));
+    }
+}
@@ -0,0 +1,315 @@
+// Date and time formats of the HTML standard.
+
+pub fn is_date(value: &str) -> bool {
+    parse_date(value).is_some()
+}
+
+pub fn is_month(value: &str) -> bool {
+    parse_month(value).is_some()
+}
+
+pub fn is_week(value: &str) -> bool {
+    value.split_once("-W").is_some_and(|(year, week)| {
+        parse_year(year).is_some_and(|year| {
+            parse_digits(week, 2).is_some_and(|week| (1..=week_count(year)).contains(&week))
+        })
+    })
+}
+
+pub fn is_time(value: &str) -> bool {
+    let Some((hour_minute, rest)) = value.split_at_checked(5) else {
+        return false;
+    };
+    let second = if rest.is_empty() {
+        None
+    } else if let Some(second) = rest.strip_prefix(':') {
+        Some(second)
+    } else {
+        return false;
+    };
+
+    hour_minute.split_once(':').is_some_and(|(hour, minute)| {
+        parse_digits(hour, 2).is_some_and(|hour| hour < 24)
+            && parse_digits(minute, 2).is_some_and(|minute| minute < 60)
+    }) && second.is_none_or(|second| {
+        let (second, fraction) = second
+            .split_once('.')
+            .map_or((second, None), |(second, fraction)| {
+                (second, Some(fraction))
+            });
+
+        parse_digits(second, 2).is_some_and(|second| second < 60)
+            && fraction.is_none_or(|fraction| {
+                (1..=3).contains(&fraction.len())
+                    && fraction.bytes().all(|byte| byte.is_ascii_digit())
+            })
+    })
+}
+
+pub fn is_local_date_time(value: &str) -> bool {
+    value
+        .split_once(['T', ' '])
+        .is_some_and(|(date, time)| is_date(date) && is_time(time))
+}
+
+pub fn is_global_date_time(value: &str) -> bool {
+    value.split_once(['T', ' ']).is_some_and(|(date, time)| {
+        is_date(date)
+            && time
+                .find(['Z', '+', '-'])
+                .is_some_and(|index| is_time(&time[..index]) && is_time_zone(&time[index..]))
+    })
+}
+
+/// Checks a value of the `datetime` attribute of `time` elements.
+pub fn is_time_datetime(value: &str) -> bool {
+    is_month(value)
+        || is_date(value)
+        || is_yearless_date(value)
+        || is_time(value)
+        || is_local_date_time(value)
+        || is_time_zone(value)
+        || is_global_date_time(value)
+        || is_week(value)
+        || parse_year(value).is_some()
+        || is_duration(value)
+}
+
+fn is_yearless_date(value: &str) -> bool {
+    value
+        .strip_prefix("--")
+        .unwrap_or(value)
+        .split_once('-')
+        .is_some_and(|(month, day)| {
+            parse_digits(month, 2).is_some_and(|month| {
+                (1..=12).contains(&month)
+                    && parse_digits(day, 2)
+                        .is_some_and(|day| (1..=day_count(4, month)).contains(&day))
+            })
+        })
+}
+
+fn is_time_zone(value: &str) -> bool {
+    if value == "Z" {
+        return true;
+    }
+
+    let Some(offset) = value.strip_prefix(['+', '-']) else {
+        return false;
+    };
+    let (hour, minute) = offset.split_once(':').unwrap_or_else(|| {
+        (
+            offset.get(..2).unwrap_or_default(),
+            offset.get(2..).unwrap_or_default(),
+        )
+    });
+
+    parse_digits(hour, 2).is_some_and(|hour| hour < 24)
+        && parse_digits(minute, 2).is_some_and(|minute| minute < 60)
+}
+
+fn is_duration(value: &str) -> bool {
+    if let Some(duration) = value.strip_prefix('P') {
+        let (date, time) = duration
+            .split_once('T')
+            .map_or((duration, None), |(date, time)| (date, Some(time)));
+
+        (!date.is_empty() || time.is_some_and(|time| !time.is_empty()))
+            && is_duration_components(date, &['D'])
+            && time.is_none_or(|time| {
+                !time.is_empty() && is_duration_components(time, &['H', 'M', 'S'])
+            })
+    } else {
+        let mut components = value.split_ascii_whitespace().peekable();
+
+        components.peek().is_some()
+            && components.all(|component| {
+                component
+                    .split_at_checked(component.len().saturating_sub(1))
+                    .is_some_and(|(number, unit)| {
+                        matches!(
+                            unit,
+                            "W" | "w" | "D" | "d" | "H" | "h" | "M" | "m" | "S" | "s"
+                        ) && is_duration_number(number, unit.eq_ignore_ascii_case("s"))
+                    })
+            })
+    }
+}
+
+fn is_duration_components(mut value: &str, units: &[char]) -> bool {
+    let mut units = units.iter();
+
+    while !value.is_empty() {
+        let Some(index) = value.find(|character: char| character.is_ascii_alphabetic()) else {
+            return false;
+        };
+        let unit = value[index..].chars().next().unwrap_or_default();
+
+        if !units.any(|&other| other == unit) || !is_duration_number(&value[..index], unit == 'S') {
+            return false;
+        }
+
+        value = &value[index + 1..];
+    }
+
+    true
+}
+
+fn is_duration_number(value: &str, fraction: bool) -> bool {
+    let (integer, decimals) = match value.split_once('.') {
+        Some((integer, decimals)) if fraction => (integer, Some(decimals)),
+        Some(_) => return false,
+        None => (value, None),
+    };
+
+    !integer.is_empty()
+        && integer.bytes().all(|byte| byte.is_ascii_digit())
+        && decimals.is_none_or(|decimals| {
+            (1..=3).contains(&decimals.len()) && decimals.bytes().all(|byte| byte.is_ascii_digit())
+        })
+}
+
+fn parse_date(value: &str) -> Option<(u32, u32, u32)> {
+    let (month, day) = value.rsplit_once('-')?;
+    let (year, month) = parse_month(month)?;
+    let day = parse_digits(day, 2)?;
+
+    (1..=day_count(year, month))
+        .contains(&day)
+        .then_some((year, month, day))
+}
+
+fn parse_month(value: &str) -> Option<(u32, u32)> {
+    let (year, month) = value.rsplit_once('-')?;
+    let year = parse_year(year)?;
+    let month = parse_digits(month, 2)?;
+
+    (1..=12).contains(&month).then_some((year, month))
+}
+
+fn parse_year(value: &str) -> Option<u32> {
+    if value.len() < 4 || !value.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+
+    value.parse().ok().filter(|&year| year > 0)
+}
+
+fn parse_digits(value: &str, length: usize) -> Option<u32> {
+    if value.len() == length && value.bytes().all(|byte| byte.is_ascii_digit()) {
+        value.parse().ok()
+    } else {
+        None
+    }
+}
+
+const fn is_leap_year(year: u32) -> bool {
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+}
+
+const fn day_count(year: u32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// A year has 53 weeks if it starts on Thursday, or on Wednesday in a leap year.
+const fn week_count(year: u32) -> u32 {
+    match (first_week_day(year), is_leap_year(year)) {
+        (4, _) | (3, true) => 53,
+        _ => 52,
+    }
+}
+
+// Returns a day of the week of January 1st from Sunday as 0.
+const fn first_week_day(year: u32) -> u32 {
+    let year = year - 1;
+
+    (year + year / 4 - year / 100 + year / 400 + 1) % 7
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_date() {
+        assert!(is_date("2024-02-29"));
+        assert!(is_date("12345-01-01"));
+        assert!(!is_date("2023-02-29"));
+        assert!(!is_date("2024-13-01"));
+        assert!(!is_date("2024-1-01"));
+        assert!(!is_date("0000-01-01"));
+        assert!(!is_date("yesterday"));
+    }
+
+    #[test]
+    fn check_month() {
+        assert!(is_month("2024-12"));
+        assert!(!is_month("2024-00"));
+        assert!(!is_month("24-12"));
+    }
+
+    #[test]
+    fn check_week() {
+        assert!(is_week("2020-W53"));
+        assert!(is_week("2021-W52"));
+        assert!(!is_week("2021-W53"));
+        assert!(!is_week("2021-W00"));
+    }
+
+    #[test]
+    fn check_time() {
+        assert!(is_time("23:59"));
+        assert!(is_time("23:59:59"));
+        assert!(is_time("23:59:59.999"));
+        assert!(!is_time("24:00"));
+        assert!(!is_time("23:60"));
+        assert!(!is_time("23:59:59."));
+        assert!(!is_time("23:59:59.1234"));
+        assert!(!is_time("2:00"));
+    }
+
+    #[test]
+    fn check_local_date_time() {
+        assert!(is_local_date_time("2024-01-01T00:00"));
+        assert!(is_local_date_time("2024-01-01 00:00:00"));
+        assert!(!is_local_date_time("2024-01-01"));
+        assert!(!is_local_date_time("2024-01-01T00:00Z"));
+    }
+
+    #[test]
+    fn check_global_date_time() {
+        assert!(is_global_date_time("2024-01-01T00:00Z"));
+        assert!(is_global_date_time("2024-01-01T00:00:00+09:00"));
+        assert!(is_global_date_time("2024-01-01 00:00-0500"));
+        assert!(!is_global_date_time("2024-01-01T00:00"));
+        assert!(!is_global_date_time("2024-01-01T00:00+24:00"));
+    }
+
+    #[test]
+    fn check_time_datetime() {
+        assert!(is_time_datetime("2024"));
+        assert!(is_time_datetime("2024-01"));
+        assert!(is_time_datetime("2024-01-01"));
+        assert!(is_time_datetime("01-01"));
+        assert!(is_time_datetime("--02-29"));
+        assert!(is_time_datetime("12:00"));
+        assert!(is_time_datetime("2024-01-01T12:00"));
+        assert!(is_time_datetime("+09:00"));
+        assert!(is_time_datetime("2024-01-01T12:00Z"));
+        assert!(is_time_datetime("2024-W01"));
+        assert!(is_time_datetime("PT1H30M"));
+        assert!(is_time_datetime("P1DT1.5S"));
+        assert!(is_time_datetime("1h 30m"));
+        assert!(!is_time_datetime("yesterday"));
+        assert!(!is_time_datetime("P"));
+        assert!(!is_time_datetime("PT"));
+        assert!(!is_time_datetime("P1H"));
+        assert!(!is_time_datetime("1.5h"));
+        assert!(!is_time_datetime(""));
+    }
+}
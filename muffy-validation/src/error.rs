@@ -6,6 +6,8 @@ use core::fmt::{self, Display, Formatter};
 pub enum MarkupError {
     /// An unknown tag.
     UnknownTag(String),
+    /// An element excluded from descendants of an ancestor.
+    ExcludedElement(String),
     /// Invalid element.
     InvalidElement {
         /// Invalid attributes.
@@ -17,12 +19,77 @@ pub enum MarkupError {
         /// Missing required children.
         missing_children: BTreeSet<String>,
     },
+    /// Invalid ARIA roles, states, or properties.
+    InvalidAria {
+        /// Invalid states and properties.
+        invalid_attributes: BTreeMap<String, BTreeSet<AriaError>>,
+        /// Missing states and properties required by a role.
+        missing_attributes: BTreeSet<String>,
+    },
+}
+
+impl MarkupError {
+    /// Codes of all error kinds.
+    pub const CODES: &[&str] = &[
+        "aria/invalid-value",
+        "aria/missing-attribute",
+        "aria/prohibited-attribute",
+        "aria/undefined-reference",
+        "markup/attribute-not-allowed",
+        "markup/child-not-allowed",
+        "markup/conflicting-attribute",
+        "markup/excluded-element",
+        "markup/invalid-attribute-value",
+        "markup/invalid-element",
+        "markup/misplaced-child",
+        "markup/missing-attribute",
+        "markup/missing-child",
+        "markup/unknown-tag",
+    ];
+
+    /// Returns a code of an error kind.
+    ///
+    /// If an element has multiple errors, the code of the first one is returned.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::UnknownTag(_) => "markup/unknown-tag",
+            Self::ExcludedElement(_) => "markup/excluded-element",
+            Self::InvalidElement {
+                invalid_attributes,
+                invalid_children,
+                missing_attributes,
+                missing_children,
+            } => {
+                if let Some(error) = invalid_attributes.values().flatten().next() {
+                    error.code()
+                } else if let Some(error) = invalid_children.values().flatten().next() {
+                    error.code()
+                } else if !missing_attributes.is_empty() {
+                    "markup/missing-attribute"
+                } else if !missing_children.is_empty() {
+                    "markup/missing-child"
+                } else {
+                    "markup/invalid-element"
+                }
+            }
+            Self::InvalidAria {
+                invalid_attributes, ..
+            } => invalid_attributes
+                .values()
+                .flatten()
+                .next()
+                .map_or("aria/missing-attribute", AriaError::code),
+        }
+    }
 }
 
 impl Display for MarkupError {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
         match self {
             Self::UnknownTag(tag) => write!(formatter, "unknown tag \"{tag}\""),
+            Self::ExcludedElement(ancestor) => {
+                write!(formatter, "not allowed as a descendant of \"{ancestor}\"")
+            }
             Self::InvalidElement {
                 invalid_attributes,
                 invalid_children,
@@ -42,6 +109,21 @@ impl Display for MarkupError {
                 .collect::<Vec<_>>()
                 .join(", ")
             ),
+            Self::InvalidAria {
+                invalid_attributes,
+                missing_attributes,
+            } => write!(
+                formatter,
+                "{}",
+                [
+                    format_errors("invalid ARIA attributes", invalid_attributes),
+                    format_names("missing ARIA attributes", missing_attributes),
+                ]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>()
+                .join(", ")
+            ),
         }
     }
 }
@@ -89,6 +171,17 @@ pub enum AttributeError {
     NotAllowed,
 }
 
+impl AttributeError {
+    /// Returns a code of an error kind.
+    pub const fn code(&self) -> &'static str {
+        match self {
+            Self::Conflict => "markup/conflicting-attribute",
+            Self::InvalidValue => "markup/invalid-attribute-value",
+            Self::NotAllowed => "markup/attribute-not-allowed",
+        }
+    }
+}
+
 impl Display for AttributeError {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
         match self {
@@ -108,6 +201,16 @@ pub enum ChildError {
     NotAllowed,
 }
 
+impl ChildError {
+    /// Returns a code of an error kind.
+    pub const fn code(&self) -> &'static str {
+        match self {
+            Self::Misplaced => "markup/misplaced-child",
+            Self::NotAllowed => "markup/child-not-allowed",
+        }
+    }
+}
+
 impl Display for ChildError {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
         match self {
@@ -117,10 +220,138 @@ impl Display for ChildError {
     }
 }
 
+/// An ARIA state or property error.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum AriaError {
+    /// An invalid value.
+    InvalidValue,
+    /// Prohibited by a role.
+    Prohibited,
+    /// A reference to an undefined ID.
+    UndefinedReference,
+}
+
+impl AriaError {
+    /// Returns a code of an error kind.
+    pub const fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidValue => "aria/invalid-value",
+            Self::Prohibited => "aria/prohibited-attribute",
+            Self::UndefinedReference => "aria/undefined-reference",
+        }
+    }
+}
+
+impl Display for AriaError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidValue => write!(formatter, "invalid value"),
+            Self::Prohibited => write!(formatter, "prohibited"),
+            Self::UndefinedReference => write!(formatter, "undefined reference"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn code_unknown_tag() {
+        assert_eq!(
+            MarkupError::UnknownTag("foo".into()).code(),
+            "markup/unknown-tag"
+        );
+    }
+
+    #[test]
+    fn code_excluded_element() {
+        assert_eq!(
+            MarkupError::ExcludedElement("a".into()).code(),
+            "markup/excluded-element"
+        );
+    }
+
+    #[test]
+    fn code_invalid_attribute_value() {
+        assert_eq!(
+            MarkupError::InvalidElement {
+                invalid_attributes: [("foo".into(), [AttributeError::InvalidValue].into())].into(),
+                invalid_children: Default::default(),
+                missing_attributes: Default::default(),
+                missing_children: Default::default(),
+            }
+            .code(),
+            "markup/invalid-attribute-value"
+        );
+    }
+
+    #[test]
+    fn code_misplaced_child() {
+        assert_eq!(
+            MarkupError::InvalidElement {
+                invalid_attributes: Default::default(),
+                invalid_children: [("foo".into(), [ChildError::Misplaced].into())].into(),
+                missing_attributes: Default::default(),
+                missing_children: Default::default(),
+            }
+            .code(),
+            "markup/misplaced-child"
+        );
+    }
+
+    #[test]
+    fn code_missing_children() {
+        assert_eq!(
+            MarkupError::InvalidElement {
+                invalid_attributes: Default::default(),
+                invalid_children: Default::default(),
+                missing_attributes: Default::default(),
+                missing_children: ["title".into()].into(),
+            }
+            .code(),
+            "markup/missing-child"
+        );
+    }
+
+    #[test]
+    fn code_invalid_aria() {
+        assert_eq!(
+            MarkupError::InvalidAria {
+                invalid_attributes: [("aria-label".into(), [AriaError::Prohibited].into())].into(),
+                missing_attributes: ["aria-checked".into()].into(),
+            }
+            .code(),
+            "aria/prohibited-attribute"
+        );
+        assert_eq!(
+            MarkupError::InvalidAria {
+                invalid_attributes: Default::default(),
+                missing_attributes: ["aria-checked".into()].into(),
+            }
+            .code(),
+            "aria/missing-attribute"
+        );
+    }
+
+    #[test]
+    fn include_codes() {
+        for code in [
+            MarkupError::UnknownTag("foo".into()).code(),
+            MarkupError::ExcludedElement("a".into()).code(),
+            AttributeError::Conflict.code(),
+            AttributeError::InvalidValue.code(),
+            AttributeError::NotAllowed.code(),
+            ChildError::Misplaced.code(),
+            ChildError::NotAllowed.code(),
+            AriaError::InvalidValue.code(),
+            AriaError::Prohibited.code(),
+            AriaError::UndefinedReference.code(),
+        ] {
+            assert!(MarkupError::CODES.contains(&code), "{code}");
+        }
+    }
+
     #[test]
     fn display_unknown_tag() {
         assert_eq!(
@@ -129,6 +360,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn display_excluded_element() {
+        assert_eq!(
+            format!("{}", MarkupError::ExcludedElement("a".into())),
+            "not allowed as a descendant of \"a\""
+        );
+    }
+
     #[test]
     fn display_not_allowed_attributes() {
         assert_eq!(
@@ -291,4 +530,22 @@ mod tests {
             "invalid attributes: foo (not allowed), invalid children: bar (not allowed)"
         );
     }
+
+    #[test]
+    fn display_invalid_aria() {
+        assert_eq!(
+            format!(
+                "{}",
+                MarkupError::InvalidAria {
+                    invalid_attributes: [(
+                        "aria-describedby".into(),
+                        [AriaError::UndefinedReference].into()
+                    )]
+                    .into(),
+                    missing_attributes: ["aria-checked".into()].into(),
+                }
+            ),
+            "invalid ARIA attributes: aria-describedby (undefined reference), missing ARIA attributes: aria-checked"
+        );
+    }
 }
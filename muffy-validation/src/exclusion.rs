@@ -0,0 +1,247 @@
+// Exclusions of descendants in the HTML standard which content models of
+// parents cannot describe.
+
+use crate::error::MarkupError;
+use muffy_document::document::Element;
+use regex::Regex;
+
+const HTML_NAMESPACE: &str = "http://www.w3.org/1999/xhtml";
+const ANY_NAME: &str = "*";
+
+pub struct NameSet {
+    pub names: &'static [&'static str],
+    pub excepted: &'static [&'static str],
+}
+
+impl NameSet {
+    fn contains(&self, name: &str) -> bool {
+        self.names
+            .iter()
+            .any(|other| *other == ANY_NAME || *other == name)
+            && !self.excepted.contains(&name)
+    }
+}
+
+// A pattern of descendants allowed in an excluding ancestor.
+pub struct Descendant {
+    pub names: NameSet,
+    pub attributes: &'static [NameSet],
+    pub values: &'static [(&'static str, &'static str)],
+}
+
+impl Descendant {
+    fn matches(&self, element: &Element) -> bool {
+        self.names.contains(element.name())
+            && element
+                .attributes()
+                .all(|(name, _)| self.attributes.iter().any(|set| set.contains(name)))
+            // Values of enumerated attributes in HTML are case-insensitive.
+            && self.values.iter().all(|(name, value)| {
+                element
+                    .attributes()
+                    .any(|(other, other_value)| other == *name && other_value.eq_ignore_ascii_case(value))
+            })
+    }
+}
+
+pub fn validate_exclusions(
+    element: &Element,
+    ancestors: &[&Element],
+    ignored_elements: &[Regex],
+    find_descendants: fn(&str) -> Option<&'static [Descendant]>,
+) -> Result<(), MarkupError> {
+    if !is_html_element(element)
+        || ignored_elements
+            .iter()
+            .any(|pattern| pattern.is_match(element.name()))
+    {
+        return Ok(());
+    }
+
+    if let Some(ancestor) = ancestors
+        .iter()
+        .rev()
+        .copied()
+        .filter(|ancestor| is_html_element(ancestor))
+        .find(|ancestor| {
+            find_descendants(ancestor.name()).is_some_and(|descendants| {
+                !descendants
+                    .iter()
+                    .any(|descendant| descendant.matches(element))
+            })
+        })
+    {
+        return Err(MarkupError::ExcludedElement(ancestor.name().into()));
+    }
+
+    Ok(())
+}
+
+fn is_html_element(element: &Element) -> bool {
+    element
+        .namespace()
+        .is_none_or(|namespace| namespace == HTML_NAMESPACE)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{MarkupError, validate_html_exclusions};
+    use muffy_document::document::Element;
+    use pretty_assertions::assert_eq;
+    use regex::Regex;
+
+    fn create_element(name: &str, attributes: &[(&str, &str)]) -> Element {
+        Element::new(
+            name.into(),
+            attributes
+                .iter()
+                .map(|(name, value)| ((*name).into(), (*value).into()))
+                .collect(),
+            vec![],
+        )
+    }
+
+    fn validate(element: &Element, ancestors: &[&Element]) -> Result<(), MarkupError> {
+        validate_html_exclusions(element, ancestors, &[])
+    }
+
+    fn excluded(ancestor: &str) -> Result<(), MarkupError> {
+        Err(MarkupError::ExcludedElement(ancestor.into()))
+    }
+
+    #[test]
+    fn exclude_interactive_content_in_link() {
+        let ancestor = create_element("a", &[("href", "/")]);
+
+        assert_eq!(
+            validate(&create_element("button", &[]), &[&ancestor]),
+            excluded("a")
+        );
+        assert_eq!(
+            validate(&create_element("a", &[("href", "/foo")]), &[&ancestor]),
+            excluded("a")
+        );
+        assert_eq!(
+            validate(&create_element("input", &[("type", "text")]), &[&ancestor]),
+            excluded("a")
+        );
+        assert_eq!(
+            validate(&create_element("input", &[]), &[&ancestor]),
+            excluded("a")
+        );
+        assert_eq!(
+            validate(&create_element("span", &[("tabindex", "0")]), &[&ancestor]),
+            excluded("a")
+        );
+        assert_eq!(
+            validate(
+                &create_element("input", &[("type", "hidden"), ("tabindex", "0")]),
+                &[&ancestor]
+            ),
+            excluded("a")
+        );
+    }
+
+    #[test]
+    fn allow_non_interactive_content_in_link() {
+        let ancestor = create_element("a", &[("href", "/")]);
+
+        assert_eq!(validate(&create_element("span", &[]), &[&ancestor]), Ok(()));
+        assert_eq!(
+            validate(&create_element("img", &[("src", "/foo.png")]), &[&ancestor]),
+            Ok(())
+        );
+        assert_eq!(
+            validate(
+                &create_element("input", &[("type", "HIDDEN"), ("name", "foo")]),
+                &[&ancestor]
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            validate(
+                &create_element("video", &[("src", "/foo.mp4")]),
+                &[&ancestor]
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn allow_link_without_href_in_button() {
+        let ancestor = create_element("button", &[]);
+
+        assert_eq!(validate(&create_element("a", &[]), &[&ancestor]), Ok(()));
+        assert_eq!(
+            validate(&create_element("a", &[("href", "/")]), &[&ancestor]),
+            excluded("button")
+        );
+    }
+
+    #[test]
+    fn exclude_nested_form() {
+        let form = create_element("form", &[]);
+        let div = create_element("div", &[]);
+
+        assert_eq!(
+            validate(&create_element("form", &[]), &[&form, &div]),
+            excluded("form")
+        );
+    }
+
+    #[test]
+    fn exclude_nested_definition() {
+        let ancestor = create_element("dfn", &[]);
+
+        assert_eq!(
+            validate(&create_element("dfn", &[]), &[&ancestor]),
+            excluded("dfn")
+        );
+    }
+
+    #[test]
+    fn exclude_header_in_footer() {
+        let footer = create_element("footer", &[]);
+
+        assert_eq!(
+            validate(&create_element("header", &[]), &[&footer]),
+            excluded("footer")
+        );
+    }
+
+    #[test]
+    fn find_nearest_excluding_ancestor() {
+        let button = create_element("button", &[]);
+        let link = create_element("a", &[("href", "/")]);
+
+        assert_eq!(
+            validate(&create_element("button", &[]), &[&button, &link]),
+            excluded("a")
+        );
+    }
+
+    #[test]
+    fn ignore_non_html_elements() {
+        let ancestor = create_element("a", &[("href", "/")])
+            .set_namespace(Some("http://www.w3.org/2000/svg".into()));
+
+        assert_eq!(
+            validate(&create_element("button", &[]), &[&ancestor]),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn ignore_excluded_elements_by_patterns() {
+        let ancestor = create_element("form", &[]);
+
+        assert_eq!(
+            validate_html_exclusions(
+                &create_element("form", &[]),
+                &[&ancestor],
+                &[Regex::new("form").unwrap()]
+            ),
+            Ok(())
+        );
+    }
+}
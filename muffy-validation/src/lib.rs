@@ -2,32 +2,48 @@
 
 extern crate alloc;
 
+mod aria;
 mod attribute;
 mod attribute_set;
 mod content;
+mod custom_element;
+mod datatype;
 mod error;
+mod exclusion;
 mod literal;
 mod rule;
+mod schema;
 mod validation;
 mod value;
 mod variant;
 
-pub use self::error::*;
+pub use self::{
+    aria::validate_aria_element,
+    custom_element::{ContentCategory, CustomElement},
+    error::*,
+    schema::{Schema, SchemaError},
+};
 use self::{
     attribute::Attribute,
     attribute_set::AttributeSet,
     content::Content,
+    datatype::Datatype,
+    exclusion::{Descendant, NameSet, validate_exclusions},
     literal::Literal,
     rule::Rule,
-    validation::{matches_wildcard, validate_rule},
+    validation::{matches_wildcard, validate_element},
     value::Value,
     variant::Variant,
 };
 use muffy_document::document::Element;
-use muffy_validation_macro::html;
+use muffy_validation_macro::{html, html_exclusions, mathml};
 
 html! {}
 
+html_exclusions! {}
+
+mathml! {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -58,7 +74,7 @@ mod tests {
         let element = create_element("invalid", vec![], vec![]);
 
         assert_eq!(
-            validate_html_element(&element, &[], &[]),
+            validate_html_element(&element, &[], &[], &[], &Default::default()),
             Err(MarkupError::UnknownTag("invalid".to_owned()))
         );
     }
@@ -70,35 +86,50 @@ mod tests {
         fn validate_valid_attribute_name_prefix() {
             let element = create_element("div", vec![("lang", "en"), ("xml:lang", "en")], vec![]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
         fn validate_valid_element() {
             let element = create_element("div", vec![], vec![]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
         fn validate_valid_attributes() {
             let element = create_element("div", vec![("id", "foo"), ("class", "bar")], vec![]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
         fn validate_valid_attribute_value() {
             let element = create_element("div", vec![("dir", "ltr")], vec![]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
         fn validate_valid_case_insensitive_attribute_value() {
             let element = create_element("div", vec![("dir", "LTR")], vec![]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -106,7 +137,7 @@ mod tests {
             let element = create_element("div", vec![("dir", "wrong")], vec![]);
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: [("dir".into(), [AttributeError::InvalidValue].into())]
                         .into(),
@@ -117,18 +148,53 @@ mod tests {
             );
         }
 
+        #[test]
+        fn validate_valid_datatype_attribute_value() {
+            let element = create_element("div", vec![("tabindex", "-1")], vec![]);
+
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
+        }
+
+        #[test]
+        fn validate_invalid_datatype_attribute_value() {
+            let element = create_element("div", vec![("tabindex", "first")], vec![]);
+
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Err(MarkupError::InvalidElement {
+                    invalid_attributes: [(
+                        "tabindex".into(),
+                        [AttributeError::InvalidValue].into()
+                    )]
+                    .into(),
+                    invalid_children: Default::default(),
+                    missing_attributes: Default::default(),
+                    missing_children: Default::default(),
+                })
+            );
+        }
+
         #[test]
         fn validate_valid_role_value() {
             let element = create_element("div", vec![("role", "img")], vec![]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
         fn validate_valid_surrounded_role_value() {
             let element = create_element("div", vec![("role", " img ")], vec![]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -137,14 +203,20 @@ mod tests {
             // are fallbacks for user agents not knowing it.
             let element = create_element("div", vec![("role", "none presentation")], vec![]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
         fn validate_valid_trailing_role_value() {
             let element = create_element("div", vec![("role", "unknown button")], vec![]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -152,7 +224,7 @@ mod tests {
             let element = create_element("div", vec![("role", "IMG")], vec![]);
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: [("role".into(), [AttributeError::InvalidValue].into())]
                         .into(),
@@ -168,7 +240,7 @@ mod tests {
             let element = create_element("div", vec![("role", "unknown other")], vec![]);
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: [("role".into(), [AttributeError::InvalidValue].into())]
                         .into(),
@@ -184,7 +256,13 @@ mod tests {
             let element = create_element("div", vec![("dir", "wrong")], vec![]);
 
             assert_eq!(
-                validate_html_element(&element, &[Regex::new("^dir$").unwrap()], &[]),
+                validate_html_element(
+                    &element,
+                    &[],
+                    &[Regex::new("^dir$").unwrap()],
+                    &[],
+                    &Default::default()
+                ),
                 Ok(())
             );
         }
@@ -194,7 +272,7 @@ mod tests {
             let element = create_element("div", vec![("invalid", "foo")], vec![]);
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: [("invalid".into(), [AttributeError::NotAllowed].into())]
                         .into(),
@@ -214,7 +292,7 @@ mod tests {
             );
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: [
                         ("invalid-one".into(), [AttributeError::NotAllowed].into()),
@@ -233,7 +311,13 @@ mod tests {
             let element = create_element("div", vec![("data-foo", "bar")], vec![]);
 
             assert_eq!(
-                validate_html_element(&element, &[Regex::new("^data-.*$").unwrap()], &[]),
+                validate_html_element(
+                    &element,
+                    &[],
+                    &[Regex::new("^data-.*$").unwrap()],
+                    &[],
+                    &Default::default()
+                ),
                 Ok(())
             );
         }
@@ -247,7 +331,13 @@ mod tests {
             );
 
             assert_eq!(
-                validate_html_element(&element, &[], &[Regex::new("^custom-element-.*$").unwrap()]),
+                validate_html_element(
+                    &element,
+                    &[],
+                    &[],
+                    &[Regex::new("^custom-element-.*$").unwrap()],
+                    &Default::default()
+                ),
                 Ok(())
             );
         }
@@ -257,7 +347,13 @@ mod tests {
             let element = create_element("custom-element-456", vec![], vec![]);
 
             assert_eq!(
-                validate_html_element(&element, &[], &[Regex::new("^custom-element-.*$").unwrap()]),
+                validate_html_element(
+                    &element,
+                    &[],
+                    &[],
+                    &[Regex::new("^custom-element-.*$").unwrap()],
+                    &Default::default()
+                ),
                 Ok(())
             );
         }
@@ -267,7 +363,13 @@ mod tests {
             let element = create_element("div", vec![("invalid", "foo")], vec![]);
 
             assert_eq!(
-                validate_html_element(&element, &[], &[Regex::new("^div$").unwrap()]),
+                validate_html_element(
+                    &element,
+                    &[],
+                    &[],
+                    &[Regex::new("^div$").unwrap()],
+                    &Default::default()
+                ),
                 Ok(())
             );
         }
@@ -277,7 +379,13 @@ mod tests {
             let element = create_element("div", vec![("invalid", "foo")], vec![]);
 
             assert_eq!(
-                validate_html_element(&element, &[], &[Regex::new("^span$").unwrap()]),
+                validate_html_element(
+                    &element,
+                    &[],
+                    &[],
+                    &[Regex::new("^span$").unwrap()],
+                    &Default::default()
+                ),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: [("invalid".into(), [AttributeError::NotAllowed].into())]
                         .into(),
@@ -292,7 +400,10 @@ mod tests {
         fn validate_valid_child() {
             let element = create_element("div", vec![], vec![create_element("p", vec![], vec![])]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -300,7 +411,10 @@ mod tests {
             let element =
                 create_element("div", vec![], vec![create_element("svg", vec![], vec![])]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -308,7 +422,10 @@ mod tests {
             let element =
                 create_element("div", vec![], vec![create_element("math", vec![], vec![])]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
     }
 
@@ -319,7 +436,10 @@ mod tests {
         fn validate_valid_element() {
             let element = create_element("p", vec![], vec![]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -330,7 +450,10 @@ mod tests {
                 vec![Arc::new(Node::Text("hello".into()))],
             );
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -338,7 +461,7 @@ mod tests {
             let element = create_element("p", vec![], vec![create_element("div", vec![], vec![])]);
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: Default::default(),
                     invalid_children: [("div".into(), [ChildError::NotAllowed].into())].into(),
@@ -360,7 +483,7 @@ mod tests {
             );
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: Default::default(),
                     invalid_children: [
@@ -379,7 +502,13 @@ mod tests {
             let element = create_element("p", vec![], vec![create_element("div", vec![], vec![])]);
 
             assert_eq!(
-                validate_html_element(&element, &[], &[Regex::new("^p$").unwrap()]),
+                validate_html_element(
+                    &element,
+                    &[],
+                    &[],
+                    &[Regex::new("^p$").unwrap()],
+                    &Default::default()
+                ),
                 Ok(())
             );
         }
@@ -392,21 +521,30 @@ mod tests {
         fn validate_valid_link() {
             let element = create_element("a", vec![("href", "/")], vec![]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
         fn validate_valid_prefixed_link() {
             let element = create_element("a", vec![("xlink:href", "/")], vec![]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
         fn validate_valid_placeholder_link() {
             let element = create_element("a", vec![], vec![]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -414,7 +552,7 @@ mod tests {
             let element = create_element("a", vec![("download", "")], vec![]);
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: Default::default(),
                     invalid_children: Default::default(),
@@ -432,7 +570,10 @@ mod tests {
         fn validate_valid_element() {
             let element = create_element("mark", vec![], vec![]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -440,7 +581,10 @@ mod tests {
             let element =
                 create_element("mark", vec![], vec![create_element("span", vec![], vec![])]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -449,7 +593,7 @@ mod tests {
                 create_element("mark", vec![], vec![create_element("div", vec![], vec![])]);
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: Default::default(),
                     invalid_children: [("div".into(), [ChildError::NotAllowed].into())].into(),
@@ -468,7 +612,7 @@ mod tests {
             let element = create_element("html", vec![], vec![]);
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: Default::default(),
                     invalid_children: Default::default(),
@@ -489,7 +633,10 @@ mod tests {
                 ],
             );
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -504,7 +651,7 @@ mod tests {
             );
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: Default::default(),
                     invalid_children: [("body".into(), [ChildError::Misplaced].into())].into(),
@@ -526,7 +673,10 @@ mod tests {
                 vec![create_element("title", vec![], vec![])],
             );
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -534,7 +684,7 @@ mod tests {
             let element = create_element("head", vec![], vec![]);
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: Default::default(),
                     invalid_children: Default::default(),
@@ -549,7 +699,7 @@ mod tests {
             let element = create_element("head", vec![], vec![create_element("p", vec![], vec![])]);
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: Default::default(),
                     invalid_children: [("p".into(), [ChildError::NotAllowed].into())].into(),
@@ -569,7 +719,7 @@ mod tests {
                 create_element("title", vec![], vec![create_element("div", vec![], vec![])]);
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: Default::default(),
                     invalid_children: [("div".into(), [ChildError::NotAllowed].into())].into(),
@@ -587,7 +737,10 @@ mod tests {
         fn validate_valid_child() {
             let element = create_element("ul", vec![], vec![create_element("li", vec![], vec![])]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -601,7 +754,10 @@ mod tests {
                 ],
             );
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -616,7 +772,7 @@ mod tests {
             );
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: Default::default(),
                     invalid_children: [("#text".into(), [ChildError::NotAllowed].into())].into(),
@@ -631,7 +787,7 @@ mod tests {
             let element = create_element("ul", vec![], vec![create_element("p", vec![], vec![])]);
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: Default::default(),
                     invalid_children: [("p".into(), [ChildError::NotAllowed].into())].into(),
@@ -650,7 +806,10 @@ mod tests {
             let element =
                 create_element("table", vec![], vec![create_element("tr", vec![], vec![])]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -666,7 +825,10 @@ mod tests {
                 ],
             );
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -681,7 +843,7 @@ mod tests {
             );
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: Default::default(),
                     invalid_children: [("thead".into(), [ChildError::Misplaced].into())].into(),
@@ -697,7 +859,7 @@ mod tests {
                 create_element("table", vec![], vec![create_element("p", vec![], vec![])]);
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: Default::default(),
                     invalid_children: [("p".into(), [ChildError::NotAllowed].into())].into(),
@@ -722,7 +884,10 @@ mod tests {
                 ],
             );
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
     }
 
@@ -733,14 +898,20 @@ mod tests {
         fn validate_valid_attributes() {
             let element = create_element("form", vec![("action", "/"), ("method", "post")], vec![]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
         fn validate_valid_case_insensitive_method_value() {
             let element = create_element("form", vec![("action", "/"), ("method", "POST")], vec![]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -749,7 +920,7 @@ mod tests {
                 create_element("form", vec![("action", "/"), ("method", "delete")], vec![]);
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: [("method".into(), [AttributeError::InvalidValue].into())]
                         .into(),
@@ -768,7 +939,10 @@ mod tests {
                 vec![create_element("input", vec![], vec![])],
             );
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
     }
 
@@ -783,7 +957,10 @@ mod tests {
                 vec![],
             );
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
     }
 
@@ -795,7 +972,7 @@ mod tests {
             let element = create_element("picture", vec![], vec![]);
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: Default::default(),
                     invalid_children: Default::default(),
@@ -816,7 +993,10 @@ mod tests {
                 ],
             );
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -831,7 +1011,7 @@ mod tests {
             );
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: Default::default(),
                     invalid_children: [("source".into(), [ChildError::Misplaced].into())].into(),
@@ -847,7 +1027,7 @@ mod tests {
                 create_element("picture", vec![], vec![create_element("p", vec![], vec![])]);
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: Default::default(),
                     invalid_children: [("p".into(), [ChildError::NotAllowed].into())].into(),
@@ -866,7 +1046,10 @@ mod tests {
             let element =
                 create_element("video", vec![("src", "vid.mp4"), ("controls", "")], vec![]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -877,7 +1060,10 @@ mod tests {
                 vec![create_element("track", vec![], vec![])],
             );
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
     }
 
@@ -892,14 +1078,20 @@ mod tests {
                 vec![],
             );
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
         fn validate_valid_charset() {
             let element = create_element("meta", vec![("charset", "utf-8")], vec![]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -910,7 +1102,10 @@ mod tests {
                 vec![],
             );
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -918,7 +1113,7 @@ mod tests {
             let element = create_element("meta", vec![("name", "description")], vec![]);
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: Default::default(),
                     invalid_children: Default::default(),
@@ -936,7 +1131,10 @@ mod tests {
                 vec![],
             );
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -948,7 +1146,7 @@ mod tests {
             );
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: [(
                         "http-equiv".into(),
@@ -975,7 +1173,7 @@ mod tests {
             );
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: [("charset".into(), [AttributeError::Conflict].into())]
                         .into(),
@@ -998,14 +1196,20 @@ mod tests {
                 vec![],
             );
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
         fn validate_valid_rel_without_href() {
             let element = create_element("link", vec![("rel", "preload")], vec![]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -1015,7 +1219,7 @@ mod tests {
             // The schema alternatively requires either the `rel` attribute or
             // the `itemprop` attribute, and one minimal diagnosis is reported.
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: Default::default(),
                     invalid_children: Default::default(),
@@ -1033,7 +1237,10 @@ mod tests {
         fn validate_valid_element() {
             let element = create_element("svg", vec![], vec![]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -1049,21 +1256,30 @@ mod tests {
                 vec![],
             );
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
         fn validate_valid_language_attributes() {
             let element = create_element("svg", vec![("lang", "en"), ("xml:lang", "en")], vec![]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
         fn validate_valid_attribute_value() {
             let element = create_element("svg", vec![("zoomAndPan", "magnify")], vec![]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -1071,7 +1287,7 @@ mod tests {
             let element = create_element("svg", vec![("zoomAndPan", "MAGNIFY")], vec![]);
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: [(
                         "zoomAndPan".into(),
@@ -1093,7 +1309,10 @@ mod tests {
                 vec![],
             );
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -1104,7 +1323,10 @@ mod tests {
                 vec![create_element("circle", vec![("r", "1")], vec![])],
             );
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -1115,7 +1337,10 @@ mod tests {
                 vec![Arc::new(Node::Text("hello".into()))],
             );
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -1126,7 +1351,10 @@ mod tests {
                 vec![create_element("stop", vec![("offset", "0")], vec![])],
             );
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -1137,21 +1365,30 @@ mod tests {
                 vec![create_element("rect", vec![], vec![])],
             );
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
         fn validate_valid_image_link() {
             let element = create_element("image", vec![("href", "/foo.png")], vec![]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
         fn validate_valid_prefixed_image_link() {
             let element = create_element("image", vec![("xlink:href", "/foo.png")], vec![]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -1162,7 +1399,10 @@ mod tests {
                 vec![create_element("div", vec![], vec![])],
             );
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -1174,7 +1414,7 @@ mod tests {
             );
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: Default::default(),
                     invalid_children: [("title".into(), [ChildError::NotAllowed].into())].into(),
@@ -1189,7 +1429,10 @@ mod tests {
             let element =
                 create_element("sodipodi:namedview", vec![("inkscape:zoom", "1")], vec![]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -1205,7 +1448,10 @@ mod tests {
                 vec![],
             );
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -1213,7 +1459,7 @@ mod tests {
             let element = create_element("sodipodi:namedview", vec![("xlink:href", "/")], vec![]);
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: [(
                         "xlink:href".into(),
@@ -1238,7 +1484,10 @@ mod tests {
                 vec![],
             );
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -1249,7 +1498,10 @@ mod tests {
                 vec![create_element("rdf:RDF", vec![], vec![])],
             );
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -1260,7 +1512,10 @@ mod tests {
                 vec![create_element("cc:Work", vec![("rdf:about", "")], vec![])],
             );
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -1268,7 +1523,7 @@ mod tests {
             let element = create_element("invalid", vec![], vec![]);
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::UnknownTag("invalid".to_owned()))
             );
         }
@@ -1278,7 +1533,7 @@ mod tests {
             let element = create_element("circle", vec![("invalid", "foo")], vec![]);
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: [("invalid".into(), [AttributeError::NotAllowed].into())]
                         .into(),
@@ -1295,7 +1550,7 @@ mod tests {
                 create_element("svg", vec![], vec![create_element("html", vec![], vec![])]);
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: Default::default(),
                     invalid_children: [("html".into(), [ChildError::NotAllowed].into())].into(),
@@ -1310,7 +1565,7 @@ mod tests {
             let element = create_element("animate", vec![], vec![]);
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: Default::default(),
                     invalid_children: Default::default(),
@@ -1325,7 +1580,13 @@ mod tests {
             let element = create_element("circle", vec![("data-foo", "bar")], vec![]);
 
             assert_eq!(
-                validate_html_element(&element, &[Regex::new("^data-.*$").unwrap()], &[]),
+                validate_html_element(
+                    &element,
+                    &[],
+                    &[Regex::new("^data-.*$").unwrap()],
+                    &[],
+                    &Default::default()
+                ),
                 Ok(())
             );
         }
@@ -1335,7 +1596,13 @@ mod tests {
             let element = create_element("foreignObject", vec![], vec![]);
 
             assert_eq!(
-                validate_html_element(&element, &[], &[Regex::new("^foreignObject$").unwrap()]),
+                validate_html_element(
+                    &element,
+                    &[],
+                    &[],
+                    &[Regex::new("^foreignObject$").unwrap()],
+                    &Default::default()
+                ),
                 Ok(())
             );
         }
@@ -1348,7 +1615,10 @@ mod tests {
         fn validate_valid_element() {
             let element = create_element("math", vec![], vec![]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -1356,14 +1626,20 @@ mod tests {
             let element =
                 create_element("math", vec![("display", "block"), ("alttext", "x")], vec![]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
         fn validate_valid_padded_attribute_value() {
             let element = create_element("math", vec![("display", " block ")], vec![]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -1371,7 +1647,7 @@ mod tests {
             let element = create_element("math", vec![("display", "Block")], vec![]);
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: [("display".into(), [AttributeError::InvalidValue].into())]
                         .into(),
@@ -1390,7 +1666,10 @@ mod tests {
                 vec![],
             );
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -1398,14 +1677,20 @@ mod tests {
             let element =
                 create_element("math", vec![], vec![create_element("mi", vec![], vec![])]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
         fn validate_valid_text_child() {
             let element = Element::new("mi".into(), vec![], vec![Arc::new(Node::Text("x".into()))]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -1419,7 +1704,10 @@ mod tests {
                 ],
             );
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -1437,7 +1725,10 @@ mod tests {
                 ],
             );
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -1448,7 +1739,10 @@ mod tests {
                 vec![create_element("span", vec![], vec![])],
             );
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -1456,7 +1750,7 @@ mod tests {
             let element = create_element("math", vec![("invalid", "foo")], vec![]);
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: [("invalid".into(), [AttributeError::NotAllowed].into())]
                         .into(),
@@ -1473,7 +1767,7 @@ mod tests {
                 create_element("math", vec![], vec![create_element("div", vec![], vec![])]);
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: Default::default(),
                     invalid_children: [("div".into(), [ChildError::NotAllowed].into())].into(),
@@ -1484,6 +1778,92 @@ mod tests {
         }
     }
 
+    mod mathml {
+        use super::*;
+
+        #[test]
+        fn validate_valid_element() {
+            let element = create_element(
+                "math",
+                vec![("display", "block")],
+                vec![create_element(
+                    "mfrac",
+                    vec![],
+                    vec![
+                        create_element("mi", vec![], vec![]),
+                        create_element("mn", vec![], vec![]),
+                    ],
+                )],
+            );
+
+            assert_eq!(
+                validate_mathml_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
+        }
+
+        #[test]
+        fn validate_invalid_element_name() {
+            let element = create_element("div", vec![], vec![]);
+
+            assert_eq!(
+                validate_mathml_element(&element, &[], &[], &[], &Default::default()),
+                Err(MarkupError::UnknownTag("div".to_owned()))
+            );
+        }
+
+        #[test]
+        fn validate_invalid_attribute() {
+            let element = create_element("mi", vec![("invalid", "foo")], vec![]);
+
+            assert_eq!(
+                validate_mathml_element(&element, &[], &[], &[], &Default::default()),
+                Err(MarkupError::InvalidElement {
+                    invalid_attributes: [("invalid".into(), [AttributeError::NotAllowed].into())]
+                        .into(),
+                    invalid_children: Default::default(),
+                    missing_attributes: Default::default(),
+                    missing_children: Default::default(),
+                })
+            );
+        }
+
+        #[test]
+        fn validate_invalid_html_child_of_token_element() {
+            let element = create_element(
+                "mtext",
+                vec![],
+                vec![create_element("span", vec![], vec![])],
+            );
+
+            assert_eq!(
+                validate_mathml_element(&element, &[], &[], &[], &Default::default()),
+                Err(MarkupError::InvalidElement {
+                    invalid_attributes: Default::default(),
+                    invalid_children: [("span".into(), [ChildError::NotAllowed].into())].into(),
+                    missing_attributes: Default::default(),
+                    missing_children: Default::default(),
+                })
+            );
+        }
+
+        #[test]
+        fn validate_ignored_attribute() {
+            let element = create_element("mi", vec![("invalid", "foo")], vec![]);
+
+            assert_eq!(
+                validate_mathml_element(
+                    &element,
+                    &[],
+                    &[Regex::new("^invalid$").unwrap()],
+                    &[],
+                    &Default::default()
+                ),
+                Ok(())
+            );
+        }
+    }
+
     mod noscript {
         use super::*;
 
@@ -1491,7 +1871,10 @@ mod tests {
         fn validate_valid_element() {
             let element = create_element("noscript", vec![], vec![]);
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -1502,7 +1885,10 @@ mod tests {
                 vec![create_element("div", vec![], vec![])],
             );
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -1513,7 +1899,10 @@ mod tests {
                 vec![create_element("link", vec![], vec![])],
             );
 
-            assert_eq!(validate_html_element(&element, &[], &[]), Ok(()));
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
+                Ok(())
+            );
         }
 
         #[test]
@@ -1525,7 +1914,7 @@ mod tests {
             );
 
             assert_eq!(
-                validate_html_element(&element, &[], &[]),
+                validate_html_element(&element, &[], &[], &[], &Default::default()),
                 Err(MarkupError::InvalidElement {
                     invalid_attributes: Default::default(),
                     invalid_children: [("title".into(), [ChildError::NotAllowed].into())].into(),
@@ -1535,4 +1924,222 @@ mod tests {
             );
         }
     }
+
+    mod custom_element {
+        use super::*;
+        use alloc::collections::BTreeMap;
+        use pretty_assertions::assert_eq;
+
+        fn custom_elements() -> BTreeMap<String, CustomElement> {
+            [
+                (
+                    "foo-bar".into(),
+                    CustomElement::new().set_attributes(
+                        [
+                            ("baz".into(), None),
+                            ("qux".into(), Some(["load".into(), "idle".into()].into())),
+                        ]
+                        .into(),
+                    ),
+                ),
+                (
+                    "foo-block".into(),
+                    CustomElement::new()
+                        .set_category(ContentCategory::Flow)
+                        .set_content(ContentCategory::Phrasing),
+                ),
+            ]
+            .into()
+        }
+
+        #[test]
+        fn validate_valid_element() {
+            let element = create_element(
+                "foo-bar",
+                vec![("baz", "x"), ("class", "y"), ("qux", "idle")],
+                vec![create_element("div", vec![], vec![])],
+            );
+
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &custom_elements()),
+                Ok(())
+            );
+        }
+
+        #[test]
+        fn validate_invalid_attributes() {
+            let element = create_element("foo-bar", vec![("qux", "eager"), ("quux", "")], vec![]);
+
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &custom_elements()),
+                Err(MarkupError::InvalidElement {
+                    invalid_attributes: [
+                        ("quux".into(), [AttributeError::NotAllowed].into()),
+                        ("qux".into(), [AttributeError::InvalidValue].into()),
+                    ]
+                    .into(),
+                    invalid_children: Default::default(),
+                    missing_attributes: Default::default(),
+                    missing_children: Default::default(),
+                })
+            );
+        }
+
+        #[test]
+        fn validate_ignored_attribute() {
+            let element = create_element("foo-bar", vec![("qux", "eager")], vec![]);
+
+            assert_eq!(
+                validate_html_element(
+                    &element,
+                    &[],
+                    &[Regex::new("^qux$").unwrap()],
+                    &[],
+                    &custom_elements()
+                ),
+                Ok(())
+            );
+        }
+
+        #[test]
+        fn validate_phrasing_content() {
+            let element = create_element(
+                "foo-block",
+                vec![],
+                vec![
+                    create_element("span", vec![], vec![]),
+                    create_element("div", vec![], vec![]),
+                ],
+            );
+
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &custom_elements()),
+                Err(MarkupError::InvalidElement {
+                    invalid_attributes: Default::default(),
+                    invalid_children: [("div".into(), [ChildError::NotAllowed].into())].into(),
+                    missing_attributes: Default::default(),
+                    missing_children: Default::default(),
+                })
+            );
+        }
+
+        #[test]
+        fn validate_element_in_phrasing_content() {
+            let element = create_element(
+                "p",
+                vec![],
+                vec![
+                    create_element("foo-bar", vec![], vec![]),
+                    create_element("foo-block", vec![], vec![]),
+                ],
+            );
+
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &custom_elements()),
+                Err(MarkupError::InvalidElement {
+                    invalid_attributes: Default::default(),
+                    invalid_children: [("foo-block".into(), [ChildError::NotAllowed].into())]
+                        .into(),
+                    missing_attributes: Default::default(),
+                    missing_children: Default::default(),
+                })
+            );
+        }
+
+        #[test]
+        fn validate_undeclared_element() {
+            let element = create_element("foo-baz", vec![], vec![]);
+
+            assert_eq!(
+                validate_html_element(&element, &[], &[], &[], &custom_elements()),
+                Err(MarkupError::UnknownTag("foo-baz".into()))
+            );
+        }
+    }
+
+    mod ancestors {
+        use super::*;
+
+        #[test]
+        fn validate_excluded_link() {
+            let ancestor = create_element("a", vec![("href", "/")], vec![]);
+            let element = create_element("a", vec![("href", "/foo")], vec![]);
+
+            assert_eq!(
+                validate_html_exclusions(&element, &[&ancestor], &[]),
+                Err(MarkupError::ExcludedElement("a".into()))
+            );
+        }
+
+        #[test]
+        fn validate_excluded_invalid_link() {
+            let ancestor = create_element("a", vec![("href", "/")], vec![]);
+            let element = create_element("a", vec![("href", "/foo"), ("foo", "bar")], vec![]);
+
+            assert_eq!(
+                validate_html_element(&element, &[&ancestor], &[], &[], &Default::default()),
+                Err(MarkupError::InvalidElement {
+                    invalid_attributes: [("foo".into(), [AttributeError::NotAllowed].into())]
+                        .into(),
+                    invalid_children: Default::default(),
+                    missing_attributes: Default::default(),
+                    missing_children: Default::default(),
+                })
+            );
+            assert_eq!(
+                validate_html_exclusions(&element, &[&ancestor], &[]),
+                Err(MarkupError::ExcludedElement("a".into()))
+            );
+        }
+
+        #[test]
+        fn validate_excluded_form() {
+            let form = create_element("form", vec![], vec![]);
+            let div = create_element("div", vec![], vec![]);
+            let element = create_element("form", vec![], vec![]);
+
+            assert_eq!(
+                validate_html_exclusions(&element, &[&form, &div], &[]),
+                Err(MarkupError::ExcludedElement("form".into()))
+            );
+        }
+
+        #[test]
+        fn validate_non_excluded_element() {
+            let ancestor = create_element("a", vec![("href", "/")], vec![]);
+            let element = create_element("span", vec![], vec![]);
+
+            assert_eq!(
+                validate_html_element(&element, &[&ancestor], &[], &[], &Default::default()),
+                Ok(())
+            );
+            assert_eq!(
+                validate_html_exclusions(&element, &[&ancestor], &[]),
+                Ok(())
+            );
+        }
+
+        #[test]
+        fn validate_unknown_descendant_of_unconstrained_element() {
+            let desc = create_element("desc", vec![], vec![]);
+            let foo = create_element("foo", vec![], vec![]);
+            let element = create_element("bar", vec![], vec![]);
+
+            assert_eq!(
+                validate_html_element(&element, &[&desc, &foo], &[], &[], &Default::default()),
+                Ok(())
+            );
+        }
+
+        #[test]
+        fn validate_unknown_descendant_of_constrained_element() {
+            let ancestor = create_element("g", vec![], vec![]);
+            let element = create_element("foo", vec![], vec![]);
+
+            assert_eq!(
+                validate_html_element(&element, &[&ancestor], &[], &[], &Default::default()),
+                Err(MarkupError::UnknownTag("foo".into()))
+            );
+        }
+    }
 }
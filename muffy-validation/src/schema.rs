@@ -0,0 +1,339 @@
+mod compiler;
+mod error;
+mod grammar;
+mod loader;
+mod name_class;
+mod pattern;
+mod validator;
+
+pub use self::error::SchemaError;
+use self::{
+    compiler::compile_grammar, grammar::Grammar, loader::load_definitions, validator::Validator,
+};
+use crate::error::MarkupError;
+use muffy_document::document::Element;
+use std::path::Path;
+
+/// A [Relax NG](https://relaxng.org/) schema loaded at runtime.
+#[derive(Debug)]
+pub struct Schema {
+    grammar: Grammar,
+}
+
+impl Schema {
+    /// Loads a schema from a file in the compact syntax.
+    ///
+    /// Files included by the schema are resolved relative to the file
+    /// including them.
+    pub fn load(path: &Path) -> Result<Self, SchemaError> {
+        Ok(Self {
+            grammar: compile_grammar(&load_definitions(path)?)?,
+        })
+    }
+
+    /// Validates an element tree.
+    ///
+    /// Errors are returned with their elements in document order.
+    pub fn validate<'a>(&self, element: &'a Element) -> Vec<(&'a Element, MarkupError)> {
+        Validator::new(&self.grammar).validate(element)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AttributeError, ChildError};
+    use muffy_document::{document::Node, xml::parse};
+    use pretty_assertions::assert_eq;
+    use std::fs::write;
+    use tempfile::tempdir;
+
+    const FEED_SCHEMA: &str = r#"
+        default namespace = "http://foo.example/"
+
+        start = feed
+
+        feed = element feed {
+            attribute version { "1" | "2" }?,
+            title,
+            entry*
+        }
+
+        title = element title { text }
+
+        entry = element entry {
+            attribute id { xsd:positiveInteger },
+            attribute xml:lang { text }?,
+            title,
+            element summary { text }?
+        }
+    "#;
+
+    fn load(files: &[(&str, &str)]) -> Result<Schema, SchemaError> {
+        let directory = tempdir().unwrap();
+
+        for (name, source) in files {
+            write(directory.path().join(name), source).unwrap();
+        }
+
+        Schema::load(&directory.path().join(files[0].0))
+    }
+
+    fn validate(schema: &Schema, source: &str) -> Vec<(String, MarkupError)> {
+        let document = parse(source).unwrap();
+        let Some(Node::Element(root)) = document
+            .children()
+            .find(|node| matches!(node, Node::Element(_)))
+        else {
+            panic!("root element expected");
+        };
+
+        schema
+            .validate(root)
+            .into_iter()
+            .map(|(element, error)| (element.name().into(), error))
+            .collect()
+    }
+
+    fn invalid_element(
+        invalid_attributes: &[(&str, AttributeError)],
+        invalid_children: &[&str],
+        missing_attributes: &[&str],
+        missing_children: &[&str],
+    ) -> MarkupError {
+        MarkupError::InvalidElement {
+            invalid_attributes: invalid_attributes
+                .iter()
+                .map(|(name, error)| ((*name).into(), [error.clone()].into()))
+                .collect(),
+            invalid_children: invalid_children
+                .iter()
+                .map(|name| ((*name).into(), [ChildError::NotAllowed].into()))
+                .collect(),
+            missing_attributes: missing_attributes
+                .iter()
+                .map(|name| (*name).into())
+                .collect(),
+            missing_children: missing_children.iter().map(|name| (*name).into()).collect(),
+        }
+    }
+
+    #[test]
+    fn validate_valid_document() {
+        let schema = load(&[("main.rnc", FEED_SCHEMA)]).unwrap();
+
+        assert_eq!(
+            validate(
+                &schema,
+                r#"
+                <feed xmlns="http://foo.example/" version="1">
+                  <title>Foo</title>
+                  <!-- comment -->
+                  <entry id="1" xml:lang="en">
+                    <title>Bar</title>
+                    <summary>Baz</summary>
+                  </entry>
+                  <entry id="2"><title/></entry>
+                </feed>
+                "#
+            ),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn validate_unknown_root() {
+        let schema = load(&[("main.rnc", FEED_SCHEMA)]).unwrap();
+
+        assert_eq!(
+            validate(&schema, r#"<feed xmlns="http://bar.example/"/>"#),
+            vec![("feed".into(), MarkupError::UnknownTag("feed".into()))]
+        );
+    }
+
+    #[test]
+    fn validate_invalid_attributes() {
+        let schema = load(&[("main.rnc", FEED_SCHEMA)]).unwrap();
+
+        assert_eq!(
+            validate(
+                &schema,
+                r#"
+                <feed xmlns="http://foo.example/" version="3" foo="bar">
+                  <title>Foo</title>
+                  <entry><title>Bar</title></entry>
+                  <entry id="0"><title>Bar</title></entry>
+                </feed>
+                "#
+            ),
+            vec![
+                (
+                    "feed".into(),
+                    invalid_element(
+                        &[
+                            ("foo", AttributeError::NotAllowed),
+                            ("version", AttributeError::InvalidValue)
+                        ],
+                        &[],
+                        &[],
+                        &[]
+                    )
+                ),
+                ("entry".into(), invalid_element(&[], &[], &["id"], &[])),
+                (
+                    "entry".into(),
+                    invalid_element(&[("id", AttributeError::InvalidValue)], &[], &[], &[])
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_invalid_children() {
+        let schema = load(&[("main.rnc", FEED_SCHEMA)]).unwrap();
+
+        assert_eq!(
+            validate(
+                &schema,
+                r#"
+                <feed xmlns="http://foo.example/">
+                  foo
+                  <summary>Foo</summary>
+                  <title>Foo</title>
+                  <entry id="1"><summary>Bar</summary></entry>
+                </feed>
+                "#
+            ),
+            vec![
+                (
+                    "feed".into(),
+                    invalid_element(&[], &["#text", "summary"], &[], &[])
+                ),
+                (
+                    "entry".into(),
+                    invalid_element(&[], &["summary"], &[], &["title"])
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_missing_children() {
+        let schema = load(&[("main.rnc", FEED_SCHEMA)]).unwrap();
+
+        assert_eq!(
+            validate(&schema, r#"<feed xmlns="http://foo.example/"/>"#),
+            vec![("feed".into(), invalid_element(&[], &[], &[], &["title"]))]
+        );
+    }
+
+    #[test]
+    fn validate_misplaced_element_against_its_definition() {
+        let schema = load(&[("main.rnc", FEED_SCHEMA)]).unwrap();
+
+        assert_eq!(
+            validate(
+                &schema,
+                r#"
+                <feed xmlns="http://foo.example/">
+                  <title>Foo</title>
+                  <title><foo/></title>
+                </feed>
+                "#
+            ),
+            vec![
+                ("feed".into(), invalid_element(&[], &["title"], &[], &[])),
+                ("title".into(), invalid_element(&[], &["foo"], &[], &[])),
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_list_and_data() {
+        let schema = load(&[(
+            "main.rnc",
+            "element foo { list { xsd:integer+ }, attribute bar { xsd:string - \"baz\" } }",
+        )])
+        .unwrap();
+
+        assert_eq!(validate(&schema, r#"<foo bar="qux">1 2 3</foo>"#), vec![]);
+        assert_eq!(
+            validate(&schema, r#"<foo bar="baz">1 x</foo>"#),
+            vec![(
+                "foo".into(),
+                invalid_element(
+                    &[("bar", AttributeError::InvalidValue)],
+                    &["#text"],
+                    &[],
+                    &[]
+                )
+            )]
+        );
+    }
+
+    #[test]
+    fn validate_interleave() {
+        let schema = load(&[(
+            "main.rnc",
+            "element foo { element bar { empty } & element baz { empty } }",
+        )])
+        .unwrap();
+
+        assert_eq!(validate(&schema, "<foo><baz/><bar/></foo>"), vec![]);
+        assert_eq!(
+            validate(&schema, "<foo><baz/></foo>"),
+            vec![("foo".into(), invalid_element(&[], &[], &[], &["bar"]))]
+        );
+    }
+
+    #[test]
+    fn validate_recursive_elements() {
+        let schema = load(&[("main.rnc", "start = node\nnode = element node { node* }")]).unwrap();
+
+        assert_eq!(
+            validate(&schema, "<node><node><node/></node><node/></node>"),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn validate_with_included_schema() {
+        let schema = load(&[
+            (
+                "main.rnc",
+                "include \"common.rnc\" { title = element name { text } }",
+            ),
+            (
+                "common.rnc",
+                "start = element foo { title }\ntitle = element title { text }",
+            ),
+        ])
+        .unwrap();
+
+        assert_eq!(validate(&schema, "<foo><name>foo</name></foo>"), vec![]);
+    }
+
+    #[test]
+    fn fail_on_missing_start() {
+        assert!(matches!(
+            load(&[("main.rnc", "foo = element foo { empty }")]),
+            Err(SchemaError::MissingStart)
+        ));
+    }
+
+    #[test]
+    fn fail_on_undefined_reference() {
+        assert!(matches!(
+            load(&[("main.rnc", "start = foo")]),
+            Err(SchemaError::UndefinedReference(name)) if name == "foo"
+        ));
+    }
+
+    #[test]
+    fn fail_on_missing_file() {
+        assert!(matches!(
+            load(&[("main.rnc", "include \"foo.rnc\"")]),
+            Err(SchemaError::Io(_))
+        ));
+    }
+}
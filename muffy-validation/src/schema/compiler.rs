@@ -0,0 +1,197 @@
+use super::{
+    error::SchemaError,
+    grammar::Grammar,
+    loader::{XSD_DATATYPE_LIBRARY, start_name},
+    name_class::NameClass,
+    pattern::{Pattern, ValueType},
+};
+use crate::datatype::Datatype;
+use alloc::{
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    sync::Arc,
+};
+use muffy_rnc::{DatatypeName, Identifier, Name, NameClass as RncNameClass, Pattern as RncPattern};
+
+/// Compiles definitions into a grammar.
+pub fn compile_grammar(
+    definitions: &BTreeMap<Identifier, RncPattern>,
+) -> Result<Grammar, SchemaError> {
+    let mut compiler = Compiler {
+        definitions,
+        compiled: Default::default(),
+        compiling: Default::default(),
+        elements: vec![],
+        pending: Default::default(),
+    };
+    let start = compiler.compile_reference(&start_name())?;
+    let mut elements = vec![];
+
+    // Element contents are compiled lazily to allow recursive references
+    // through elements.
+    while let Some(pattern) = compiler.pending.pop_front() {
+        elements.push(compiler.compile(pattern)?);
+    }
+
+    Ok(Grammar::new(
+        start,
+        compiler.elements.into_iter().zip(elements).collect(),
+    ))
+}
+
+struct Compiler<'a> {
+    definitions: &'a BTreeMap<Identifier, RncPattern>,
+    compiled: BTreeMap<Identifier, Arc<Pattern>>,
+    compiling: BTreeSet<Identifier>,
+    elements: Vec<NameClass>,
+    pending: VecDeque<&'a RncPattern>,
+}
+
+impl<'a> Compiler<'a> {
+    fn compile(&mut self, pattern: &'a RncPattern) -> Result<Arc<Pattern>, SchemaError> {
+        Ok(match pattern {
+            RncPattern::Attribute {
+                name_class,
+                pattern,
+            } => Pattern::Attribute(compile_name_class(name_class), self.compile(pattern)?).into(),
+            RncPattern::Choice(patterns) => {
+                let mut compiled = Pattern::not_allowed();
+
+                for pattern in patterns {
+                    compiled = Pattern::choice(compiled, self.compile(pattern)?);
+                }
+
+                compiled
+            }
+            RncPattern::Data { name, except, .. } => Pattern::Data(
+                compile_datatype(name),
+                except
+                    .as_ref()
+                    .map(|pattern| self.compile(pattern))
+                    .transpose()?,
+            )
+            .into(),
+            RncPattern::Element {
+                name_class,
+                pattern,
+            } => {
+                self.elements.push(compile_name_class(name_class));
+                self.pending.push_back(pattern);
+
+                Pattern::Element(self.elements.len() - 1).into()
+            }
+            RncPattern::Empty => Pattern::empty(),
+            RncPattern::External(_) => return Err(SchemaError::Unsupported("external")),
+            RncPattern::Grammar(_) => return Err(SchemaError::Unsupported("nested grammar")),
+            RncPattern::Group(patterns) => {
+                let mut compiled = Pattern::empty();
+
+                for pattern in patterns {
+                    compiled = Pattern::group(compiled, self.compile(pattern)?);
+                }
+
+                compiled
+            }
+            RncPattern::Interleave(patterns) => {
+                let mut compiled = Pattern::empty();
+
+                for pattern in patterns {
+                    compiled = Pattern::interleave(compiled, self.compile(pattern)?);
+                }
+
+                compiled
+            }
+            RncPattern::List(pattern) => Pattern::List(self.compile(pattern)?).into(),
+            RncPattern::Many0(pattern) => Pattern::choice(
+                Pattern::one_or_more(self.compile(pattern)?),
+                Pattern::empty(),
+            ),
+            RncPattern::Many1(pattern) => Pattern::one_or_more(self.compile(pattern)?),
+            RncPattern::Name(name) => {
+                if name.prefix.is_some() {
+                    return Err(SchemaError::Unsupported("prefixed reference"));
+                }
+
+                self.compile_reference(&name.local)?
+            }
+            RncPattern::NotAllowed => Pattern::not_allowed(),
+            RncPattern::Optional(pattern) => {
+                Pattern::choice(self.compile(pattern)?, Pattern::empty())
+            }
+            RncPattern::Text => Pattern::Text.into(),
+            RncPattern::Value { name, value } => Pattern::Value(
+                match name {
+                    Some(DatatypeName::String) => ValueType::String,
+                    Some(DatatypeName::Name(Name {
+                        prefix: Some(library),
+                        local,
+                    })) if library.component == XSD_DATATYPE_LIBRARY
+                        && local.component == "string" =>
+                    {
+                        ValueType::String
+                    }
+                    Some(DatatypeName::Name(_) | DatatypeName::Token) | None => ValueType::Token,
+                },
+                value.clone(),
+            )
+            .into(),
+        })
+    }
+
+    fn compile_reference(&mut self, name: &Identifier) -> Result<Arc<Pattern>, SchemaError> {
+        if let Some(pattern) = self.compiled.get(name) {
+            return Ok(pattern.clone());
+        } else if !self.compiling.insert(name.clone()) {
+            return Err(SchemaError::Unsupported(
+                "recursive reference outside elements",
+            ));
+        }
+
+        let pattern = self.compile(self.definitions.get(name).ok_or_else(|| {
+            if *name == start_name() {
+                SchemaError::MissingStart
+            } else {
+                SchemaError::UndefinedReference(name.to_string())
+            }
+        })?)?;
+
+        self.compiling.remove(name);
+        self.compiled.insert(name.clone(), pattern.clone());
+
+        Ok(pattern)
+    }
+}
+
+fn compile_name_class(name_class: &RncNameClass) -> NameClass {
+    match name_class {
+        RncNameClass::Any => NameClass::Any,
+        RncNameClass::Choice(name_classes) => {
+            NameClass::Choice(name_classes.iter().map(compile_name_class).collect())
+        }
+        RncNameClass::Except { base, except } => NameClass::Except(
+            compile_name_class(base).into(),
+            compile_name_class(except).into(),
+        ),
+        RncNameClass::Name(name) => NameClass::Name {
+            namespace: namespace(name.prefix.as_ref()),
+            local: name.local.to_string(),
+        },
+        RncNameClass::Namespace(prefix) => NameClass::Namespace(namespace(prefix.as_ref())),
+    }
+}
+
+// Prefixes are already replaced by namespace URIs by the loader.
+fn namespace(prefix: Option<&Identifier>) -> String {
+    prefix
+        .map(|prefix| prefix.component.clone())
+        .unwrap_or_default()
+}
+
+fn compile_datatype(name: &DatatypeName) -> Option<Datatype> {
+    match name {
+        DatatypeName::Name(Name {
+            prefix: Some(library),
+            local,
+        }) => Datatype::from_name(&library.component, &local.to_string()),
+        DatatypeName::Name(_) | DatatypeName::String | DatatypeName::Token => None,
+    }
+}
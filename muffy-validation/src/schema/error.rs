@@ -0,0 +1,62 @@
+use core::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+use muffy_rnc::{ParseError, SchemaError as RncSchemaError};
+use std::io;
+
+/// A schema error.
+#[derive(Debug)]
+pub enum SchemaError {
+    /// An invalid definition.
+    Definition(RncSchemaError),
+    /// An I/O error.
+    Io(io::Error),
+    /// A missing start pattern.
+    MissingStart,
+    /// A missing parent directory of a schema file.
+    NoParentDirectory,
+    /// A parse error.
+    Parse(ParseError),
+    /// An undefined namespace or datatype prefix.
+    UndefinedPrefix(String),
+    /// An undefined reference.
+    UndefinedReference(String),
+    /// An unsupported syntax.
+    Unsupported(&'static str),
+}
+
+impl Display for SchemaError {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Definition(error) => write!(formatter, "{error}"),
+            Self::Io(error) => write!(formatter, "{error}"),
+            Self::MissingStart => write!(formatter, "missing start pattern"),
+            Self::NoParentDirectory => write!(formatter, "no parent directory"),
+            Self::Parse(error) => write!(formatter, "{error}"),
+            Self::UndefinedPrefix(prefix) => write!(formatter, "undefined prefix: {prefix}"),
+            Self::UndefinedReference(name) => write!(formatter, "undefined reference: {name}"),
+            Self::Unsupported(name) => write!(formatter, "unsupported syntax: {name}"),
+        }
+    }
+}
+
+impl Error for SchemaError {}
+
+impl From<io::Error> for SchemaError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<ParseError> for SchemaError {
+    fn from(error: ParseError) -> Self {
+        Self::Parse(error)
+    }
+}
+
+impl From<RncSchemaError> for SchemaError {
+    fn from(error: RncSchemaError) -> Self {
+        Self::Definition(error)
+    }
+}
@@ -0,0 +1,292 @@
+use super::{name_class::NameClass, pattern::Pattern};
+use alloc::sync::Arc;
+
+/// A compiled grammar.
+#[derive(Debug)]
+pub struct Grammar {
+    start: Arc<Pattern>,
+    elements: Vec<(NameClass, Arc<Pattern>)>,
+}
+
+impl Grammar {
+    pub const fn new(start: Arc<Pattern>, elements: Vec<(NameClass, Arc<Pattern>)>) -> Self {
+        Self { start, elements }
+    }
+
+    pub const fn start(&self) -> &Arc<Pattern> {
+        &self.start
+    }
+
+    /// Returns a name class of an element pattern.
+    pub fn element_name_class(&self, index: usize) -> &NameClass {
+        &self.elements[index].0
+    }
+
+    /// Finds content of any element pattern matching a name.
+    pub fn find_element(&self, namespace: &str, local: &str) -> Option<&Arc<Pattern>> {
+        self.elements
+            .iter()
+            .find(|(name_class, _)| name_class.contains(namespace, local))
+            .map(|(_, content)| content)
+    }
+
+    pub fn start_tag_open(
+        &self,
+        pattern: &Arc<Pattern>,
+        namespace: &str,
+        local: &str,
+    ) -> Arc<Pattern> {
+        self.start_tag_open_by(pattern, &|_, name_class| {
+            name_class.contains(namespace, local)
+        })
+    }
+
+    /// Opens a start tag of elements matching a predicate on their indices and
+    /// name classes.
+    pub fn start_tag_open_by(
+        &self,
+        pattern: &Arc<Pattern>,
+        matches: &dyn Fn(usize, &NameClass) -> bool,
+    ) -> Arc<Pattern> {
+        match &**pattern {
+            Pattern::After(one, other) => {
+                apply_after(&self.start_tag_open_by(one, matches), &|pattern| {
+                    Pattern::after(pattern, other.clone())
+                })
+            }
+            Pattern::Choice(one, other) => Pattern::choice(
+                self.start_tag_open_by(one, matches),
+                self.start_tag_open_by(other, matches),
+            ),
+            Pattern::Element(index) => {
+                let (name_class, content) = &self.elements[*index];
+
+                if matches(*index, name_class) {
+                    Pattern::after(content.clone(), Pattern::empty())
+                } else {
+                    Pattern::not_allowed()
+                }
+            }
+            Pattern::Group(one, other) => {
+                let derivative = apply_after(&self.start_tag_open_by(one, matches), &|pattern| {
+                    Pattern::group(pattern, other.clone())
+                });
+
+                if one.nullable() {
+                    Pattern::choice(derivative, self.start_tag_open_by(other, matches))
+                } else {
+                    derivative
+                }
+            }
+            Pattern::Interleave(one, other) => Pattern::choice(
+                apply_after(&self.start_tag_open_by(one, matches), &|pattern| {
+                    Pattern::interleave(pattern, other.clone())
+                }),
+                apply_after(&self.start_tag_open_by(other, matches), &|pattern| {
+                    Pattern::interleave(one.clone(), pattern)
+                }),
+            ),
+            Pattern::OneOrMore(one) => {
+                apply_after(&self.start_tag_open_by(one, matches), &|other| {
+                    Pattern::group(other, Pattern::choice(pattern.clone(), Pattern::empty()))
+                })
+            }
+            Pattern::Attribute(..)
+            | Pattern::Data(..)
+            | Pattern::Empty
+            | Pattern::List(_)
+            | Pattern::NotAllowed
+            | Pattern::Text
+            | Pattern::Value(..) => Pattern::not_allowed(),
+        }
+    }
+
+    /// Derives a pattern by an attribute. If a value is `None`, only its
+    /// name is matched.
+    pub fn attribute(
+        pattern: &Arc<Pattern>,
+        namespace: &str,
+        local: &str,
+        value: Option<&str>,
+    ) -> Arc<Pattern> {
+        match &**pattern {
+            Pattern::After(one, other) => {
+                Pattern::after(Self::attribute(one, namespace, local, value), other.clone())
+            }
+            Pattern::Attribute(name_class, content) => {
+                if name_class.contains(namespace, local)
+                    && value.is_none_or(|value| Self::value_matches(content, value))
+                {
+                    Pattern::empty()
+                } else {
+                    Pattern::not_allowed()
+                }
+            }
+            Pattern::Choice(one, other) => Pattern::choice(
+                Self::attribute(one, namespace, local, value),
+                Self::attribute(other, namespace, local, value),
+            ),
+            Pattern::Group(one, other) => Pattern::choice(
+                Pattern::group(Self::attribute(one, namespace, local, value), other.clone()),
+                Pattern::group(one.clone(), Self::attribute(other, namespace, local, value)),
+            ),
+            Pattern::Interleave(one, other) => Pattern::choice(
+                Pattern::interleave(Self::attribute(one, namespace, local, value), other.clone()),
+                Pattern::interleave(one.clone(), Self::attribute(other, namespace, local, value)),
+            ),
+            Pattern::OneOrMore(one) => Pattern::group(
+                Self::attribute(one, namespace, local, value),
+                Pattern::choice(pattern.clone(), Pattern::empty()),
+            ),
+            Pattern::Data(..)
+            | Pattern::Element(_)
+            | Pattern::Empty
+            | Pattern::List(_)
+            | Pattern::NotAllowed
+            | Pattern::Text
+            | Pattern::Value(..) => Pattern::not_allowed(),
+        }
+    }
+
+    /// Closes a start tag. If it is lenient, missing attributes are ignored.
+    pub fn start_tag_close(pattern: &Arc<Pattern>, lenient: bool) -> Arc<Pattern> {
+        match &**pattern {
+            Pattern::After(one, other) => {
+                Pattern::after(Self::start_tag_close(one, lenient), other.clone())
+            }
+            Pattern::Attribute(..) => {
+                if lenient {
+                    Pattern::empty()
+                } else {
+                    Pattern::not_allowed()
+                }
+            }
+            Pattern::Choice(one, other) => Pattern::choice(
+                Self::start_tag_close(one, lenient),
+                Self::start_tag_close(other, lenient),
+            ),
+            Pattern::Group(one, other) => Pattern::group(
+                Self::start_tag_close(one, lenient),
+                Self::start_tag_close(other, lenient),
+            ),
+            Pattern::Interleave(one, other) => Pattern::interleave(
+                Self::start_tag_close(one, lenient),
+                Self::start_tag_close(other, lenient),
+            ),
+            Pattern::OneOrMore(one) => Pattern::one_or_more(Self::start_tag_close(one, lenient)),
+            Pattern::Data(..)
+            | Pattern::Element(_)
+            | Pattern::Empty
+            | Pattern::List(_)
+            | Pattern::NotAllowed
+            | Pattern::Text
+            | Pattern::Value(..) => pattern.clone(),
+        }
+    }
+
+    pub fn text(pattern: &Arc<Pattern>, text: &str) -> Arc<Pattern> {
+        match &**pattern {
+            Pattern::After(one, other) => Pattern::after(Self::text(one, text), other.clone()),
+            Pattern::Choice(one, other) => {
+                Pattern::choice(Self::text(one, text), Self::text(other, text))
+            }
+            Pattern::Data(datatype, except) => {
+                if datatype.is_none_or(|datatype| datatype.matches(text))
+                    && except
+                        .as_ref()
+                        .is_none_or(|except| !Self::text(except, text).nullable())
+                {
+                    Pattern::empty()
+                } else {
+                    Pattern::not_allowed()
+                }
+            }
+            Pattern::Group(one, other) => {
+                let derivative = Pattern::group(Self::text(one, text), other.clone());
+
+                if one.nullable() {
+                    Pattern::choice(derivative, Self::text(other, text))
+                } else {
+                    derivative
+                }
+            }
+            Pattern::Interleave(one, other) => Pattern::choice(
+                Pattern::interleave(Self::text(one, text), other.clone()),
+                Pattern::interleave(one.clone(), Self::text(other, text)),
+            ),
+            Pattern::List(content) => {
+                if text
+                    .split_ascii_whitespace()
+                    .fold(content.clone(), |pattern, token| {
+                        Self::text(&pattern, token)
+                    })
+                    .nullable()
+                {
+                    Pattern::empty()
+                } else {
+                    Pattern::not_allowed()
+                }
+            }
+            Pattern::OneOrMore(one) => Pattern::group(
+                Self::text(one, text),
+                Pattern::choice(pattern.clone(), Pattern::empty()),
+            ),
+            Pattern::Text => pattern.clone(),
+            Pattern::Value(value_type, value) => {
+                if value_type.matches(value, text) {
+                    Pattern::empty()
+                } else {
+                    Pattern::not_allowed()
+                }
+            }
+            Pattern::Attribute(..) | Pattern::Element(_) | Pattern::Empty | Pattern::NotAllowed => {
+                Pattern::not_allowed()
+            }
+        }
+    }
+
+    /// Ends an element. If it is lenient, missing content is ignored.
+    pub fn end_tag(pattern: &Arc<Pattern>, lenient: bool) -> Arc<Pattern> {
+        match &**pattern {
+            Pattern::After(one, other) => {
+                if lenient || one.nullable() {
+                    other.clone()
+                } else {
+                    Pattern::not_allowed()
+                }
+            }
+            Pattern::Choice(one, other) => {
+                Pattern::choice(Self::end_tag(one, lenient), Self::end_tag(other, lenient))
+            }
+            Pattern::Attribute(..)
+            | Pattern::Data(..)
+            | Pattern::Element(_)
+            | Pattern::Empty
+            | Pattern::Group(..)
+            | Pattern::Interleave(..)
+            | Pattern::List(_)
+            | Pattern::NotAllowed
+            | Pattern::OneOrMore(_)
+            | Pattern::Text
+            | Pattern::Value(..) => Pattern::not_allowed(),
+        }
+    }
+
+    fn value_matches(pattern: &Arc<Pattern>, value: &str) -> bool {
+        (pattern.nullable() && value.trim_ascii().is_empty())
+            || Self::text(pattern, value).nullable()
+    }
+}
+
+fn apply_after(
+    pattern: &Arc<Pattern>,
+    function: &dyn Fn(Arc<Pattern>) -> Arc<Pattern>,
+) -> Arc<Pattern> {
+    match &**pattern {
+        Pattern::After(one, other) => Pattern::after(one.clone(), function(other.clone())),
+        Pattern::Choice(one, other) => {
+            Pattern::choice(apply_after(one, function), apply_after(other, function))
+        }
+        _ => Pattern::not_allowed(),
+    }
+}
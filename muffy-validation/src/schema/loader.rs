@@ -0,0 +1,478 @@
+// Loading of schema files into definitions whose namespace and datatype
+// prefixes are replaced by their URIs as definitions of different files are
+// combined into a single grammar.
+
+use super::error::SchemaError;
+use alloc::collections::{BTreeMap, BTreeSet};
+use muffy_rnc::{
+    DatatypeName, Declaration, Definition, DefinitionSet, Grammar, GrammarContent, Identifier,
+    IncludeContent, Name, NameClass, Pattern, SchemaBody, Start, defined_names, parse_schema,
+};
+use std::{fs::read_to_string, path::Path};
+
+/// A name of a start pattern which never conflicts with defined names.
+const START_NAME: &str = "#start";
+
+const XML_NAMESPACE: &str = "http://www.w3.org/XML/1998/namespace";
+pub const XSD_DATATYPE_LIBRARY: &str = "http://www.w3.org/2001/XMLSchema-datatypes";
+
+/// Loads definitions including a start pattern from a schema file.
+pub fn load_definitions(path: &Path) -> Result<BTreeMap<Identifier, Pattern>, SchemaError> {
+    let mut definitions = DefinitionSet::default();
+
+    load_schema(path, "", &Default::default(), &mut definitions)?;
+
+    Ok(definitions.into_patterns())
+}
+
+pub fn start_name() -> Identifier {
+    identifier(START_NAME)
+}
+
+fn load_schema(
+    path: &Path,
+    default_namespace: &str,
+    overridden: &BTreeSet<Identifier>,
+    definitions: &mut DefinitionSet,
+) -> Result<(), SchemaError> {
+    let schema = parse_schema(&read_to_string(path)?)?;
+    let namespaces = Namespaces::new(&schema.declarations, default_namespace);
+
+    match &schema.body {
+        SchemaBody::Grammar(grammar) | SchemaBody::Pattern(Pattern::Grammar(grammar)) => {
+            load_grammar(
+                grammar,
+                &namespaces,
+                path.parent().ok_or(SchemaError::NoParentDirectory)?,
+                overridden,
+                definitions,
+            )
+        }
+        SchemaBody::Pattern(pattern) => define_start(
+            &Start {
+                combine: None,
+                pattern: pattern.clone(),
+            },
+            &namespaces,
+            overridden,
+            definitions,
+        ),
+    }
+}
+
+fn load_grammar(
+    grammar: &Grammar,
+    namespaces: &Namespaces,
+    directory: &Path,
+    overridden: &BTreeSet<Identifier>,
+    definitions: &mut DefinitionSet,
+) -> Result<(), SchemaError> {
+    for content in &grammar.contents {
+        match content {
+            GrammarContent::Definition(definition) => {
+                define(definition, namespaces, overridden, definitions)?
+            }
+            GrammarContent::Div(grammar) => {
+                load_grammar(grammar, namespaces, directory, overridden, definitions)?
+            }
+            GrammarContent::Include(include) => {
+                let mut names = defined_names(&include.contents);
+
+                if has_start(&include.contents) {
+                    names.insert(start_name());
+                }
+
+                load_schema(
+                    &directory.join(&include.uri),
+                    &if let Some(inherit) = &include.inherit {
+                        namespaces.namespace(&inherit.prefix)?
+                    } else {
+                        namespaces.default.clone()
+                    },
+                    &overridden.iter().cloned().chain(names).collect(),
+                    definitions,
+                )?;
+
+                load_include_contents(&include.contents, namespaces, overridden, definitions)?;
+            }
+            GrammarContent::Start(start) => {
+                define_start(start, namespaces, overridden, definitions)?
+            }
+            GrammarContent::Annotation(_) => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn load_include_contents(
+    contents: &[IncludeContent],
+    namespaces: &Namespaces,
+    overridden: &BTreeSet<Identifier>,
+    definitions: &mut DefinitionSet,
+) -> Result<(), SchemaError> {
+    for content in contents {
+        match content {
+            IncludeContent::Definition(definition) => {
+                define(definition, namespaces, overridden, definitions)?
+            }
+            IncludeContent::Div(contents) => {
+                load_include_contents(contents, namespaces, overridden, definitions)?
+            }
+            IncludeContent::Start(start) => {
+                define_start(start, namespaces, overridden, definitions)?
+            }
+            IncludeContent::Annotation(_) => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn has_start(contents: &[IncludeContent]) -> bool {
+    contents.iter().any(|content| match content {
+        IncludeContent::Start(_) => true,
+        IncludeContent::Div(contents) => has_start(contents),
+        IncludeContent::Annotation(_) | IncludeContent::Definition(_) => false,
+    })
+}
+
+fn define_start(
+    start: &Start,
+    namespaces: &Namespaces,
+    overridden: &BTreeSet<Identifier>,
+    definitions: &mut DefinitionSet,
+) -> Result<(), SchemaError> {
+    define(
+        &Definition {
+            name: start_name(),
+            combine: start.combine,
+            pattern: start.pattern.clone(),
+        },
+        namespaces,
+        overridden,
+        definitions,
+    )
+}
+
+fn define(
+    definition: &Definition,
+    namespaces: &Namespaces,
+    overridden: &BTreeSet<Identifier>,
+    definitions: &mut DefinitionSet,
+) -> Result<(), SchemaError> {
+    if !overridden.contains(&definition.name) {
+        definitions.define(&Definition {
+            name: definition.name.clone(),
+            combine: definition.combine,
+            pattern: namespaces.resolve_pattern(&definition.pattern)?,
+        })?;
+    }
+
+    Ok(())
+}
+
+fn identifier(component: &str) -> Identifier {
+    Identifier {
+        component: component.into(),
+        sub_components: vec![],
+    }
+}
+
+struct Namespaces {
+    default: String,
+    prefixes: BTreeMap<String, String>,
+    datatypes: BTreeMap<String, String>,
+}
+
+impl Namespaces {
+    fn new(declarations: &[Declaration], default: &str) -> Self {
+        let mut namespaces = Self {
+            default: default.into(),
+            prefixes: [("xml".into(), XML_NAMESPACE.into())].into(),
+            datatypes: [("xsd".into(), XSD_DATATYPE_LIBRARY.into())].into(),
+        };
+
+        for declaration in declarations {
+            match declaration {
+                Declaration::Datatypes(declaration) => {
+                    if let Some(prefix) = &declaration.prefix {
+                        namespaces
+                            .datatypes
+                            .insert(prefix.to_string(), declaration.uri.clone());
+                    }
+                }
+                Declaration::DefaultNamespace(declaration) => {
+                    namespaces.default = declaration.uri.clone();
+
+                    if let Some(prefix) = &declaration.prefix {
+                        namespaces
+                            .prefixes
+                            .insert(prefix.to_string(), declaration.uri.clone());
+                    }
+                }
+                Declaration::Namespace(declaration) => {
+                    namespaces
+                        .prefixes
+                        .insert(declaration.prefix.to_string(), declaration.uri.clone());
+                }
+            }
+        }
+
+        namespaces
+    }
+
+    fn namespace(&self, prefix: &Identifier) -> Result<String, SchemaError> {
+        self.prefixes
+            .get(&prefix.to_string())
+            .cloned()
+            .ok_or_else(|| SchemaError::UndefinedPrefix(prefix.to_string()))
+    }
+
+    fn resolve_pattern(&self, pattern: &Pattern) -> Result<Pattern, SchemaError> {
+        Ok(match pattern {
+            Pattern::Attribute {
+                name_class,
+                pattern,
+            } => Pattern::Attribute {
+                name_class: self.resolve_name_class(name_class, "")?,
+                pattern: self.resolve_pattern(pattern)?.into(),
+            },
+            Pattern::Choice(patterns) => Pattern::Choice(self.resolve_patterns(patterns)?),
+            Pattern::Data {
+                name,
+                parameters,
+                except,
+            } => Pattern::Data {
+                name: self.resolve_datatype_name(name)?,
+                parameters: parameters.clone(),
+                except: except
+                    .as_ref()
+                    .map(|pattern| self.resolve_pattern(pattern).map(Box::new))
+                    .transpose()?,
+            },
+            Pattern::Element {
+                name_class,
+                pattern,
+            } => Pattern::Element {
+                name_class: self.resolve_name_class(name_class, &self.default)?,
+                pattern: self.resolve_pattern(pattern)?.into(),
+            },
+            Pattern::Group(patterns) => Pattern::Group(self.resolve_patterns(patterns)?),
+            Pattern::Interleave(patterns) => Pattern::Interleave(self.resolve_patterns(patterns)?),
+            Pattern::List(pattern) => Pattern::List(self.resolve_pattern(pattern)?.into()),
+            Pattern::Many0(pattern) => Pattern::Many0(self.resolve_pattern(pattern)?.into()),
+            Pattern::Many1(pattern) => Pattern::Many1(self.resolve_pattern(pattern)?.into()),
+            Pattern::Optional(pattern) => Pattern::Optional(self.resolve_pattern(pattern)?.into()),
+            Pattern::Value { name, value } => Pattern::Value {
+                name: name
+                    .as_ref()
+                    .map(|name| self.resolve_datatype_name(name))
+                    .transpose()?,
+                value: value.clone(),
+            },
+            Pattern::Empty
+            | Pattern::External(_)
+            | Pattern::Grammar(_)
+            | Pattern::Name(_)
+            | Pattern::NotAllowed
+            | Pattern::Text => pattern.clone(),
+        })
+    }
+
+    fn resolve_patterns(&self, patterns: &[Pattern]) -> Result<Vec<Pattern>, SchemaError> {
+        patterns
+            .iter()
+            .map(|pattern| self.resolve_pattern(pattern))
+            .collect()
+    }
+
+    fn resolve_name_class(
+        &self,
+        name_class: &NameClass,
+        default: &str,
+    ) -> Result<NameClass, SchemaError> {
+        Ok(match name_class {
+            NameClass::Any => NameClass::Any,
+            NameClass::Choice(name_classes) => NameClass::Choice(
+                name_classes
+                    .iter()
+                    .map(|name_class| self.resolve_name_class(name_class, default))
+                    .collect::<Result<_, _>>()?,
+            ),
+            NameClass::Except { base, except } => NameClass::Except {
+                base: self.resolve_name_class(base, default)?.into(),
+                except: self.resolve_name_class(except, default)?.into(),
+            },
+            NameClass::Name(name) => NameClass::Name(Name {
+                prefix: Some(identifier(&if let Some(prefix) = &name.prefix {
+                    self.namespace(prefix)?
+                } else {
+                    default.into()
+                })),
+                local: name.local.clone(),
+            }),
+            NameClass::Namespace(prefix) => {
+                NameClass::Namespace(Some(identifier(&if let Some(prefix) = prefix {
+                    self.namespace(prefix)?
+                } else {
+                    Default::default()
+                })))
+            }
+        })
+    }
+
+    fn resolve_datatype_name(&self, name: &DatatypeName) -> Result<DatatypeName, SchemaError> {
+        Ok(match name {
+            DatatypeName::Name(Name {
+                prefix: Some(prefix),
+                local,
+            }) => DatatypeName::Name(Name {
+                prefix: Some(identifier(
+                    self.datatypes
+                        .get(&prefix.to_string())
+                        .ok_or_else(|| SchemaError::UndefinedPrefix(prefix.to_string()))?,
+                )),
+                local: local.clone(),
+            }),
+            DatatypeName::Name(_) | DatatypeName::String | DatatypeName::Token => name.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::fs::write;
+    use tempfile::tempdir;
+
+    fn load(files: &[(&str, &str)]) -> Result<BTreeMap<Identifier, Pattern>, SchemaError> {
+        let directory = tempdir().unwrap();
+
+        for (name, source) in files {
+            write(directory.path().join(name), source).unwrap();
+        }
+
+        load_definitions(&directory.path().join(files[0].0))
+    }
+
+    fn name(namespace: &str, local: &str) -> NameClass {
+        NameClass::Name(Name {
+            prefix: Some(identifier(namespace)),
+            local: identifier(local),
+        })
+    }
+
+    #[test]
+    fn load_start_pattern() {
+        assert_eq!(
+            load(&[("main.rnc", "element foo { empty }")]).unwrap(),
+            [(
+                start_name(),
+                Pattern::Element {
+                    name_class: name("", "foo"),
+                    pattern: Pattern::Empty.into(),
+                }
+            )]
+            .into()
+        );
+    }
+
+    #[test]
+    fn resolve_default_namespace() {
+        assert_eq!(
+            load(&[(
+                "main.rnc",
+                "default namespace = \"http://foo.example/\"\nstart = element foo { attribute bar { text } }"
+            )])
+            .unwrap()[&start_name()],
+            Pattern::Element {
+                name_class: name("http://foo.example/", "foo"),
+                pattern: Pattern::Attribute {
+                    name_class: name("", "bar"),
+                    pattern: Pattern::Text.into(),
+                }
+                .into(),
+            }
+        );
+    }
+
+    #[test]
+    fn resolve_namespace_prefix() {
+        assert_eq!(
+            load(&[(
+                "main.rnc",
+                "namespace foo = \"http://foo.example/\"\nstart = element foo:bar { empty }"
+            )])
+            .unwrap()[&start_name()],
+            Pattern::Element {
+                name_class: name("http://foo.example/", "bar"),
+                pattern: Pattern::Empty.into(),
+            }
+        );
+    }
+
+    #[test]
+    fn resolve_datatype_prefix() {
+        assert_eq!(
+            load(&[("main.rnc", "start = element foo { xsd:integer }")]).unwrap()[&start_name()],
+            Pattern::Element {
+                name_class: name("", "foo"),
+                pattern: Pattern::Data {
+                    name: DatatypeName::Name(Name {
+                        prefix: Some(identifier(XSD_DATATYPE_LIBRARY)),
+                        local: identifier("integer"),
+                    }),
+                    parameters: vec![],
+                    except: None,
+                }
+                .into(),
+            }
+        );
+    }
+
+    #[test]
+    fn inherit_default_namespace_in_include() {
+        assert_eq!(
+            load(&[
+                (
+                    "main.rnc",
+                    "default namespace = \"http://foo.example/\"\ninclude \"a.rnc\""
+                ),
+                ("a.rnc", "start = element foo { empty }"),
+            ])
+            .unwrap()[&start_name()],
+            Pattern::Element {
+                name_class: name("http://foo.example/", "foo"),
+                pattern: Pattern::Empty.into(),
+            }
+        );
+    }
+
+    #[test]
+    fn override_start_pattern_in_include() {
+        assert_eq!(
+            load(&[
+                (
+                    "main.rnc",
+                    "include \"a.rnc\" { start = element bar { empty } }"
+                ),
+                ("a.rnc", "start = element foo { empty }"),
+            ])
+            .unwrap()[&start_name()],
+            Pattern::Element {
+                name_class: name("", "bar"),
+                pattern: Pattern::Empty.into(),
+            }
+        );
+    }
+
+    #[test]
+    fn fail_on_undefined_prefix() {
+        assert!(matches!(
+            load(&[("main.rnc", "start = element foo:bar { empty }")]),
+            Err(SchemaError::UndefinedPrefix(prefix)) if prefix == "foo"
+        ));
+    }
+}
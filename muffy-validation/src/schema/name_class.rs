@@ -0,0 +1,79 @@
+use alloc::collections::BTreeSet;
+
+/// A name class with namespaces resolved.
+#[derive(Debug, Eq, PartialEq)]
+pub enum NameClass {
+    Any,
+    Choice(Vec<Self>),
+    Except(Box<Self>, Box<Self>),
+    Name { namespace: String, local: String },
+    Namespace(String),
+}
+
+impl NameClass {
+    pub fn contains(&self, namespace: &str, local: &str) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Choice(name_classes) => name_classes
+                .iter()
+                .any(|name_class| name_class.contains(namespace, local)),
+            Self::Except(base, except) => {
+                base.contains(namespace, local) && !except.contains(namespace, local)
+            }
+            Self::Name {
+                namespace: other_namespace,
+                local: other_local,
+            } => namespace == other_namespace && local == other_local,
+            Self::Namespace(other) => namespace == other,
+        }
+    }
+
+    /// Returns local names of names without wildcards for error messages.
+    pub fn names(&self) -> BTreeSet<String> {
+        match self {
+            Self::Choice(name_classes) => name_classes.iter().flat_map(Self::names).collect(),
+            Self::Name { local, .. } => [local.clone()].into(),
+            Self::Any | Self::Except(..) | Self::Namespace(_) => Default::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn name(namespace: &str, local: &str) -> NameClass {
+        NameClass::Name {
+            namespace: namespace.into(),
+            local: local.into(),
+        }
+    }
+
+    #[test]
+    fn contain_name() {
+        assert!(name("foo", "bar").contains("foo", "bar"));
+        assert!(!name("foo", "bar").contains("", "bar"));
+        assert!(!name("foo", "bar").contains("foo", "baz"));
+    }
+
+    #[test]
+    fn contain_name_in_namespace_except_name() {
+        let name_class = NameClass::Except(
+            NameClass::Namespace("foo".into()).into(),
+            name("foo", "bar").into(),
+        );
+
+        assert!(name_class.contains("foo", "baz"));
+        assert!(!name_class.contains("foo", "bar"));
+        assert!(!name_class.contains("", "baz"));
+    }
+
+    #[test]
+    fn collect_names() {
+        assert_eq!(
+            NameClass::Choice(vec![name("", "foo"), NameClass::Any, name("", "bar")]).names(),
+            ["bar".into(), "foo".into()].into()
+        );
+    }
+}
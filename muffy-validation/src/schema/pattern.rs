@@ -0,0 +1,163 @@
+use super::name_class::NameClass;
+use crate::datatype::Datatype;
+use alloc::sync::Arc;
+
+/// A pattern in a form for derivative-based validation.
+///
+/// See [An algorithm for RELAX NG validation](https://relaxng.org/jclark/derivative.html).
+#[derive(Debug, Eq, PartialEq)]
+pub enum Pattern {
+    After(Arc<Self>, Arc<Self>),
+    Attribute(NameClass, Arc<Self>),
+    Choice(Arc<Self>, Arc<Self>),
+    /// A datatype, which is `None` if it is unconstrained, with an optional
+    /// exception.
+    Data(Option<Datatype>, Option<Arc<Self>>),
+    /// An element referred by its index in a grammar.
+    Element(usize),
+    Empty,
+    Group(Arc<Self>, Arc<Self>),
+    Interleave(Arc<Self>, Arc<Self>),
+    List(Arc<Self>),
+    NotAllowed,
+    OneOrMore(Arc<Self>),
+    Text,
+    Value(ValueType, String),
+}
+
+/// A type of values determining their comparison.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ValueType {
+    String,
+    Token,
+}
+
+impl ValueType {
+    pub fn matches(self, expected: &str, value: &str) -> bool {
+        match self {
+            Self::String => expected == value,
+            Self::Token => expected
+                .split_ascii_whitespace()
+                .eq(value.split_ascii_whitespace()),
+        }
+    }
+}
+
+impl Pattern {
+    pub fn empty() -> Arc<Self> {
+        Self::Empty.into()
+    }
+
+    pub fn not_allowed() -> Arc<Self> {
+        Self::NotAllowed.into()
+    }
+
+    pub fn choice(one: Arc<Self>, other: Arc<Self>) -> Arc<Self> {
+        match (&*one, &*other) {
+            (Self::NotAllowed, _) => other,
+            (_, Self::NotAllowed) => one,
+            (_, Self::Choice(first, second)) if *first == one || *second == one => other,
+            _ if one == other => one,
+            _ => Self::Choice(one, other).into(),
+        }
+    }
+
+    pub fn group(one: Arc<Self>, other: Arc<Self>) -> Arc<Self> {
+        match (&*one, &*other) {
+            (Self::NotAllowed, _) | (_, Self::NotAllowed) => Self::not_allowed(),
+            (Self::Empty, _) => other,
+            (_, Self::Empty) => one,
+            _ => Self::Group(one, other).into(),
+        }
+    }
+
+    pub fn interleave(one: Arc<Self>, other: Arc<Self>) -> Arc<Self> {
+        match (&*one, &*other) {
+            (Self::NotAllowed, _) | (_, Self::NotAllowed) => Self::not_allowed(),
+            (Self::Empty, _) => other,
+            (_, Self::Empty) => one,
+            _ => Self::Interleave(one, other).into(),
+        }
+    }
+
+    pub fn after(one: Arc<Self>, other: Arc<Self>) -> Arc<Self> {
+        match (&*one, &*other) {
+            (Self::NotAllowed, _) | (_, Self::NotAllowed) => Self::not_allowed(),
+            _ => Self::After(one, other).into(),
+        }
+    }
+
+    pub fn one_or_more(pattern: Arc<Self>) -> Arc<Self> {
+        match &*pattern {
+            Self::NotAllowed | Self::Empty => pattern,
+            _ => Self::OneOrMore(pattern).into(),
+        }
+    }
+
+    pub const fn is_not_allowed(&self) -> bool {
+        matches!(self, Self::NotAllowed)
+    }
+
+    /// Returns `true` if a pattern matches an empty sequence.
+    pub fn nullable(&self) -> bool {
+        match self {
+            Self::Choice(one, other) => one.nullable() || other.nullable(),
+            Self::Group(one, other) | Self::Interleave(one, other) => {
+                one.nullable() && other.nullable()
+            }
+            Self::OneOrMore(pattern) => pattern.nullable(),
+            Self::Empty | Self::Text => true,
+            Self::After(..)
+            | Self::Attribute(..)
+            | Self::Data(..)
+            | Self::Element(_)
+            | Self::List(_)
+            | Self::NotAllowed
+            | Self::Value(..) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn simplify_choice() {
+        assert_eq!(
+            Pattern::choice(Pattern::not_allowed(), Pattern::Text.into()),
+            Pattern::Text.into()
+        );
+        assert_eq!(
+            Pattern::choice(Pattern::Text.into(), Pattern::Text.into()),
+            Pattern::Text.into()
+        );
+    }
+
+    #[test]
+    fn simplify_group() {
+        assert_eq!(
+            Pattern::group(Pattern::empty(), Pattern::Text.into()),
+            Pattern::Text.into()
+        );
+        assert_eq!(
+            Pattern::group(Pattern::Text.into(), Pattern::not_allowed()),
+            Pattern::not_allowed()
+        );
+    }
+
+    #[test]
+    fn check_nullable() {
+        assert!(Pattern::choice(Pattern::Element(0).into(), Pattern::empty()).nullable());
+        assert!(!Pattern::group(Pattern::Element(0).into(), Pattern::Text.into()).nullable());
+        assert!(Pattern::one_or_more(Pattern::Text.into()).nullable());
+    }
+
+    #[test]
+    fn match_values() {
+        assert!(ValueType::Token.matches("foo bar", " foo  bar "));
+        assert!(!ValueType::String.matches("foo bar", " foo  bar "));
+        assert!(ValueType::String.matches("foo", "foo"));
+    }
+}
@@ -0,0 +1,338 @@
+use super::{grammar::Grammar, pattern::Pattern};
+use crate::error::{AttributeError, ChildError, MarkupError};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+use muffy_document::document::{Element, Node, prefix_namespace};
+
+const TEXT_NAME: &str = "#text";
+
+/// A validator of element trees against a grammar.
+///
+/// It recovers from errors so that it reports as many errors as possible in a
+/// single pass. For example, an element not allowed in its parent is still
+/// validated against any element pattern matching its name.
+pub struct Validator<'a, 'b> {
+    grammar: &'a Grammar,
+    errors: Vec<(&'b Element, Option<MarkupError>)>,
+}
+
+impl<'a, 'b> Validator<'a, 'b> {
+    pub const fn new(grammar: &'a Grammar) -> Self {
+        Self {
+            grammar,
+            errors: vec![],
+        }
+    }
+
+    pub fn validate(mut self, root: &'b Element) -> Vec<(&'b Element, MarkupError)> {
+        let (namespace, local) = element_name(root);
+        let pattern = self
+            .grammar
+            .start_tag_open(self.grammar.start(), namespace, local);
+
+        if pattern.is_not_allowed() {
+            self.errors
+                .push((root, Some(MarkupError::UnknownTag(root.name().into()))));
+        } else {
+            self.validate_element(&pattern, root);
+        }
+
+        self.errors
+            .into_iter()
+            .filter_map(|(element, error)| Some((element, error?)))
+            .collect()
+    }
+
+    // Validates an element after its start tag is opened and returns a
+    // pattern after its end tag.
+    fn validate_element(&mut self, pattern: &Arc<Pattern>, element: &'b Element) -> Arc<Pattern> {
+        // Reserve a slot to report errors in document order.
+        let index = self.errors.len();
+        self.errors.push((element, None));
+
+        let mut errors = ElementErrors::default();
+        let mut pattern = pattern.clone();
+
+        for (name, value) in element.attributes() {
+            let (namespace, local) = attribute_name(name);
+            let derivative = Grammar::attribute(&pattern, namespace, local, Some(value));
+
+            if derivative.is_not_allowed() {
+                let derivative = Grammar::attribute(&pattern, namespace, local, None);
+
+                errors
+                    .invalid_attributes
+                    .entry(name.into())
+                    .or_default()
+                    .insert(if derivative.is_not_allowed() {
+                        AttributeError::NotAllowed
+                    } else {
+                        AttributeError::InvalidValue
+                    });
+
+                if !derivative.is_not_allowed() {
+                    pattern = derivative;
+                }
+            } else {
+                pattern = derivative;
+            }
+        }
+
+        let closed = Grammar::start_tag_close(&pattern, false);
+
+        pattern = if closed.is_not_allowed() {
+            errors
+                .missing_attributes
+                .extend(missing_attributes(&pattern));
+            Grammar::start_tag_close(&pattern, true)
+        } else {
+            closed
+        };
+
+        let children = merge_children(element);
+
+        if let [] | [Child::Text(_)] = children.as_slice() {
+            let text = if let [Child::Text(text)] = children.as_slice() {
+                text.as_str()
+            } else {
+                ""
+            };
+            let derivative = Grammar::text(&pattern, text);
+
+            if text.trim_ascii().is_empty() {
+                pattern = Pattern::choice(pattern, derivative);
+            } else if derivative.is_not_allowed() {
+                errors.insert_invalid_child(TEXT_NAME);
+            } else {
+                pattern = derivative;
+            }
+        } else {
+            for child in children {
+                match child {
+                    Child::Element(child) => {
+                        pattern = self.validate_child(&pattern, child, &mut errors);
+                    }
+                    Child::Text(text) => {
+                        if text.trim_ascii().is_empty() {
+                            continue;
+                        }
+
+                        let derivative = Grammar::text(&pattern, &text);
+
+                        if derivative.is_not_allowed() {
+                            errors.insert_invalid_child(TEXT_NAME);
+                        } else {
+                            pattern = derivative;
+                        }
+                    }
+                }
+            }
+        }
+
+        let ended = Grammar::end_tag(&pattern, false);
+
+        let pattern = if ended.is_not_allowed() {
+            let mut names = self.missing_children(&pattern);
+
+            // Invalid texts are already reported.
+            if errors.invalid_children.contains_key(TEXT_NAME) {
+                names.remove(TEXT_NAME);
+            }
+
+            errors.missing_children.extend(names);
+            Grammar::end_tag(&pattern, true)
+        } else {
+            ended
+        };
+
+        self.errors[index].1 = errors.into_error();
+
+        pattern
+    }
+
+    fn validate_child(
+        &mut self,
+        pattern: &Arc<Pattern>,
+        element: &'b Element,
+        errors: &mut ElementErrors,
+    ) -> Arc<Pattern> {
+        let grammar = self.grammar;
+        let (namespace, local) = element_name(element);
+        let derivative = grammar.start_tag_open(pattern, namespace, local);
+
+        if derivative.is_not_allowed() {
+            errors.insert_invalid_child(element.name());
+
+            if let Some(content) = grammar.find_element(namespace, local) {
+                self.validate_element(&Pattern::after(content.clone(), Pattern::empty()), element);
+            }
+
+            pattern.clone()
+        } else {
+            self.validate_element(&derivative, element)
+        }
+    }
+
+    // Finds names of elements which can come next. If some of them complete
+    // content alone, only those are reported.
+    fn missing_children(&self, pattern: &Arc<Pattern>) -> BTreeSet<String> {
+        let indices = first_elements(pattern);
+        let completing = indices
+            .iter()
+            .copied()
+            .filter(|&index| {
+                !Grammar::end_tag(
+                    &Grammar::end_tag(
+                        &self
+                            .grammar
+                            .start_tag_open_by(pattern, &|other, _| other == index),
+                        true,
+                    ),
+                    false,
+                )
+                .is_not_allowed()
+            })
+            .collect::<BTreeSet<_>>();
+        let names = if completing.is_empty() {
+            indices
+        } else {
+            completing
+        }
+        .into_iter()
+        .flat_map(|index| self.grammar.element_name_class(index).names())
+        .collect::<BTreeSet<_>>();
+
+        if names.is_empty() {
+            [TEXT_NAME.into()].into()
+        } else {
+            names
+        }
+    }
+}
+
+#[derive(Default)]
+struct ElementErrors {
+    invalid_attributes: BTreeMap<String, BTreeSet<AttributeError>>,
+    invalid_children: BTreeMap<String, BTreeSet<ChildError>>,
+    missing_attributes: BTreeSet<String>,
+    missing_children: BTreeSet<String>,
+}
+
+impl ElementErrors {
+    fn insert_invalid_child(&mut self, name: &str) {
+        self.invalid_children
+            .entry(name.into())
+            .or_default()
+            .insert(ChildError::NotAllowed);
+    }
+
+    fn into_error(self) -> Option<MarkupError> {
+        (!self.invalid_attributes.is_empty()
+            || !self.invalid_children.is_empty()
+            || !self.missing_attributes.is_empty()
+            || !self.missing_children.is_empty())
+        .then_some(MarkupError::InvalidElement {
+            invalid_attributes: self.invalid_attributes,
+            invalid_children: self.invalid_children,
+            missing_attributes: self.missing_attributes,
+            missing_children: self.missing_children,
+        })
+    }
+}
+
+enum Child<'a> {
+    Element(&'a Element),
+    Text(String),
+}
+
+// Merges adjacent texts skipping comments between them.
+fn merge_children(element: &Element) -> Vec<Child<'_>> {
+    let mut children = vec![];
+
+    for node in element.children() {
+        match node {
+            Node::Element(element) => children.push(Child::Element(element)),
+            Node::Text(text) => {
+                if let Some(Child::Text(last)) = children.last_mut() {
+                    last.push_str(text);
+                } else {
+                    children.push(Child::Text(text.clone()));
+                }
+            }
+            Node::Comment(_) => {}
+        }
+    }
+
+    children
+}
+
+fn missing_attributes(pattern: &Arc<Pattern>) -> BTreeSet<String> {
+    if !Grammar::start_tag_close(pattern, false).is_not_allowed() {
+        return Default::default();
+    }
+
+    match &**pattern {
+        Pattern::After(pattern, _) | Pattern::OneOrMore(pattern) => missing_attributes(pattern),
+        Pattern::Choice(one, other)
+        | Pattern::Group(one, other)
+        | Pattern::Interleave(one, other) => missing_attributes(one)
+            .into_iter()
+            .chain(missing_attributes(other))
+            .collect(),
+        Pattern::Attribute(name_class, _) => name_class.names(),
+        Pattern::Data(..)
+        | Pattern::Element(_)
+        | Pattern::Empty
+        | Pattern::List(_)
+        | Pattern::NotAllowed
+        | Pattern::Text
+        | Pattern::Value(..) => Default::default(),
+    }
+}
+
+fn first_elements(pattern: &Pattern) -> BTreeSet<usize> {
+    match pattern {
+        Pattern::After(pattern, _) | Pattern::OneOrMore(pattern) => first_elements(pattern),
+        Pattern::Choice(one, other) | Pattern::Interleave(one, other) => first_elements(one)
+            .into_iter()
+            .chain(first_elements(other))
+            .collect(),
+        Pattern::Group(one, other) => {
+            let mut indices = first_elements(one);
+
+            if one.nullable() {
+                indices.extend(first_elements(other));
+            }
+
+            indices
+        }
+        Pattern::Element(index) => [*index].into(),
+        Pattern::Attribute(..)
+        | Pattern::Data(..)
+        | Pattern::Empty
+        | Pattern::List(_)
+        | Pattern::NotAllowed
+        | Pattern::Text
+        | Pattern::Value(..) => Default::default(),
+    }
+}
+
+fn element_name(element: &Element) -> (&str, &str) {
+    let name = element.name();
+
+    (
+        element.namespace().unwrap_or_default(),
+        name.rsplit_once(':').map_or(name, |(_, local)| local),
+    )
+}
+
+// Prefixes of attributes are resolved by their canonical namespaces. Otherwise,
+// they are considered as namespaces themselves as they are in attribute names
+// of non-canonical prefixes colliding with canonical ones.
+fn attribute_name(name: &str) -> (&str, &str) {
+    name.rsplit_once(':').map_or(("", name), |(prefix, local)| {
+        (prefix_namespace(prefix).unwrap_or(prefix), local)
+    })
+}
@@ -5,6 +5,7 @@ use self::{state::State, variant_evaluation::VariantEvaluation};
 use crate::{
     attribute_set::AttributeSet,
     content::{Content, TEXT_TOKEN},
+    custom_element::CustomElement,
     error::{AttributeError, ChildError, MarkupError},
     rule::Rule,
     variant::Variant,
@@ -13,25 +14,90 @@ use alloc::collections::{BTreeMap, BTreeSet};
 use muffy_document::document::{Element, Node};
 use regex::Regex;
 
+const ANY_NAME: &str = "*";
 const EMPTY_ATTRIBUTE_SET: AttributeSet = AttributeSet {
     required: &[],
     optional: &[],
 };
 
-pub fn validate_rule(
+pub fn validate_element(
     element: &Element,
+    ancestors: &[&Element],
     ignored_attributes: &[Regex],
     ignored_elements: &[Regex],
+    custom_elements: &BTreeMap<String, CustomElement>,
+    find_rule: fn(&str) -> Option<&'static Rule>,
+) -> Result<(), MarkupError> {
+    if ignored_elements
+        .iter()
+        .any(|pattern| pattern.is_match(element.name()))
+    {
+        return Ok(());
+    }
+
+    let Some(rule) = find_rule(
+        custom_elements
+            .get(element.name())
+            .map_or(element.name(), |custom_element| {
+                custom_element.content().element_name()
+            }),
+    ) else {
+        // Descendants of elements with unconstrained content models can have any
+        // names.
+        return if ancestors
+            .iter()
+            .rev()
+            .find_map(|ancestor| find_rule(ancestor.name()))
+            .is_some_and(|rule| rule.children.contains(&ANY_NAME))
+        {
+            Ok(())
+        } else {
+            Err(MarkupError::UnknownTag(element.name().into()))
+        };
+    };
+
+    validate_rule(
+        element,
+        ignored_attributes,
+        ignored_elements,
+        custom_elements,
+        rule,
+    )
+}
+
+fn validate_rule(
+    element: &Element,
+    ignored_attributes: &[Regex],
+    ignored_elements: &[Regex],
+    custom_elements: &BTreeMap<String, CustomElement>,
     rule: &Rule,
 ) -> Result<(), MarkupError> {
+    let custom_element = custom_elements.get(element.name());
     let (attributes, exempt_attributes, disallowed_attributes) =
-        classify_attributes(element, ignored_attributes, rule);
-    let (children, disallowed_children) = classify_children(element, ignored_elements, rule);
+        classify_attributes(element, ignored_attributes, custom_element, rule);
+    let (children, disallowed_children) =
+        classify_children(element, ignored_elements, custom_elements, rule);
 
     let mut attribute_errors = disallowed_attributes
         .into_iter()
         .map(|name| (name.into(), [AttributeError::NotAllowed].into()))
         .collect::<BTreeMap<String, BTreeSet<AttributeError>>>();
+
+    if let Some(custom_element) = custom_element {
+        for (name, value) in element.attributes() {
+            if let Some(Some(values)) = custom_element.attributes().get(name)
+                && !values.contains(value)
+                && !ignored_attributes
+                    .iter()
+                    .any(|pattern| pattern.is_match(name))
+            {
+                attribute_errors
+                    .entry(name.into())
+                    .or_default()
+                    .insert(AttributeError::InvalidValue);
+            }
+        }
+    }
     let mut child_errors = disallowed_children
         .into_iter()
         .map(|name| (name.into(), [ChildError::NotAllowed].into()))
@@ -183,6 +249,7 @@ fn collect_missing_children(state: &State, names: &[&'static str]) -> BTreeSet<&
 fn classify_attributes<'a>(
     element: &'a Element,
     ignored_attributes: &[Regex],
+    custom_element: Option<&CustomElement>,
     rule: &Rule,
 ) -> (
     Vec<(&'static str, &'a str)>,
@@ -194,6 +261,11 @@ fn classify_attributes<'a>(
     let mut disallowed_attributes = vec![];
 
     for (name, value) in element.attributes() {
+        // Attributes of custom elements are validated separately.
+        if custom_element.is_some_and(|element| element.attributes().contains_key(name)) {
+            continue;
+        }
+
         let ignored = ignored_attributes
             .iter()
             .any(|pattern| pattern.is_match(name));
@@ -220,6 +292,7 @@ fn classify_attributes<'a>(
 fn classify_children<'a>(
     element: &'a Element,
     ignored_elements: &[Regex],
+    custom_elements: &BTreeMap<String, CustomElement>,
     rule: &Rule,
 ) -> (Vec<(&'static str, bool)>, Vec<&'a str>) {
     let mut children = vec![];
@@ -227,6 +300,7 @@ fn classify_children<'a>(
 
     for child in element.children() {
         let name = match child {
+            Node::Comment(_) => continue,
             Node::Element(child) => child.name(),
             Node::Text(text) => {
                 if text.chars().all(char::is_whitespace) {
@@ -239,8 +313,12 @@ fn classify_children<'a>(
         let exempt = ignored_elements
             .iter()
             .any(|pattern| pattern.is_match(name));
+        // Custom elements are placed as built-in elements of their categories.
+        let category_name = custom_elements.get(name).map_or(name, |custom_element| {
+            custom_element.category().element_name()
+        });
 
-        if let Some(name) = find_name(rule.children, name) {
+        if let Some(name) = find_name(rule.children, category_name) {
             children.push((name, exempt));
         } else if !exempt {
             disallowed_children.push(name);
@@ -598,6 +676,7 @@ mod tests {
                 &create_element("example", vec![("foo", ""), ("bar", "")], vec!["one"]),
                 &[],
                 &[],
+                &Default::default(),
                 &RULE,
             ),
             Ok(())
@@ -611,6 +690,7 @@ mod tests {
                 &create_element("example", vec![("baz", "")], vec![]),
                 &[],
                 &[],
+                &Default::default(),
                 &RULE,
             ),
             Ok(())
@@ -624,6 +704,7 @@ mod tests {
                 &create_element("example", vec![("foo", ""), ("unknown", "")], vec!["one"]),
                 &[],
                 &[],
+                &Default::default(),
                 &RULE,
             ),
             Err(MarkupError::InvalidElement {
@@ -643,6 +724,7 @@ mod tests {
                 &create_element("example", vec![("baz", ""), ("bar", "")], vec![]),
                 &[],
                 &[],
+                &Default::default(),
                 &RULE,
             ),
             Err(MarkupError::InvalidElement {
@@ -661,6 +743,7 @@ mod tests {
                 &create_element("example", vec![("bar", "")], vec!["one"]),
                 &[],
                 &[],
+                &Default::default(),
                 &RULE,
             ),
             Err(MarkupError::InvalidElement {
@@ -679,6 +762,7 @@ mod tests {
                 &create_element("example", vec![("foo", "")], vec!["two", "one"]),
                 &[],
                 &[],
+                &Default::default(),
                 &RULE,
             ),
             Err(MarkupError::InvalidElement {
@@ -697,6 +781,7 @@ mod tests {
                 &create_element("example", vec![("foo", "")], vec![]),
                 &[],
                 &[],
+                &Default::default(),
                 &RULE,
             ),
             Err(MarkupError::InvalidElement {
@@ -715,6 +800,7 @@ mod tests {
                 &create_element("example", vec![("foo", ""), ("data-x", "")], vec!["one"]),
                 &[Regex::new("^data-.*$").unwrap()],
                 &[],
+                &Default::default(),
                 &RULE,
             ),
             Ok(())
@@ -728,6 +814,7 @@ mod tests {
                 &create_element("example", vec![("foo", "")], vec!["one", "custom-x"]),
                 &[],
                 &[Regex::new("^custom-.*$").unwrap()],
+                &Default::default(),
                 &RULE,
             ),
             Ok(())
@@ -741,6 +828,7 @@ mod tests {
                 &create_element("example", vec![("foo", "")], vec!["one"]),
                 &[Regex::new("^foo$").unwrap()],
                 &[],
+                &Default::default(),
                 &RULE,
             ),
             Ok(())
@@ -754,6 +842,7 @@ mod tests {
                 &create_element("example", vec![("foo", "")], vec!["one"]),
                 &[],
                 &[Regex::new("^one$").unwrap()],
+                &Default::default(),
                 &RULE,
             ),
             Ok(())
@@ -767,6 +856,7 @@ mod tests {
                 &create_element("example", vec![], vec!["one"]),
                 &[Regex::new("^foo$").unwrap()],
                 &[],
+                &Default::default(),
                 &RULE,
             ),
             Ok(())
@@ -780,6 +870,7 @@ mod tests {
                 &create_element("example", vec![("foo", "")], vec![]),
                 &[],
                 &[Regex::new("^one$").unwrap()],
+                &Default::default(),
                 &RULE,
             ),
             Ok(())
@@ -793,6 +884,7 @@ mod tests {
                 &create_element("example", vec![("foo", "")], vec!["two", "one", "two"]),
                 &[],
                 &[Regex::new("^two$").unwrap()],
+                &Default::default(),
                 &RULE,
             ),
             Ok(())
@@ -808,6 +900,7 @@ mod tests {
                 &create_element("example", vec![("bar", "")], vec![]),
                 &[],
                 &[],
+                &Default::default(),
                 &RULE,
             ),
             Err(MarkupError::InvalidElement {
@@ -846,6 +939,7 @@ mod tests {
                 &create_element("example", vec![("foo", "")], vec![]),
                 &[],
                 &[],
+                &Default::default(),
                 &ATTRIBUTE_SET_RULE,
             ),
             Err(MarkupError::InvalidElement {
@@ -862,6 +956,7 @@ mod tests {
                 &create_element("example", vec![("bar", "")], vec![]),
                 &[],
                 &[],
+                &Default::default(),
                 &ATTRIBUTE_SET_RULE,
             ),
             Ok(())
@@ -895,6 +990,7 @@ mod tests {
                     &create_element("example", vec![("kind", "one")], vec![]),
                     &[],
                     &[],
+                    &Default::default(),
                     &VALUE_RULE,
                 ),
                 Ok(())
@@ -908,6 +1004,7 @@ mod tests {
                     &create_element("example", vec![("kind", "three")], vec![]),
                     &[],
                     &[],
+                    &Default::default(),
                     &VALUE_RULE,
                 ),
                 Err(MarkupError::InvalidElement {
@@ -927,6 +1024,7 @@ mod tests {
                     &create_element("example", vec![("kind", "three")], vec![]),
                     &[Regex::new("^kind$").unwrap()],
                     &[],
+                    &Default::default(),
                     &VALUE_RULE,
                 ),
                 Ok(())
@@ -969,6 +1067,7 @@ mod tests {
                     &create_element("example", vec![("kind", "two")], vec!["two"]),
                     &[],
                     &[],
+                    &Default::default(),
                     &VARIANT_RULE,
                 ),
                 Ok(())
@@ -981,6 +1080,7 @@ mod tests {
                     &create_element("example", vec![("kind", "two")], vec![]),
                     &[],
                     &[],
+                    &Default::default(),
                     &VARIANT_RULE,
                 ),
                 Err(MarkupError::InvalidElement {
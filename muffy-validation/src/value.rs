@@ -1,7 +1,10 @@
-use crate::literal::Literal;
+use crate::{datatype::Datatype, literal::Literal};
 
 pub enum Value {
     Any,
+    Choice(&'static [Self]),
+    Datatype(Datatype),
+    List(&'static Self),
     LiteralSet(&'static [Literal]),
     TokenList(&'static [Literal]),
 }
@@ -10,6 +13,11 @@ impl Value {
     pub fn matches(&self, value: &str) -> bool {
         match self {
             Self::Any => true,
+            Self::Choice(values) => values.iter().any(|other| other.matches(value)),
+            Self::Datatype(datatype) => datatype.matches(value),
+            Self::List(item) => value
+                .split_ascii_whitespace()
+                .all(|token| item.matches(token)),
             Self::LiteralSet(literals) => matches_literal(literals, value),
             // Browsers process only the first recognized token, so a value is
             // valid as long as it names something known.
@@ -49,6 +57,36 @@ mod tests {
         assert!(!VALUE.matches("baz"));
     }
 
+    #[test]
+    fn match_choice() {
+        const VALUE: Value = Value::Choice(&[
+            Value::Datatype(Datatype::Integer),
+            Value::LiteralSet(&[Literal::Exact("auto")]),
+        ]);
+
+        assert!(VALUE.matches("42"));
+        assert!(VALUE.matches("auto"));
+        assert!(!VALUE.matches("foo"));
+    }
+
+    #[test]
+    fn match_datatype() {
+        const VALUE: Value = Value::Datatype(Datatype::Integer);
+
+        assert!(VALUE.matches("42"));
+        assert!(!VALUE.matches("abc"));
+    }
+
+    #[test]
+    fn match_list() {
+        const VALUE: Value = Value::List(&Value::Datatype(Datatype::Integer));
+
+        assert!(VALUE.matches(""));
+        assert!(VALUE.matches("1"));
+        assert!(VALUE.matches(" 1  2\t3 "));
+        assert!(!VALUE.matches("1 x 3"));
+    }
+
     mod token_list {
         use super::*;
 
@@ -0,0 +1,270 @@
+use crate::{
+    DocumentOutput,
+    element::Element,
+    error::{Error, ItemError},
+};
+use alloc::collections::BTreeSet;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tokio::fs::{read_to_string, write};
+
+/// A baseline of known errors.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Baseline {
+    entries: BTreeSet<BaselineEntry>,
+}
+
+impl Baseline {
+    /// Creates a baseline.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns entries.
+    pub fn entries(&self) -> impl ExactSizeIterator<Item = &BaselineEntry> {
+        self.entries.iter()
+    }
+
+    /// Inserts errors in a document.
+    pub fn insert_document(&mut self, document: &DocumentOutput) {
+        for output in document.elements() {
+            for error in output.results().filter_map(|result| result.as_ref().err()) {
+                self.entries.insert(BaselineEntry::new(
+                    document.url().as_str(),
+                    output.element(),
+                    error,
+                ));
+            }
+        }
+    }
+
+    /// Removes errors in the baseline from a document.
+    pub fn filter_document(&self, mut document: DocumentOutput) -> DocumentOutput {
+        let url = document.url().to_string();
+
+        document.retain_results(|element, result| {
+            result.as_ref().err().is_none_or(|error| {
+                !self
+                    .entries
+                    .contains(&BaselineEntry::new(&url, element, error))
+            })
+        });
+
+        document
+    }
+
+    /// Returns entries not found in another baseline.
+    pub fn stale_entries<'a>(
+        &'a self,
+        current: &'a Self,
+    ) -> impl Iterator<Item = &'a BaselineEntry> {
+        self.entries.difference(&current.entries)
+    }
+}
+
+/// A baseline entry.
+///
+/// Entries are keyed on error codes and messages without positions in
+/// documents so that they survive edits moving errors around.
+#[derive(Clone, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct BaselineEntry {
+    url: String,
+    element: String,
+    code: String,
+    error: String,
+}
+
+impl BaselineEntry {
+    fn new(url: &str, element: &Element, error: &ItemError) -> Self {
+        Self {
+            url: url.into(),
+            element: [element.name().into()]
+                .into_iter()
+                .chain(
+                    element
+                        .attributes()
+                        .iter()
+                        .map(|(key, value)| format!("{key}=\"{value}\"")),
+                )
+                .collect::<Vec<_>>()
+                .join(" "),
+            code: error.code().into(),
+            error: strip_position(&error.to_string()).into(),
+        }
+    }
+
+    /// Returns a document URL.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns an element.
+    pub fn element(&self) -> &str {
+        &self.element
+    }
+
+    /// Returns an error code.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Returns an error message.
+    pub fn error(&self) -> &str {
+        &self.error
+    }
+}
+
+// Strips a trailing position of `<line>:<column>` format from a message.
+fn strip_position(message: &str) -> &str {
+    message
+        .rsplit_once(" at ")
+        .filter(|(_, position)| {
+            position.split_once(':').is_some_and(|(line, column)| {
+                [line, column].iter().all(|number| {
+                    !number.is_empty() && number.bytes().all(|byte| byte.is_ascii_digit())
+                })
+            })
+        })
+        .map_or(message, |(message, _)| message)
+}
+
+/// Reads a baseline file.
+pub async fn read_baseline(path: &Path) -> Result<Baseline, Error> {
+    Ok(serde_json::from_str(&read_to_string(path).await?)?)
+}
+
+/// Writes a baseline file.
+pub async fn write_baseline(path: &Path, baseline: &Baseline) -> Result<(), Error> {
+    write(path, serde_json::to_string_pretty(baseline)? + "\n").await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{element_output::ElementOutput, error::ItemError, item_output::ItemOutput};
+    use http::StatusCode;
+    use pretty_assertions::assert_eq;
+    use tempfile::tempdir;
+    use url::Url;
+
+    fn document_output(statuses: &[StatusCode]) -> DocumentOutput {
+        DocumentOutput::new(
+            Url::parse("https://foo.com").unwrap(),
+            vec![ElementOutput::new(
+                Element::new("a".into(), vec![("href".into(), "/bar".into())]),
+                [Ok(ItemOutput::new())]
+                    .into_iter()
+                    .chain(
+                        statuses
+                            .iter()
+                            .map(|&status| Err(ItemError::HttpStatus(status))),
+                    )
+                    .collect(),
+            )],
+        )
+    }
+
+    #[test]
+    fn insert_document() {
+        let mut baseline = Baseline::new();
+
+        baseline.insert_document(&document_output(&[StatusCode::NOT_FOUND]));
+
+        assert_eq!(
+            baseline.entries().collect::<Vec<_>>(),
+            vec![&BaselineEntry {
+                url: "https://foo.com/".into(),
+                element: "a href=\"/bar\"".into(),
+                code: "http-status".into(),
+                error: "invalid status 404 Not Found".into(),
+            }]
+        );
+    }
+
+    #[test]
+    fn filter_known_errors() {
+        let mut baseline = Baseline::new();
+        baseline.insert_document(&document_output(&[StatusCode::NOT_FOUND]));
+
+        let document = baseline.filter_document(document_output(&[
+            StatusCode::NOT_FOUND,
+            StatusCode::FORBIDDEN,
+        ]));
+
+        assert_eq!(document.metrics().success(), 1);
+        assert_eq!(document.metrics().error(), 1);
+        assert!(matches!(
+            document
+                .elements()
+                .flat_map(ElementOutput::results)
+                .find_map(|result| result.as_ref().err()),
+            Some(ItemError::HttpStatus(StatusCode::FORBIDDEN))
+        ));
+    }
+
+    #[test]
+    fn find_stale_entries() {
+        let mut baseline = Baseline::new();
+        baseline.insert_document(&document_output(&[
+            StatusCode::NOT_FOUND,
+            StatusCode::FORBIDDEN,
+        ]));
+        let mut current = Baseline::new();
+        current.insert_document(&document_output(&[StatusCode::NOT_FOUND]));
+
+        assert_eq!(
+            baseline
+                .stale_entries(&current)
+                .map(BaselineEntry::error)
+                .collect::<Vec<_>>(),
+            vec!["invalid status 403 Forbidden"]
+        );
+    }
+
+    #[test]
+    fn filter_errors_at_different_positions() {
+        let document_output = |message: &str| {
+            DocumentOutput::new(
+                Url::parse("https://foo.com").unwrap(),
+                vec![ElementOutput::new(
+                    Element::new("style".into(), vec![]),
+                    vec![Err(ItemError::CssSyntax(message.into()))],
+                )],
+            )
+        };
+        let mut baseline = Baseline::new();
+        baseline.insert_document(&document_output("Invalid empty selector at 1:1"));
+
+        let document = baseline.filter_document(document_output("Invalid empty selector at 3:5"));
+
+        assert_eq!(document.metrics().error(), 0);
+        assert_eq!(
+            baseline
+                .entries()
+                .map(|entry| (entry.code(), entry.error()))
+                .collect::<Vec<_>>(),
+            vec![("css-syntax", "invalid CSS: Invalid empty selector")]
+        );
+    }
+
+    #[test]
+    fn keep_messages_without_positions() {
+        assert_eq!(strip_position("foo at bar"), "foo at bar");
+        assert_eq!(strip_position("foo at 1:"), "foo at 1:");
+        assert_eq!(strip_position("foo at 1:2"), "foo");
+    }
+
+    #[tokio::test]
+    async fn read_written_baseline() {
+        let directory = tempdir().unwrap();
+        let file = directory.path().join("baseline.json");
+        let mut baseline = Baseline::new();
+        baseline.insert_document(&document_output(&[StatusCode::NOT_FOUND]));
+
+        write_baseline(&file, &baseline).await.unwrap();
+
+        assert_eq!(read_baseline(&file).await.unwrap(), baseline);
+    }
+}
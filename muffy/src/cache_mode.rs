@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// A source of freshness of cached responses.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CacheMode {
+    /// A configured maximum age for all responses.
+    #[default]
+    Fixed,
+    /// `Cache-Control` and `Expires` headers of responses.
+    ///
+    /// Their lifetimes are clamped by configured minimum and maximum ages.
+    Headers,
+}
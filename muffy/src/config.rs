@@ -7,9 +7,16 @@ pub use self::{
     serde::{SerializableConfig, compile_config},
     toml::read_config,
 };
-use alloc::sync::Arc;
-use core::{cmp::Reverse, ops::Deref, time::Duration};
+use crate::{cache_mode::CacheMode, request_method::RequestMethod, severity::Severity};
+use alloc::{collections::BTreeMap, sync::Arc};
+use core::{
+    cmp::Reverse,
+    fmt::{self, Debug, Formatter},
+    ops::Deref,
+    time::Duration,
+};
 use http::{HeaderMap, StatusCode};
+use muffy_validation::{CustomElement, Schema};
 use regex::Regex;
 use rlimit::{Resource, getrlimit};
 use std::collections::{HashMap, HashSet};
@@ -25,6 +32,10 @@ pub const DEFAULT_MAX_REDIRECTS: usize = 16;
 pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
 
 const DEFAULT_MINIMUM_CONCURRENCY: usize = 256;
+// A placeholder of secrets in debug outputs.
+const REDACTED: &str = "<redacted>";
+// Severities of errors not fatal to documents by default.
+const DEFAULT_SEVERITIES: &[(&str, Severity)] = &[("meta-refresh", Severity::Warning)];
 
 /// Returns a default concurrency.
 pub fn default_concurrency() -> usize {
@@ -145,9 +156,12 @@ pub struct SiteConfig {
     headers: HeaderMap,
     ignored_fragments: Vec<Regex>,
     max_redirects: usize,
+    method: RequestMethod,
+    proxy: Option<Arc<ProxyConfig>>,
     recursive: bool,
     retry: Arc<RetryConfig>,
     scheme: SchemeConfig,
+    severities: HashMap<String, Severity>,
     status: StatusConfig,
     timeout: Option<Duration>,
     validation: ValidationConfig,
@@ -194,11 +208,39 @@ impl SiteConfig {
         &self.scheme
     }
 
+    /// Returns severities of errors by their codes.
+    pub const fn severities(&self) -> &HashMap<String, Severity> {
+        &self.severities
+    }
+
+    /// Returns a severity of errors with a code.
+    pub fn severity(&self, code: &str) -> Severity {
+        self.severities.get(code).copied().unwrap_or_else(|| {
+            DEFAULT_SEVERITIES
+                .iter()
+                .find(|(other, _)| *other == code)
+                .map(|(_, severity)| *severity)
+                .unwrap_or_default()
+        })
+    }
+
     /// Returns a maximum number of redirects.
     pub const fn max_redirects(&self) -> usize {
         self.max_redirects
     }
 
+    /// Returns a request method policy.
+    pub const fn method(&self) -> RequestMethod {
+        self.method
+    }
+
+    /// Returns a proxy configuration.
+    ///
+    /// If it is `None`, proxies are configured by environment variables.
+    pub const fn proxy(&self) -> Option<&Arc<ProxyConfig>> {
+        self.proxy.as_ref()
+    }
+
     /// Returns a timeout.
     pub const fn timeout(&self) -> Option<Duration> {
         self.timeout
@@ -256,12 +298,30 @@ impl SiteConfig {
         self
     }
 
+    /// Sets severities of errors by their codes.
+    pub fn set_severities(mut self, severities: HashMap<String, Severity>) -> Self {
+        self.severities = severities;
+        self
+    }
+
     /// Sets a maximum number of redirects.
     pub const fn set_max_redirects(mut self, count: usize) -> Self {
         self.max_redirects = count;
         self
     }
 
+    /// Sets a request method policy.
+    pub const fn set_method(mut self, method: RequestMethod) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// Sets a proxy configuration.
+    pub fn set_proxy(mut self, proxy: Option<Arc<ProxyConfig>>) -> Self {
+        self.proxy = proxy;
+        self
+    }
+
     /// Sets a timeout.
     pub const fn set_timeout(mut self, duration: Option<Duration>) -> Self {
         self.timeout = duration;
@@ -288,9 +348,12 @@ impl PartialEq for SiteConfig {
             && self.headers == other.headers
             && patterns_equal(&self.ignored_fragments, &other.ignored_fragments)
             && self.max_redirects == other.max_redirects
+            && self.method == other.method
+            && self.proxy == other.proxy
             && self.recursive == other.recursive
             && self.retry == other.retry
             && self.scheme == other.scheme
+            && self.severities == other.severities
             && self.status == other.status
             && self.timeout == other.timeout
             && self.validation == other.validation
@@ -364,27 +427,99 @@ impl Default for SchemeConfig {
 /// A validation configuration.
 #[derive(Clone, Debug, Eq, PartialEq, Default)]
 pub struct ValidationConfig {
+    aria: bool,
+    duplicate_ids: bool,
     html: Option<MarkupConfig>,
+    id_references: Option<IdReferenceConfig>,
+    mathml: Option<MarkupConfig>,
+    meta_refresh: bool,
+    schemas: Vec<SchemaConfig>,
     svg: Option<MarkupConfig>,
 }
 
 impl ValidationConfig {
+    /// Returns `true` if ARIA roles, states, and properties are validated.
+    pub const fn aria(&self) -> bool {
+        self.aria
+    }
+
+    /// Returns `true` if duplicate IDs are validated.
+    pub const fn duplicate_ids(&self) -> bool {
+        self.duplicate_ids
+    }
+
     /// Returns an HTML validation configuration.
     pub const fn html(&self) -> Option<&MarkupConfig> {
         self.html.as_ref()
     }
 
+    /// Returns an ID reference validation configuration.
+    pub const fn id_references(&self) -> Option<&IdReferenceConfig> {
+        self.id_references.as_ref()
+    }
+
+    /// Returns a MathML validation configuration.
+    pub const fn mathml(&self) -> Option<&MarkupConfig> {
+        self.mathml.as_ref()
+    }
+
+    /// Returns `true` if `<meta http-equiv="refresh">` elements are reported.
+    pub const fn meta_refresh(&self) -> bool {
+        self.meta_refresh
+    }
+
+    /// Returns schema validation configurations.
+    pub fn schemas(&self) -> &[SchemaConfig] {
+        &self.schemas
+    }
+
     /// Returns an SVG validation configuration.
     pub const fn svg(&self) -> Option<&MarkupConfig> {
         self.svg.as_ref()
     }
 
+    /// Sets whether ARIA roles, states, and properties are validated.
+    pub const fn set_aria(mut self, aria: bool) -> Self {
+        self.aria = aria;
+        self
+    }
+
+    /// Sets whether duplicate IDs are validated.
+    pub const fn set_duplicate_ids(mut self, duplicate_ids: bool) -> Self {
+        self.duplicate_ids = duplicate_ids;
+        self
+    }
+
     /// Sets an HTML validation configuration.
     pub fn set_html(mut self, config: Option<MarkupConfig>) -> Self {
         self.html = config;
         self
     }
 
+    /// Sets an ID reference validation configuration.
+    pub fn set_id_references(mut self, config: Option<IdReferenceConfig>) -> Self {
+        self.id_references = config;
+        self
+    }
+
+    /// Sets a MathML validation configuration.
+    pub fn set_mathml(mut self, config: Option<MarkupConfig>) -> Self {
+        self.mathml = config;
+        self
+    }
+
+    /// Sets whether `<meta http-equiv="refresh">` elements are reported.
+    pub const fn set_meta_refresh(mut self, meta_refresh: bool) -> Self {
+        self.meta_refresh = meta_refresh;
+        self
+    }
+
+    /// Sets schema validation configurations.
+    pub fn set_schemas(mut self, schemas: Vec<SchemaConfig>) -> Self {
+        self.schemas = schemas;
+        self
+    }
+
     /// Sets an SVG validation configuration.
     pub fn set_svg(mut self, config: Option<MarkupConfig>) -> Self {
         self.svg = config;
@@ -392,22 +527,125 @@ impl ValidationConfig {
     }
 }
 
+/// An ID reference validation configuration.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct IdReferenceConfig {
+    attributes: Vec<String>,
+}
+
+impl IdReferenceConfig {
+    /// Creates an ID reference validation configuration.
+    pub const fn new() -> Self {
+        Self { attributes: vec![] }
+    }
+
+    /// Returns names of attributes referring to IDs in addition to built-in
+    /// ones.
+    pub fn attributes(&self) -> &[String] {
+        &self.attributes
+    }
+
+    /// Sets names of attributes referring to IDs in addition to built-in ones.
+    pub fn set_attributes(mut self, attributes: Vec<String>) -> Self {
+        self.attributes = attributes;
+        self
+    }
+}
+
+/// A schema validation configuration.
+///
+/// Documents matching either its media type or URL pattern are validated
+/// against its schema.
+#[derive(Clone, Debug)]
+pub struct SchemaConfig {
+    media_type: Option<String>,
+    url: Option<Regex>,
+    schema: Arc<Schema>,
+}
+
+impl SchemaConfig {
+    /// Creates a schema validation configuration.
+    pub const fn new(schema: Arc<Schema>) -> Self {
+        Self {
+            media_type: None,
+            url: None,
+            schema,
+        }
+    }
+
+    /// Returns a media type.
+    pub fn media_type(&self) -> Option<&str> {
+        self.media_type.as_deref()
+    }
+
+    /// Returns a URL pattern.
+    pub const fn url(&self) -> Option<&Regex> {
+        self.url.as_ref()
+    }
+
+    /// Returns a schema.
+    pub const fn schema(&self) -> &Arc<Schema> {
+        &self.schema
+    }
+
+    /// Sets a media type.
+    pub fn set_media_type(mut self, media_type: Option<String>) -> Self {
+        self.media_type = media_type;
+        self
+    }
+
+    /// Sets a URL pattern.
+    pub fn set_url(mut self, url: Option<Regex>) -> Self {
+        self.url = url;
+        self
+    }
+
+    /// Returns `true` if a document matches the configuration.
+    pub fn matches(&self, url: &Url, media_type: Option<&str>) -> bool {
+        self.media_type.as_deref().is_some_and(|expected| {
+            media_type.is_some_and(|media_type| media_type.eq_ignore_ascii_case(expected))
+        }) || self
+            .url
+            .as_ref()
+            .is_some_and(|pattern| pattern.is_match(url.as_str()))
+    }
+}
+
+impl PartialEq for SchemaConfig {
+    fn eq(&self, other: &Self) -> bool {
+        self.media_type == other.media_type
+            && self.url.as_ref().map(Regex::as_str) == other.url.as_ref().map(Regex::as_str)
+            && Arc::ptr_eq(&self.schema, &other.schema)
+    }
+}
+
+impl Eq for SchemaConfig {}
+
 /// A markup validation configuration.
 #[derive(Clone, Debug, Default)]
 pub struct MarkupConfig {
+    custom_elements: BTreeMap<String, CustomElement>,
     ignored_attributes: Vec<Regex>,
     ignored_elements: Vec<Regex>,
+    ignored_syntax_errors: Vec<Regex>,
 }
 
 impl MarkupConfig {
     /// Creates a markup validation configuration.
     pub const fn new(ignored_attributes: Vec<Regex>, ignored_elements: Vec<Regex>) -> Self {
         Self {
+            custom_elements: BTreeMap::new(),
             ignored_attributes,
             ignored_elements,
+            ignored_syntax_errors: vec![],
         }
     }
 
+    /// Returns custom elements.
+    pub const fn custom_elements(&self) -> &BTreeMap<String, CustomElement> {
+        &self.custom_elements
+    }
+
     /// Returns ignored attributes.
     pub fn ignored_attributes(&self) -> &[Regex] {
         &self.ignored_attributes
@@ -417,12 +655,31 @@ impl MarkupConfig {
     pub fn ignored_elements(&self) -> &[Regex] {
         &self.ignored_elements
     }
+
+    /// Returns ignored syntax errors.
+    pub fn ignored_syntax_errors(&self) -> &[Regex] {
+        &self.ignored_syntax_errors
+    }
+
+    /// Sets custom elements.
+    pub fn set_custom_elements(mut self, elements: BTreeMap<String, CustomElement>) -> Self {
+        self.custom_elements = elements;
+        self
+    }
+
+    /// Sets ignored syntax errors.
+    pub fn set_ignored_syntax_errors(mut self, errors: Vec<Regex>) -> Self {
+        self.ignored_syntax_errors = errors;
+        self
+    }
 }
 
 impl PartialEq for MarkupConfig {
     fn eq(&self, other: &Self) -> bool {
-        patterns_equal(&self.ignored_attributes, &other.ignored_attributes)
+        self.custom_elements == other.custom_elements
+            && patterns_equal(&self.ignored_attributes, &other.ignored_attributes)
             && patterns_equal(&self.ignored_elements, &other.ignored_elements)
+            && patterns_equal(&self.ignored_syntax_errors, &other.ignored_syntax_errors)
     }
 }
 
@@ -431,7 +688,9 @@ impl Eq for MarkupConfig {}
 /// A cache configuration.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct CacheConfig {
-    max_age: Duration,
+    max_age: Option<Duration>,
+    min_age: Duration,
+    mode: CacheMode,
     stale_while_revalidate: Duration,
 }
 
@@ -442,21 +701,46 @@ impl CacheConfig {
     }
 
     /// Returns a maximum age.
-    pub const fn max_age(&self) -> Duration {
+    ///
+    /// If it is `None`, cached responses are stale immediately in the fixed
+    /// mode while their lifetimes are unbounded in the headers mode.
+    pub const fn max_age(&self) -> Option<Duration> {
         self.max_age
     }
 
+    /// Returns a minimum age.
+    pub const fn min_age(&self) -> Duration {
+        self.min_age
+    }
+
+    /// Returns a cache mode.
+    pub const fn mode(&self) -> CacheMode {
+        self.mode
+    }
+
     /// Returns a stale-while-revalidate period.
     pub const fn stale_while_revalidate(&self) -> Duration {
         self.stale_while_revalidate
     }
 
     /// Sets a maximum age.
-    pub const fn set_max_age(mut self, age: Duration) -> Self {
+    pub const fn set_max_age(mut self, age: Option<Duration>) -> Self {
         self.max_age = age;
         self
     }
 
+    /// Sets a minimum age.
+    pub const fn set_min_age(mut self, age: Duration) -> Self {
+        self.min_age = age;
+        self
+    }
+
+    /// Sets a cache mode.
+    pub const fn set_mode(mut self, mode: CacheMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
     /// Sets a stale-while-revalidate period.
     pub const fn set_stale_while_revalidate(mut self, period: Duration) -> Self {
         self.stale_while_revalidate = period;
@@ -464,6 +748,67 @@ impl CacheConfig {
     }
 }
 
+/// A proxy configuration.
+#[derive(Clone, Default, Eq, Hash, PartialEq)]
+pub struct ProxyConfig {
+    url: Option<Url>,
+    username: Option<String>,
+    password: Option<String>,
+}
+
+impl ProxyConfig {
+    /// Creates a proxy configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a proxy URL.
+    ///
+    /// If it is `None`, requests bypass any proxies.
+    pub const fn url(&self) -> Option<&Url> {
+        self.url.as_ref()
+    }
+
+    /// Returns a username for basic authentication.
+    pub fn username(&self) -> Option<&str> {
+        self.username.as_deref()
+    }
+
+    /// Returns a password for basic authentication.
+    pub fn password(&self) -> Option<&str> {
+        self.password.as_deref()
+    }
+
+    /// Sets a proxy URL.
+    pub fn set_url(mut self, url: Option<Url>) -> Self {
+        self.url = url;
+        self
+    }
+
+    /// Sets a username for basic authentication.
+    pub fn set_username(mut self, username: Option<String>) -> Self {
+        self.username = username;
+        self
+    }
+
+    /// Sets a password for basic authentication.
+    pub fn set_password(mut self, password: Option<String>) -> Self {
+        self.password = password;
+        self
+    }
+}
+
+impl Debug for ProxyConfig {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("ProxyConfig")
+            .field("url", &self.url)
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| REDACTED))
+            .finish()
+    }
+}
+
 /// A retry configuration.
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct RetryConfig {
@@ -677,6 +1022,32 @@ impl SiteRateLimitConfig {
 mod tests {
     use super::*;
 
+    #[test]
+    fn redact_proxy_password() {
+        let config = ProxyConfig::new()
+            .set_username(Some("foo".into()))
+            .set_password(Some("bar".into()));
+
+        assert_eq!(
+            format!("{config:?}"),
+            r#"ProxyConfig { url: None, username: Some("foo"), password: Some("<redacted>") }"#
+        );
+    }
+
+    #[test]
+    fn default_severities() {
+        let config = SiteConfig::default();
+
+        assert_eq!(config.severity("http-status"), Severity::Error);
+        assert_eq!(config.severity("meta-refresh"), Severity::Warning);
+        assert_eq!(
+            config
+                .set_severities([("meta-refresh".to_owned(), Severity::Error)].into())
+                .severity("meta-refresh"),
+            Severity::Error
+        );
+    }
+
     #[test]
     fn site_config_path_order() {
         let config = Config::new(
@@ -3,6 +3,7 @@ use core::{
     fmt,
     fmt::{Display, Formatter},
 };
+use muffy_validation::SchemaError;
 use std::{io, path::PathBuf};
 use url::ParseError;
 
@@ -27,8 +28,12 @@ pub enum ConfigError {
     MultipleDefaultSiteConfigs(Vec<String>),
     /// A regular expression error.
     Regex(regex::Error),
+    /// A schema error.
+    Schema(SchemaError),
     /// A TOML deserialization error.
     TomlDeserialize(toml::de::Error),
+    /// An unknown error code.
+    UnknownErrorCode(String),
     /// A URL parse error.
     UrlParse(ParseError),
 }
@@ -76,9 +81,15 @@ impl Display for ConfigError {
             Self::Regex(error) => {
                 write!(formatter, "{error}")
             }
+            Self::Schema(error) => {
+                write!(formatter, "{error}")
+            }
             Self::TomlDeserialize(error) => {
                 write!(formatter, "{error}")
             }
+            Self::UnknownErrorCode(code) => {
+                write!(formatter, "unknown error code: {code}")
+            }
             Self::UrlParse(error) => {
                 write!(formatter, "{error}")
             }
@@ -112,6 +123,12 @@ impl From<regex::Error> for ConfigError {
     }
 }
 
+impl From<SchemaError> for ConfigError {
+    fn from(error: SchemaError) -> Self {
+        Self::Schema(error)
+    }
+}
+
 impl From<io::Error> for ConfigError {
     fn from(error: io::Error) -> Self {
         Self::Io(error)
@@ -1,11 +1,20 @@
 use super::error::ConfigError;
-use crate::config::{
-    DEFAULT_ACCEPTED_SCHEMES, DEFAULT_ACCEPTED_STATUS_CODES, DEFAULT_MAX_REDIRECTS, DEFAULT_TIMEOUT,
+use crate::{
+    cache_mode::CacheMode,
+    config::{
+        DEFAULT_ACCEPTED_SCHEMES, DEFAULT_ACCEPTED_STATUS_CODES, DEFAULT_MAX_REDIRECTS,
+        DEFAULT_TIMEOUT, REDACTED,
+    },
+    error::ItemError,
+    request_method::RequestMethod,
+    severity::Severity,
 };
 use alloc::{collections::BTreeMap, sync::Arc};
+use core::fmt::{self, Debug, Formatter};
 use duration_string::DurationString;
 use http::{HeaderName, HeaderValue, StatusCode};
 use itertools::Itertools;
+use muffy_validation::{CustomElement, Schema};
 use petgraph::{
     Graph,
     algo::{kosaraju_scc, toposort},
@@ -43,6 +52,7 @@ pub struct SerializableConfig {
     extend: Option<PathBuf>,
     concurrency: Option<usize>,
     cache: Option<GlobalCacheConfig>,
+    proxy: Option<ProxyConfig>,
     rate_limit: Option<RateLimitConfig>,
     sites: BTreeMap<String, SiteConfig>,
 }
@@ -53,6 +63,19 @@ impl SerializableConfig {
         self.extend.as_deref()
     }
 
+    /// Resolves relative paths in a configuration against a directory.
+    pub fn resolve_paths(&mut self, directory: &Path) {
+        for schema in self
+            .sites
+            .values_mut()
+            .flat_map(|site| &mut site.validation)
+            .flat_map(|validation| &mut validation.schemas)
+            .flatten()
+        {
+            schema.path = directory.join(&schema.path);
+        }
+    }
+
     /// Merges another configuration.
     pub fn merge(&mut self, other: Self) {
         if other.concurrency.is_some() {
@@ -67,6 +90,10 @@ impl SerializableConfig {
             }
         }
 
+        if other.proxy.is_some() {
+            self.proxy = other.proxy;
+        }
+
         if let Some(limit) = other.rate_limit {
             self.rate_limit = Some(limit);
         }
@@ -105,11 +132,14 @@ struct SiteConfig {
     ignore: Option<bool>,
     ignored_fragments: Option<Vec<String>>,
     max_redirects: Option<usize>,
+    method: Option<RequestMethod>,
+    proxy: Option<ProxyConfig>,
     rate_limit: Option<RateLimitConfig>,
     recurse: Option<bool>,
     retry: Option<RetryConfig>,
     roots: Option<HashSet<Url>>,
     schemes: Option<HashSet<String>>,
+    severities: Option<HashMap<String, Severity>>,
     statuses: Option<HashSet<u16>>,
     timeout: Option<DurationString>,
     validation: Option<ValidationConfig>,
@@ -159,6 +189,14 @@ impl SiteConfig {
             self.max_redirects = other.max_redirects;
         }
 
+        if other.method.is_some() {
+            self.method = other.method;
+        }
+
+        if other.proxy.is_some() {
+            self.proxy = other.proxy;
+        }
+
         if other.rate_limit.is_some() {
             self.rate_limit = other.rate_limit;
         }
@@ -183,6 +221,14 @@ impl SiteConfig {
             self.schemes = other.schemes;
         }
 
+        if let Some(other) = other.severities {
+            if let Some(severities) = &mut self.severities {
+                severities.extend(other);
+            } else {
+                self.severities = Some(other);
+            }
+        }
+
         if other.statuses.is_some() {
             self.statuses = other.statuses;
         }
@@ -201,10 +247,31 @@ impl SiteConfig {
     }
 }
 
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ProxyConfig {
+    url: Option<Url>,
+    username: Option<String>,
+    password: Option<String>,
+}
+
+impl Debug for ProxyConfig {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("ProxyConfig")
+            .field("url", &self.url)
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| REDACTED))
+            .finish()
+    }
+}
+
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 struct CacheConfig {
     max_age: Option<DurationString>,
+    min_age: Option<DurationString>,
+    mode: Option<CacheMode>,
     stale_while_revalidate: Option<DurationString>,
 }
 
@@ -214,6 +281,14 @@ impl CacheConfig {
             self.max_age = other.max_age;
         }
 
+        if other.min_age.is_some() {
+            self.min_age = other.min_age;
+        }
+
+        if other.mode.is_some() {
+            self.mode = other.mode;
+        }
+
         if other.stale_while_revalidate.is_some() {
             self.stale_while_revalidate = other.stale_while_revalidate;
         }
@@ -223,12 +298,34 @@ impl CacheConfig {
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 struct ValidationConfig {
+    aria: Option<bool>,
+    duplicate_ids: Option<bool>,
     html: Option<MarkupConfig>,
+    id_references: Option<IdReferenceConfig>,
+    mathml: Option<MarkupConfig>,
+    meta_refresh: Option<bool>,
+    schemas: Option<Vec<SchemaConfig>>,
     svg: Option<MarkupConfig>,
 }
 
 impl ValidationConfig {
     fn merge(&mut self, other: Self) {
+        if other.aria.is_some() {
+            self.aria = other.aria;
+        }
+
+        if other.duplicate_ids.is_some() {
+            self.duplicate_ids = other.duplicate_ids;
+        }
+
+        if other.meta_refresh.is_some() {
+            self.meta_refresh = other.meta_refresh;
+        }
+
+        if let Some(other) = other.schemas {
+            self.schemas.get_or_insert_default().extend(other);
+        }
+
         if let Some(other) = other.html {
             if let Some(html) = &mut self.html {
                 html.merge(other);
@@ -237,6 +334,22 @@ impl ValidationConfig {
             }
         }
 
+        if let Some(other) = other.id_references {
+            if let Some(id_references) = &mut self.id_references {
+                id_references.merge(other);
+            } else {
+                self.id_references = Some(other);
+            }
+        }
+
+        if let Some(other) = other.mathml {
+            if let Some(mathml) = &mut self.mathml {
+                mathml.merge(other);
+            } else {
+                self.mathml = Some(other);
+            }
+        }
+
         if let Some(other) = other.svg {
             if let Some(svg) = &mut self.svg {
                 svg.merge(other);
@@ -247,15 +360,49 @@ impl ValidationConfig {
     }
 }
 
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct IdReferenceConfig {
+    attributes: Option<Vec<String>>,
+}
+
+impl IdReferenceConfig {
+    fn merge(&mut self, other: Self) {
+        if let Some(other) = other.attributes {
+            if let Some(attributes) = &mut self.attributes {
+                attributes.extend(other);
+                attributes.sort();
+                attributes.dedup();
+            } else {
+                self.attributes = Some(other);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SchemaConfig {
+    path: PathBuf,
+    media_type: Option<String>,
+    url: Option<String>,
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 struct MarkupConfig {
+    custom_elements: Option<BTreeMap<String, CustomElementConfig>>,
     ignored_attributes: Option<Vec<String>>,
     ignored_elements: Option<Vec<String>>,
+    ignored_syntax_errors: Option<Vec<String>>,
 }
 
 impl MarkupConfig {
     fn merge(&mut self, other: Self) {
+        if let Some(other) = other.custom_elements {
+            self.custom_elements.get_or_insert_default().extend(other);
+        }
+
         if let Some(other) = other.ignored_attributes {
             if let Some(prefixes) = &mut self.ignored_attributes {
                 prefixes.extend(other);
@@ -275,6 +422,46 @@ impl MarkupConfig {
                 self.ignored_elements = Some(other);
             }
         }
+
+        if let Some(other) = other.ignored_syntax_errors {
+            if let Some(patterns) = &mut self.ignored_syntax_errors {
+                patterns.extend(other);
+                patterns.sort();
+                patterns.dedup();
+            } else {
+                self.ignored_syntax_errors = Some(other);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CustomElementConfig {
+    attributes: Option<BTreeMap<String, CustomAttributeConfig>>,
+    category: Option<ContentCategory>,
+    content: Option<ContentCategory>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CustomAttributeConfig {
+    values: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum ContentCategory {
+    Flow,
+    Phrasing,
+}
+
+impl From<ContentCategory> for muffy_validation::ContentCategory {
+    fn from(category: ContentCategory) -> Self {
+        match category {
+            ContentCategory::Flow => Self::Flow,
+            ContentCategory::Phrasing => Self::Phrasing,
+        }
     }
 }
 
@@ -378,6 +565,9 @@ pub fn compile_config(config: SerializableConfig) -> Result<super::Config, Confi
         .map(|(name, site)| (name.as_str(), site))
         .collect::<HashMap<_, _>>();
 
+    let default = DEFAULT_SITE_CONFIG
+        .clone()
+        .set_proxy(config.proxy.as_ref().map(compile_proxy_config));
     let mut recursion = HashMap::<&str, _>::default();
     let mut configs = HashMap::<&str, Arc<_>>::default();
 
@@ -403,7 +593,7 @@ pub fn compile_config(config: SerializableConfig) -> Result<super::Config, Confi
                 if let Some(name) = &site.extend {
                     &configs[name.as_str()]
                 } else {
-                    &DEFAULT_SITE_CONFIG
+                    &default
                 },
             )?
             .into(),
@@ -420,7 +610,7 @@ pub fn compile_config(config: SerializableConfig) -> Result<super::Config, Confi
             .collect(),
         default_name
             .and_then(|name| configs.get(name).cloned())
-            .unwrap_or_else(|| DEFAULT_SITE_CONFIG.clone().into()),
+            .unwrap_or_else(|| default.clone().into()),
         included_sites
             .iter()
             .flat_map(|(name, site)| {
@@ -495,7 +685,19 @@ fn compile_site_config(
                     site.cache
                         .as_ref()
                         .and_then(|cache| cache.max_age.as_deref().copied())
-                        .unwrap_or(parent.cache().max_age()),
+                        .or(parent.cache().max_age()),
+                )
+                .set_min_age(
+                    site.cache
+                        .as_ref()
+                        .and_then(|cache| cache.min_age.as_deref().copied())
+                        .unwrap_or(parent.cache().min_age()),
+                )
+                .set_mode(
+                    site.cache
+                        .as_ref()
+                        .and_then(|cache| cache.mode)
+                        .unwrap_or(parent.cache().mode()),
                 )
                 .set_stale_while_revalidate(
                     site.cache
@@ -522,6 +724,24 @@ fn compile_site_config(
             &site.ignored_fragments,
             Some(parent.ignored_fragments()),
         )?)
+        .set_severities(
+            site.severities
+                .as_ref()
+                .map(|severities| {
+                    if let Some(code) = severities
+                        .keys()
+                        .find(|code| !ItemError::codes().any(|other| other == code.as_str()))
+                    {
+                        return Err(ConfigError::UnknownErrorCode(code.clone()));
+                    }
+
+                    let mut merged = parent.severities().clone();
+                    merged.extend(severities.clone());
+                    Ok(merged)
+                })
+                .transpose()?
+                .unwrap_or_else(|| parent.severities().clone()),
+        )
         .set_status(
             site.statuses
                 .as_ref()
@@ -545,6 +765,13 @@ fn compile_site_config(
                 .unwrap_or(parent.scheme().clone()),
         )
         .set_max_redirects(site.max_redirects.unwrap_or(parent.max_redirects()))
+        .set_method(site.method.unwrap_or(parent.method()))
+        .set_proxy(
+            site.proxy
+                .as_ref()
+                .map(compile_proxy_config)
+                .or_else(|| parent.proxy().cloned()),
+        )
         .set_timeout(site.timeout.as_deref().copied().or(parent.timeout()))
         .set_retry(if let Some(retry) = &site.retry {
             super::RetryConfig::default()
@@ -580,12 +807,56 @@ fn compile_site_config(
         .set_recursive(site.recurse == Some(true))
         .set_validation(
             super::ValidationConfig::default()
+                .set_aria(
+                    site.validation
+                        .as_ref()
+                        .and_then(|validation| validation.aria)
+                        .unwrap_or(parent.validation().aria()),
+                )
+                .set_duplicate_ids(
+                    site.validation
+                        .as_ref()
+                        .and_then(|validation| validation.duplicate_ids)
+                        .unwrap_or(parent.validation().duplicate_ids()),
+                )
                 .set_html(compile_markup_config(
                     site.validation
                         .as_ref()
                         .and_then(|validation| validation.html.as_ref()),
                     parent.validation().html(),
                 )?)
+                .set_id_references(compile_id_reference_config(
+                    site.validation
+                        .as_ref()
+                        .and_then(|validation| validation.id_references.as_ref()),
+                    parent.validation().id_references(),
+                ))
+                .set_mathml(compile_markup_config(
+                    site.validation
+                        .as_ref()
+                        .and_then(|validation| validation.mathml.as_ref()),
+                    parent.validation().mathml(),
+                )?)
+                .set_meta_refresh(
+                    site.validation
+                        .as_ref()
+                        .and_then(|validation| validation.meta_refresh)
+                        .unwrap_or(parent.validation().meta_refresh()),
+                )
+                .set_schemas(
+                    if let Some(schemas) = site
+                        .validation
+                        .as_ref()
+                        .and_then(|validation| validation.schemas.as_ref())
+                    {
+                        schemas
+                            .iter()
+                            .map(compile_schema_config)
+                            .collect::<Result<_, _>>()?
+                    } else {
+                        parent.validation().schemas().to_vec()
+                    },
+                )
                 .set_svg(compile_markup_config(
                     site.validation
                         .as_ref()
@@ -595,26 +866,110 @@ fn compile_site_config(
         ))
 }
 
+fn compile_proxy_config(config: &ProxyConfig) -> Arc<super::ProxyConfig> {
+    super::ProxyConfig::new()
+        .set_url(config.url.clone())
+        .set_username(config.username.clone())
+        .set_password(config.password.clone())
+        .into()
+}
+
+fn compile_schema_config(config: &SchemaConfig) -> Result<super::SchemaConfig, ConfigError> {
+    Ok(super::SchemaConfig::new(Schema::load(&config.path)?.into())
+        .set_media_type(config.media_type.clone())
+        .set_url(
+            config
+                .url
+                .as_ref()
+                .map(|pattern| Regex::new(&format!("^(?:{pattern})$")))
+                .transpose()?,
+        ))
+}
+
+fn compile_id_reference_config(
+    config: Option<&IdReferenceConfig>,
+    parent: Option<&super::IdReferenceConfig>,
+) -> Option<super::IdReferenceConfig> {
+    if let Some(config) = config {
+        Some(
+            super::IdReferenceConfig::new().set_attributes(
+                config
+                    .attributes
+                    .clone()
+                    .or_else(|| parent.map(|parent| parent.attributes().to_vec()))
+                    .unwrap_or_default(),
+            ),
+        )
+    } else {
+        parent.cloned()
+    }
+}
+
 fn compile_markup_config(
     config: Option<&MarkupConfig>,
     parent: Option<&super::MarkupConfig>,
 ) -> Result<Option<super::MarkupConfig>, ConfigError> {
     Ok(if let Some(config) = config {
-        Some(super::MarkupConfig::new(
-            compile_patterns(
-                &config.ignored_attributes,
-                parent.map(|parent| parent.ignored_attributes()),
-            )?,
-            compile_patterns(
-                &config.ignored_elements,
-                parent.map(|parent| parent.ignored_elements()),
-            )?,
-        ))
+        Some(
+            super::MarkupConfig::new(
+                compile_patterns(
+                    &config.ignored_attributes,
+                    parent.map(|parent| parent.ignored_attributes()),
+                )?,
+                compile_patterns(
+                    &config.ignored_elements,
+                    parent.map(|parent| parent.ignored_elements()),
+                )?,
+            )
+            .set_ignored_syntax_errors(compile_patterns(
+                &config.ignored_syntax_errors,
+                parent.map(|parent| parent.ignored_syntax_errors()),
+            )?)
+            .set_custom_elements(if let Some(elements) = &config.custom_elements {
+                elements
+                    .iter()
+                    .map(|(name, element)| (name.clone(), compile_custom_element(element)))
+                    .collect()
+            } else {
+                parent
+                    .map(|parent| parent.custom_elements().clone())
+                    .unwrap_or_default()
+            }),
+        )
     } else {
         parent.cloned()
     })
 }
 
+fn compile_custom_element(config: &CustomElementConfig) -> CustomElement {
+    let mut element = CustomElement::new().set_attributes(
+        config
+            .attributes
+            .iter()
+            .flatten()
+            .map(|(name, attribute)| {
+                (
+                    name.clone(),
+                    attribute
+                        .values
+                        .as_ref()
+                        .map(|values| values.iter().cloned().collect()),
+                )
+            })
+            .collect(),
+    );
+
+    if let Some(category) = config.category {
+        element = element.set_category(category.into());
+    }
+
+    if let Some(content) = config.content {
+        element = element.set_content(content.into());
+    }
+
+    element
+}
+
 fn compile_patterns(
     patterns: &Option<Vec<String>>,
     parent_patterns: Option<&[Regex]>,
@@ -685,8 +1040,10 @@ mod tests {
     use pretty_assertions::assert_eq;
     use std::{
         collections::{HashMap, HashSet},
+        fs::write,
         path::PathBuf,
     };
+    use tempfile::tempdir;
 
     #[test]
     fn compile_empty() {
@@ -695,6 +1052,7 @@ mod tests {
             sites: Default::default(),
             concurrency: None,
             cache: None,
+            proxy: None,
             rate_limit: None,
         })
         .unwrap();
@@ -709,7 +1067,7 @@ mod tests {
 
         assert_eq!(default.max_redirects(), DEFAULT_MAX_REDIRECTS);
         assert_eq!(default.timeout(), DEFAULT_TIMEOUT.into());
-        assert_eq!(default.cache().max_age(), Duration::default());
+        assert_eq!(default.cache().max_age(), None);
         assert_eq!(
             default.cache().stale_while_revalidate(),
             Duration::default()
@@ -734,12 +1092,15 @@ mod tests {
                     SiteConfig {
                         cache: Some(CacheConfig {
                             max_age: Some(Duration::from_secs(2045).into()),
+                            min_age: Some(Duration::from_secs(60).into()),
+                            mode: Some(CacheMode::Headers),
                             stale_while_revalidate: Some(Duration::from_secs(30).into()),
                         }),
                         concurrency: Some(42),
                         headers: Some([("user-agent".to_owned(), "my-agent".to_owned())].into()),
                         ignored_fragments: Some(vec!["L\\d+".into()]),
                         max_redirects: Some(42),
+                        method: Some(RequestMethod::Auto),
                         recurse: Some(true),
                         retry: Some(RetryConfig {
                             count: 193.into(),
@@ -752,6 +1113,7 @@ mod tests {
                             statuses: None,
                         }),
                         schemes: Some(["https".to_owned()].into()),
+                        severities: Some([("http-status".to_owned(), Severity::Warning)].into()),
                         statuses: Some([200, 403, 418].into()),
                         timeout: Some(Duration::from_secs(42).into()),
                         ..Default::default()
@@ -770,6 +1132,7 @@ mod tests {
             .into(),
             concurrency: None,
             cache: None,
+            proxy: None,
             rate_limit: None,
         })
         .unwrap();
@@ -785,7 +1148,9 @@ mod tests {
                     .set_id(id.map(Into::into))
                     .set_cache(
                         config::CacheConfig::default()
-                            .set_max_age(Duration::from_secs(2045))
+                            .set_max_age(Some(Duration::from_secs(2045)))
+                            .set_min_age(Duration::from_secs(60))
+                            .set_mode(CacheMode::Headers)
                             .set_stale_while_revalidate(Duration::from_secs(30)),
                     )
                     .set_headers(HeaderMap::from_iter([(
@@ -793,6 +1158,7 @@ mod tests {
                         HeaderValue::try_from("my-agent").unwrap(),
                     )]))
                     .set_ignored_fragments(vec![Regex::new("^(?:L\\d+)$").unwrap()])
+                    .set_severities([("http-status".to_owned(), Severity::Warning)].into())
                     .set_status(config::StatusConfig::new(
                         [
                             StatusCode::try_from(200).unwrap(),
@@ -803,6 +1169,7 @@ mod tests {
                     ))
                     .set_scheme(config::SchemeConfig::new(["https".to_owned()].into()))
                     .set_max_redirects(42)
+                    .set_method(RequestMethod::Auto)
                     .set_timeout(Duration::from_secs(42).into())
                     .set_retry(
                         config::RetryConfig::default()
@@ -855,6 +1222,7 @@ mod tests {
             .into(),
             concurrency: None,
             cache: None,
+            proxy: None,
             rate_limit: None,
         })
         .unwrap();
@@ -919,6 +1287,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn compile_severities_with_extend() {
+        let config = compile_config(SerializableConfig {
+            sites: [
+                (
+                    "base".to_owned(),
+                    SiteConfig {
+                        severities: Some([("meta-refresh".to_owned(), Severity::Error)].into()),
+                        ..Default::default()
+                    },
+                ),
+                (
+                    "foo".to_owned(),
+                    SiteConfig {
+                        extend: Some("base".to_owned()),
+                        roots: Some([Url::parse("https://foo.com/").unwrap()].into()),
+                        severities: Some([("http-status".to_owned(), Severity::Warning)].into()),
+                        ..Default::default()
+                    },
+                ),
+            ]
+            .into(),
+            ..Default::default()
+        })
+        .unwrap();
+        let site = &config.sites().get("foo.com").unwrap()[0].1;
+
+        assert_eq!(site.severity("meta-refresh"), Severity::Error);
+        assert_eq!(site.severity("http-status"), Severity::Warning);
+    }
+
     #[test]
     fn compile_ignored_default_site_config() {
         let config = compile_config(SerializableConfig {
@@ -934,6 +1333,7 @@ mod tests {
             .into(),
             concurrency: None,
             cache: None,
+            proxy: None,
             rate_limit: None,
         })
         .unwrap();
@@ -982,6 +1382,7 @@ mod tests {
             .into(),
             concurrency: None,
             cache: None,
+            proxy: None,
             rate_limit: None,
         })
         .unwrap();
@@ -1053,6 +1454,7 @@ mod tests {
             .into(),
             concurrency: None,
             cache: None,
+            proxy: None,
             rate_limit: None,
         })
         .unwrap();
@@ -1095,6 +1497,7 @@ mod tests {
             .into(),
             concurrency: None,
             cache: None,
+            proxy: None,
             rate_limit: None,
         })
         .unwrap();
@@ -1127,6 +1530,7 @@ mod tests {
             .into(),
             concurrency: None,
             cache: None,
+            proxy: None,
             rate_limit: None,
         };
 
@@ -1153,6 +1557,7 @@ mod tests {
             .into(),
             concurrency: None,
             cache: None,
+            proxy: None,
             rate_limit: None,
         };
 
@@ -1176,6 +1581,7 @@ mod tests {
             .into(),
             concurrency: None,
             cache: None,
+            proxy: None,
             rate_limit: None,
         };
 
@@ -1202,6 +1608,32 @@ mod tests {
         assert!(matches!(compile_config(config), Err(ConfigError::Regex(_))));
     }
 
+    #[test]
+    fn compile_unknown_severity_code() {
+        let config = SerializableConfig {
+            sites: [(
+                "default".to_owned(),
+                SiteConfig {
+                    severities: Some(
+                        [
+                            ("markup/unknown-tag".to_owned(), Severity::Off),
+                            ("unknown-tag".to_owned(), Severity::Off),
+                        ]
+                        .into(),
+                    ),
+                    ..Default::default()
+                },
+            )]
+            .into(),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            compile_config(config),
+            Err(ConfigError::UnknownErrorCode(code)) if code == "unknown-tag"
+        ));
+    }
+
     #[test]
     fn compile_concurrency() {
         let config = SerializableConfig {
@@ -1216,6 +1648,7 @@ mod tests {
             .into(),
             concurrency: Some(2045),
             cache: None,
+            proxy: None,
             rate_limit: None,
         };
 
@@ -1245,6 +1678,7 @@ mod tests {
             .into(),
             concurrency: Some(2045),
             cache: None,
+            proxy: None,
             rate_limit: Some(RateLimitConfig {
                 supply: 42,
                 window: Duration::from_millis(2045).into(),
@@ -1267,6 +1701,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn compile_proxy_config() {
+        let proxy = config::ProxyConfig::new()
+            .set_url(Some(Url::parse("http://proxy.com:3128/").unwrap()))
+            .set_username(Some("foo".into()))
+            .set_password(Some("bar".into()));
+        let config = compile_config(SerializableConfig {
+            extend: None,
+            sites: [
+                (
+                    "foo".to_owned(),
+                    SiteConfig {
+                        roots: Some([Url::parse("https://foo.com/").unwrap()].into()),
+                        ..Default::default()
+                    },
+                ),
+                (
+                    "bar".to_owned(),
+                    SiteConfig {
+                        roots: Some([Url::parse("https://bar.com/").unwrap()].into()),
+                        proxy: Some(ProxyConfig {
+                            url: None,
+                            username: None,
+                            password: None,
+                        }),
+                        ..Default::default()
+                    },
+                ),
+            ]
+            .into(),
+            concurrency: None,
+            cache: None,
+            proxy: Some(ProxyConfig {
+                url: Some(Url::parse("http://proxy.com:3128/").unwrap()),
+                username: Some("foo".into()),
+                password: Some("bar".into()),
+            }),
+            rate_limit: None,
+        })
+        .unwrap();
+
+        assert_eq!(
+            config
+                .site(&Url::parse("https://foo.com/").unwrap())
+                .proxy()
+                .map(AsRef::as_ref),
+            Some(&proxy)
+        );
+        assert_eq!(
+            config
+                .site(&Url::parse("https://bar.com/").unwrap())
+                .proxy()
+                .map(AsRef::as_ref),
+            Some(&config::ProxyConfig::new())
+        );
+        assert_eq!(
+            config
+                .site(&Url::parse("https://baz.com/").unwrap())
+                .proxy()
+                .map(AsRef::as_ref),
+            Some(&proxy)
+        );
+    }
+
     #[test]
     fn compile_global_cache_config() {
         let config = SerializableConfig {
@@ -1276,6 +1774,7 @@ mod tests {
             cache: Some(GlobalCacheConfig {
                 persistent: Some(true),
             }),
+            proxy: None,
             rate_limit: None,
         };
 
@@ -1307,6 +1806,7 @@ mod tests {
             .into(),
             concurrency: None,
             cache: None,
+            proxy: None,
             rate_limit: None,
         })
         .unwrap();
@@ -1358,6 +1858,7 @@ mod tests {
             .into(),
             concurrency: None,
             cache: None,
+            proxy: None,
             rate_limit: None,
         });
 
@@ -1383,6 +1884,7 @@ mod tests {
             .into(),
             concurrency: None,
             cache: None,
+            proxy: None,
             rate_limit: None,
         });
 
@@ -1400,6 +1902,7 @@ mod tests {
             .into(),
             concurrency: None,
             cache: None,
+            proxy: None,
             rate_limit: None,
         });
 
@@ -1424,6 +1927,7 @@ mod tests {
             .into(),
             concurrency: None,
             cache: None,
+            proxy: None,
             rate_limit: None,
         })
         .unwrap();
@@ -1444,6 +1948,7 @@ mod tests {
                 cache: Some(GlobalCacheConfig {
                     persistent: Some(false),
                 }),
+                proxy: None,
                 rate_limit: Some(RateLimitConfig {
                     supply: 1,
                     window: Duration::from_secs(1).into(),
@@ -1453,6 +1958,8 @@ mod tests {
                     SiteConfig {
                         cache: Some(CacheConfig {
                             max_age: Some(Duration::from_secs(5).into()),
+                            min_age: Some(Duration::from_secs(3).into()),
+                            mode: None,
                             stale_while_revalidate: Some(Duration::from_secs(7).into()),
                         }),
                         concurrency: Some(4),
@@ -1469,6 +1976,7 @@ mod tests {
                 cache: Some(GlobalCacheConfig {
                     persistent: Some(true),
                 }),
+                proxy: None,
                 rate_limit: Some(RateLimitConfig {
                     supply: 2,
                     window: Duration::from_secs(2).into(),
@@ -1479,6 +1987,8 @@ mod tests {
                         SiteConfig {
                             cache: Some(CacheConfig {
                                 max_age: None,
+                                min_age: None,
+                                mode: Some(CacheMode::Headers),
                                 stale_while_revalidate: None,
                             }),
                             concurrency: Some(8),
@@ -1535,6 +2045,17 @@ mod tests {
                     .unwrap(),
                 Duration::from_secs(7)
             );
+            assert_eq!(
+                site.cache
+                    .as_ref()
+                    .unwrap()
+                    .min_age
+                    .as_deref()
+                    .copied()
+                    .unwrap(),
+                Duration::from_secs(3)
+            );
+            assert_eq!(site.cache.as_ref().unwrap().mode, Some(CacheMode::Headers));
 
             let headers = site.headers.as_ref().unwrap();
 
@@ -1549,6 +2070,7 @@ mod tests {
                 extend: None,
                 concurrency: None,
                 cache: None,
+                proxy: None,
                 rate_limit: None,
                 sites: [(
                     "example".to_owned(),
@@ -1566,6 +2088,7 @@ mod tests {
                 extend: None,
                 concurrency: None,
                 cache: None,
+                proxy: None,
                 rate_limit: None,
                 sites: [(
                     "example".to_owned(),
@@ -1599,6 +2122,7 @@ mod tests {
                 cache: Some(GlobalCacheConfig {
                     persistent: Some(true),
                 }),
+                proxy: None,
                 rate_limit: Some(RateLimitConfig {
                     supply: 1,
                     window: Duration::from_secs(1).into(),
@@ -1607,6 +2131,7 @@ mod tests {
                     "example".to_owned(),
                     SiteConfig {
                         max_redirects: Some(5),
+                        method: Some(RequestMethod::Head),
                         retry: Some(RetryConfig {
                             count: Some(1),
                             factor: Some(1.0),
@@ -1627,6 +2152,7 @@ mod tests {
                 extend: None,
                 concurrency: None,
                 cache: Some(GlobalCacheConfig { persistent: None }),
+                proxy: None,
                 rate_limit: None,
                 sites: [(
                     "example".to_owned(),
@@ -1656,6 +2182,7 @@ mod tests {
             let site = config.sites.get("example").unwrap();
 
             assert_eq!(site.max_redirects, Some(5));
+            assert_eq!(site.method, Some(RequestMethod::Head));
             assert_eq!(
                 site.timeout.as_deref().copied().unwrap(),
                 Duration::from_secs(4)
@@ -1696,6 +2223,34 @@ mod tests {
             );
         }
 
+        #[test]
+        fn merge_severities() {
+            let mut config = SiteConfig {
+                severities: Some(
+                    [
+                        ("css".to_owned(), Severity::Warning),
+                        ("http-status".to_owned(), Severity::Off),
+                    ]
+                    .into(),
+                ),
+                ..Default::default()
+            };
+
+            config.merge(SiteConfig {
+                severities: Some([("http-status".to_owned(), Severity::Error)].into()),
+                ..Default::default()
+            });
+
+            assert_eq!(
+                config.severities.unwrap(),
+                [
+                    ("css".to_owned(), Severity::Warning),
+                    ("http-status".to_owned(), Severity::Error),
+                ]
+                .into()
+            );
+        }
+
         #[test]
         fn merge_empty_sets() {
             let mut config = SerializableConfig {
@@ -1761,21 +2316,177 @@ mod tests {
             );
         }
 
+        #[test]
+        fn compile_aria_validation() {
+            let config = compile_config(SerializableConfig {
+                sites: [(
+                    "foo".to_owned(),
+                    SiteConfig {
+                        roots: Some([Url::parse("https://foo.com/").unwrap()].into()),
+                        validation: Some(ValidationConfig {
+                            aria: Some(true),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                )]
+                .into(),
+                ..Default::default()
+            })
+            .unwrap();
+
+            assert!(
+                config.sites().get("foo.com").unwrap()[0]
+                    .1
+                    .validation()
+                    .aria()
+            );
+        }
+
+        #[test]
+        fn compile_duplicate_id_validation() {
+            let config = compile_config(SerializableConfig {
+                sites: [(
+                    "foo".to_owned(),
+                    SiteConfig {
+                        roots: Some([Url::parse("https://foo.com/").unwrap()].into()),
+                        validation: Some(ValidationConfig {
+                            duplicate_ids: Some(true),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                )]
+                .into(),
+                ..Default::default()
+            })
+            .unwrap();
+
+            assert!(
+                config.sites().get("foo.com").unwrap()[0]
+                    .1
+                    .validation()
+                    .duplicate_ids()
+            );
+        }
+
+        #[test]
+        fn compile_meta_refresh_validation() {
+            let config = compile_config(SerializableConfig {
+                sites: [(
+                    "foo".to_owned(),
+                    SiteConfig {
+                        roots: Some([Url::parse("https://foo.com/").unwrap()].into()),
+                        validation: Some(ValidationConfig {
+                            meta_refresh: Some(true),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                )]
+                .into(),
+                ..Default::default()
+            })
+            .unwrap();
+
+            assert!(
+                config.sites().get("foo.com").unwrap()[0]
+                    .1
+                    .validation()
+                    .meta_refresh()
+            );
+        }
+
+        #[test]
+        fn compile_id_reference_validation() {
+            let config = compile_config(SerializableConfig {
+                sites: [
+                    (
+                        "foo".to_owned(),
+                        SiteConfig {
+                            validation: Some(ValidationConfig {
+                                id_references: Some(IdReferenceConfig {
+                                    attributes: Some(vec!["data-target".into()]),
+                                }),
+                                ..Default::default()
+                            }),
+                            ..Default::default()
+                        },
+                    ),
+                    (
+                        "bar".to_owned(),
+                        SiteConfig {
+                            extend: Some("foo".into()),
+                            roots: Some([Url::parse("https://bar.com/").unwrap()].into()),
+                            validation: Some(ValidationConfig {
+                                id_references: Some(IdReferenceConfig::default()),
+                                ..Default::default()
+                            }),
+                            ..Default::default()
+                        },
+                    ),
+                ]
+                .into(),
+                ..Default::default()
+            })
+            .unwrap();
+
+            assert_eq!(
+                config.sites().get("bar.com").unwrap()[0]
+                    .1
+                    .validation()
+                    .id_references(),
+                Some(&config::IdReferenceConfig::new().set_attributes(vec!["data-target".into()]))
+            );
+        }
+
+        #[test]
+        fn merge_id_reference_config() {
+            let mut config = ValidationConfig {
+                id_references: Some(IdReferenceConfig {
+                    attributes: Some(vec!["data-foo".into()]),
+                }),
+                ..Default::default()
+            };
+
+            config.merge(ValidationConfig {
+                id_references: Some(IdReferenceConfig {
+                    attributes: Some(vec!["data-bar".into(), "data-foo".into()]),
+                }),
+                ..Default::default()
+            });
+
+            assert_eq!(
+                config.id_references.unwrap().attributes,
+                Some(vec!["data-bar".into(), "data-foo".into()])
+            );
+        }
+
         #[test]
         fn merge_validation_config() {
             let mut config = ValidationConfig {
                 html: Some(MarkupConfig {
+                    custom_elements: None,
                     ignored_attributes: Some(vec!["a-".into()]),
                     ignored_elements: Some(vec!["x-".into()]),
+                    ignored_syntax_errors: None,
                 }),
                 ..Default::default()
             };
 
             config.merge(ValidationConfig {
+                aria: None,
+                duplicate_ids: Some(true),
                 html: Some(MarkupConfig {
+                    custom_elements: None,
                     ignored_attributes: Some(vec!["b-".into()]),
                     ignored_elements: Some(vec!["y-".into()]),
+                    ignored_syntax_errors: Some(vec!["Unexpected token".into()]),
                 }),
+                id_references: None,
+                mathml: Some(MarkupConfig::default()),
+                meta_refresh: Some(true),
+                schemas: None,
                 svg: Some(MarkupConfig::default()),
             });
 
@@ -1799,14 +2510,126 @@ mod tests {
                     .unwrap(),
                 &vec!["x-".to_string(), "y-".to_string()]
             );
+            assert_eq!(
+                config
+                    .html
+                    .as_ref()
+                    .unwrap()
+                    .ignored_syntax_errors
+                    .as_ref()
+                    .unwrap(),
+                &vec!["Unexpected token".to_string()]
+            );
+            assert_eq!(config.duplicate_ids, Some(true));
+            assert!(config.mathml.is_some());
+            assert_eq!(config.meta_refresh, Some(true));
             assert!(config.svg.is_some());
         }
 
+        #[test]
+        fn merge_schema_configs() {
+            let mut config = ValidationConfig {
+                schemas: Some(vec![SchemaConfig {
+                    path: "foo.rnc".into(),
+                    media_type: None,
+                    url: None,
+                }]),
+                ..Default::default()
+            };
+
+            config.merge(ValidationConfig {
+                schemas: Some(vec![SchemaConfig {
+                    path: "bar.rnc".into(),
+                    media_type: None,
+                    url: None,
+                }]),
+                ..Default::default()
+            });
+
+            assert_eq!(
+                config
+                    .schemas
+                    .unwrap()
+                    .iter()
+                    .map(|schema| schema.path.clone())
+                    .collect::<Vec<_>>(),
+                vec![PathBuf::from("foo.rnc"), PathBuf::from("bar.rnc")]
+            );
+        }
+
+        #[test]
+        fn compile_schema_config() {
+            let directory = tempdir().unwrap();
+            let path = directory.path().join("foo.rnc");
+
+            write(&path, "element foo { empty }").unwrap();
+
+            let config = compile_config(SerializableConfig {
+                sites: [(
+                    "foo".to_owned(),
+                    SiteConfig {
+                        roots: Some([Url::parse("https://foo.com/").unwrap()].into()),
+                        validation: Some(ValidationConfig {
+                            schemas: Some(vec![SchemaConfig {
+                                path,
+                                media_type: Some("application/foo+xml".into()),
+                                url: Some("https://foo.com/.*\\.xml".into()),
+                            }]),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                )]
+                .into(),
+                ..Default::default()
+            })
+            .unwrap();
+            let schema = &config.sites().get("foo.com").unwrap()[0]
+                .1
+                .validation()
+                .schemas()[0];
+
+            assert_eq!(schema.media_type(), Some("application/foo+xml"));
+            assert!(schema.matches(&Url::parse("https://foo.com/bar.xml").unwrap(), None));
+            assert!(!schema.matches(&Url::parse("https://bar.com/foo.xml").unwrap(), None));
+            assert!(schema.matches(
+                &Url::parse("https://foo.com/").unwrap(),
+                Some("application/foo+xml")
+            ));
+        }
+
+        #[test]
+        fn compile_invalid_schema_config() {
+            assert!(matches!(
+                compile_config(SerializableConfig {
+                    sites: [(
+                        "foo".to_owned(),
+                        SiteConfig {
+                            validation: Some(ValidationConfig {
+                                schemas: Some(vec![SchemaConfig {
+                                    path: "/nonexistent/foo.rnc".into(),
+                                    media_type: None,
+                                    url: None,
+                                }]),
+                                ..Default::default()
+                            }),
+                            ..Default::default()
+                        },
+                    )]
+                    .into(),
+                    ..Default::default()
+                }),
+                Err(ConfigError::Schema(_))
+            ));
+        }
+
         #[test]
         fn compile_markup_config_with_regex_wrapping() {
             let config = MarkupConfig {
+                custom_elements: None,
                 ignored_attributes: Some(vec!["data-.*".into(), "align".into()]),
                 ignored_elements: Some(vec!["sl-.*".into(), "div".into()]),
+                ignored_syntax_errors: Some(vec!["Bad .*".into()]),
             };
 
             let compiled = compile_markup_config(Some(&config), None).unwrap().unwrap();
@@ -1820,6 +2643,10 @@ mod tests {
             assert_eq!(elements.len(), 2);
             assert_eq!(elements[0].as_str(), "^(?:sl-.*)$");
             assert_eq!(elements[1].as_str(), "^(?:div)$");
+
+            let errors = compiled.ignored_syntax_errors();
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[0].as_str(), "^(?:Bad .*)$");
         }
 
         #[test]
@@ -1842,8 +2669,10 @@ mod tests {
 
             // Override parent
             let config = MarkupConfig {
+                custom_elements: None,
                 ignored_attributes: Some(vec!["child-attr".into()]),
                 ignored_elements: Some(vec!["child-el".into()]),
+                ignored_syntax_errors: None,
             };
             let compiled = compile_markup_config(Some(&config), Some(&parent))
                 .unwrap()
@@ -1856,6 +2685,61 @@ mod tests {
             assert_eq!(compiled.ignored_elements()[0].as_str(), "^(?:child-el)$");
         }
 
+        #[test]
+        fn compile_custom_elements() {
+            let config = MarkupConfig {
+                custom_elements: Some(
+                    [(
+                        "foo-bar".into(),
+                        CustomElementConfig {
+                            attributes: Some(
+                                [
+                                    ("baz".into(), CustomAttributeConfig::default()),
+                                    (
+                                        "qux".into(),
+                                        CustomAttributeConfig {
+                                            values: Some(vec!["load".into(), "idle".into()]),
+                                        },
+                                    ),
+                                ]
+                                .into(),
+                            ),
+                            category: Some(ContentCategory::Flow),
+                            content: None,
+                        },
+                    )]
+                    .into(),
+                ),
+                ..Default::default()
+            };
+
+            let compiled = compile_markup_config(Some(&config), None).unwrap().unwrap();
+
+            assert_eq!(
+                compiled.custom_elements(),
+                &[(
+                    "foo-bar".into(),
+                    CustomElement::new()
+                        .set_attributes(
+                            [
+                                ("baz".into(), None),
+                                ("qux".into(), Some(["idle".into(), "load".into()].into())),
+                            ]
+                            .into()
+                        )
+                        .set_category(muffy_validation::ContentCategory::Flow)
+                )]
+                .into()
+            );
+
+            let parent = compiled;
+            let compiled = compile_markup_config(Some(&MarkupConfig::default()), Some(&parent))
+                .unwrap()
+                .unwrap();
+
+            assert_eq!(compiled.custom_elements(), parent.custom_elements());
+        }
+
         #[test]
         fn compile_retry_statuses() {
             let config = compile_config(SerializableConfig {
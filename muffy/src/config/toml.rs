@@ -33,7 +33,11 @@ pub async fn read_config(path: &Path) -> Result<SerializableConfig, ConfigError>
 }
 
 async fn read_bare_config(path: &Path) -> Result<SerializableConfig, ConfigError> {
-    Ok(toml::from_str(&read_to_string(&path).await?)?)
+    let mut config = toml::from_str::<SerializableConfig>(&read_to_string(&path).await?)?;
+
+    config.resolve_paths(path.parent().unwrap_or_else(|| Path::new(".")));
+
+    Ok(config)
 }
 
 #[cfg(test)]
@@ -133,6 +137,49 @@ mod tests {
         assert_eq!(config.concurrency().global(), Some(5));
     }
 
+    #[tokio::test]
+    async fn resolve_relative_schema_files() {
+        let directory = tempdir().unwrap();
+        let directory = directory.path();
+        let sub_directory = directory.join("nested");
+        let base_file = sub_directory.join("base.toml");
+        let child_file = directory.join("child.toml");
+
+        create_dir_all(&sub_directory).await.unwrap();
+        write(sub_directory.join("foo.rnc"), "element foo { empty }")
+            .await
+            .unwrap();
+        write(
+            &base_file,
+            indoc! {r#"
+                [sites.default]
+                roots = ["https://example.com/"]
+                [[sites.default.validation.schemas]]
+                path = "foo.rnc"
+                media_type = "application/foo+xml"
+            "#},
+        )
+        .await
+        .unwrap();
+        write(
+            &child_file,
+            indoc! {r#"
+                extend = "nested/base.toml"
+                sites = {}
+            "#},
+        )
+        .await
+        .unwrap();
+
+        let config = compile_config(read_config(&child_file).await.unwrap()).unwrap();
+        let site = config.sites().get("example.com").unwrap();
+
+        assert_eq!(
+            site[0].1.validation().schemas()[0].media_type(),
+            Some("application/foo+xml")
+        );
+    }
+
     #[tokio::test]
     async fn detect_circular_extends() {
         let directory = tempdir().unwrap();
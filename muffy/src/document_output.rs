@@ -1,4 +1,8 @@
-use crate::{Metrics, element_output::ElementOutput};
+use crate::{
+    Metrics, element::Element, element_output::ElementOutput, error::ItemError,
+    item_output::ItemOutput, severity::Severity,
+};
+use alloc::sync::Arc;
 use serde::Serialize;
 use url::Url;
 
@@ -6,6 +10,7 @@ use url::Url;
 #[derive(Debug, Serialize)]
 pub struct DocumentOutput {
     url: Url,
+    site_id: Option<Arc<str>>,
     elements: Vec<ElementOutput>,
     metrics: Metrics,
 }
@@ -15,18 +20,8 @@ impl DocumentOutput {
     pub fn new(url: Url, elements: Vec<ElementOutput>) -> Self {
         Self {
             url,
-            metrics: Metrics::new(
-                elements
-                    .iter()
-                    .flat_map(ElementOutput::results)
-                    .filter(|result| result.is_ok())
-                    .count(),
-                elements
-                    .iter()
-                    .flat_map(ElementOutput::results)
-                    .filter(|result| result.is_err())
-                    .count(),
-            ),
+            site_id: None,
+            metrics: Self::calculate_metrics(&elements),
             elements,
         }
     }
@@ -36,6 +31,11 @@ impl DocumentOutput {
         &self.url
     }
 
+    /// Returns an ID of a site the document belongs to.
+    pub const fn site_id(&self) -> Option<&Arc<str>> {
+        self.site_id.as_ref()
+    }
+
     /// Returns elements with their validation results.
     pub fn elements(&self) -> impl Iterator<Item = &ElementOutput> {
         self.elements.iter()
@@ -45,4 +45,50 @@ impl DocumentOutput {
     pub const fn metrics(&self) -> Metrics {
         self.metrics
     }
+
+    /// Sets an ID of a site the document belongs to.
+    pub fn set_site_id(mut self, id: Option<Arc<str>>) -> Self {
+        self.site_id = id;
+        self
+    }
+
+    /// Retains validation results of elements matching a predicate and drops
+    /// elements without any results.
+    pub(crate) fn retain_results(
+        &mut self,
+        mut predicate: impl FnMut(&Element, &Result<ItemOutput, ItemError>) -> bool,
+    ) {
+        for element in &mut self.elements {
+            element.retain_results(&mut predicate);
+        }
+
+        self.elements.retain(|element| !element.is_empty());
+        self.metrics = Self::calculate_metrics(&self.elements);
+    }
+
+    /// Applies severities to errors of elements and drops elements whose
+    /// errors are all turned off.
+    pub(crate) fn apply_severities(&mut self, severity: impl Fn(&ItemError) -> Severity) {
+        self.elements.retain_mut(|element| {
+            let empty = element.is_empty();
+            element.apply_severities(&severity);
+            empty || !element.is_empty()
+        });
+        self.metrics = Self::calculate_metrics(&self.elements);
+    }
+
+    fn calculate_metrics(elements: &[ElementOutput]) -> Metrics {
+        Metrics::new(
+            elements
+                .iter()
+                .flat_map(ElementOutput::results)
+                .filter(|result| result.is_ok())
+                .count(),
+            elements
+                .iter()
+                .flat_map(ElementOutput::results)
+                .filter(|result| result.is_err())
+                .count(),
+        )
+    }
 }
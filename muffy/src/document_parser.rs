@@ -11,6 +11,9 @@ use core::{
 use muffy_document::{document::Document, html, xml};
 use std::io;
 
+const XML_MEDIA_TYPES: &[&str] = &["application/xml", "text/xml"];
+const XML_MEDIA_TYPE_SUFFIX: &str = "+xml";
+
 /// A document parser.
 pub struct DocumentParser {
     cache: Box<dyn LocalCache<Result<Arc<Document>, DocumentParseError>>>,
@@ -50,11 +53,11 @@ impl DocumentParser {
     }
 
     fn is_xml(response: &Response) -> bool {
-        response
-            .media_type()
-            .ok()
-            .flatten()
-            .is_some_and(|value| value.eq_ignore_ascii_case("image/svg+xml"))
+        response.media_type().ok().flatten().is_some_and(|value| {
+            let value = value.to_ascii_lowercase();
+
+            XML_MEDIA_TYPES.contains(&value.as_str()) || value.ends_with(XML_MEDIA_TYPE_SUFFIX)
+        })
     }
 }
 
@@ -87,7 +90,7 @@ mod tests {
     use crate::MemoryCache;
     use http::StatusCode;
     use indoc::indoc;
-    use muffy_document::document::Element;
+    use muffy_document::document::{Element, ParseError};
     use pretty_assertions::assert_eq;
     use url::Url;
 
@@ -140,6 +143,7 @@ mod tests {
                 .set_namespace(Some(XHTML_NAMESPACE.into()))
                 .into()
             )])
+            .set_errors(vec![ParseError::from("Unexpected token").set_line(Some(1))])
             .into()
         );
     }
@@ -198,6 +202,35 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn parse_xml_response_with_suffixed_media_type() {
+        let parser = DocumentParser::new(MemoryCache::new(0));
+
+        assert_eq!(
+            parser
+                .parse(&Arc::new(Response::new(
+                    Url::parse("https://foo.com/feed.xml").unwrap(),
+                    StatusCode::OK,
+                    http::HeaderMap::from_iter([(
+                        http::header::CONTENT_TYPE,
+                        http::HeaderValue::from_static("application/atom+xml"),
+                    )]),
+                    r#"<feed xmlns="http://www.w3.org/2005/Atom"></feed>"#
+                        .as_bytes()
+                        .to_vec(),
+                    Default::default(),
+                )))
+                .await
+                .unwrap(),
+            Document::new(vec![Arc::new(
+                Element::new("feed".into(), vec![], vec![])
+                    .set_namespace(Some("http://www.w3.org/2005/Atom".into()))
+                    .into()
+            )])
+            .into()
+        );
+    }
+
     #[tokio::test]
     async fn parse_base() {
         let parser = DocumentParser::new(MemoryCache::new(0));
@@ -2,7 +2,9 @@
 pub enum DocumentType {
     Css,
     Html,
+    MathMl,
     Robots,
     Sitemap,
     Svg,
+    Xml,
 }
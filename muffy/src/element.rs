@@ -1,14 +1,20 @@
+use muffy_document::document::Span;
 use serde::Serialize;
 
 #[derive(Debug, Serialize)]
 pub struct Element {
     name: String,
     attributes: Vec<(String, String)>,
+    span: Option<Span>,
 }
 
 impl Element {
     pub const fn new(name: String, attributes: Vec<(String, String)>) -> Self {
-        Self { name, attributes }
+        Self {
+            name,
+            attributes,
+            span: None,
+        }
     }
 
     #[allow(clippy::missing_const_for_fn)]
@@ -20,4 +26,13 @@ impl Element {
     pub fn attributes(&self) -> &[(String, String)] {
         &self.attributes
     }
+
+    pub const fn span(&self) -> Option<Span> {
+        self.span
+    }
+
+    pub const fn set_span(mut self, span: Option<Span>) -> Self {
+        self.span = span;
+        self
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{element::Element, error::ItemError, item_output::ItemOutput};
+use crate::{element::Element, error::ItemError, item_output::ItemOutput, severity::Severity};
 use serde::Serialize;
 
 /// An element output.
@@ -6,11 +6,16 @@ use serde::Serialize;
 pub struct ElementOutput {
     element: Element,
     results: Vec<Result<ItemOutput, ItemError>>,
+    warnings: Vec<ItemError>,
 }
 
 impl ElementOutput {
     pub const fn new(element: Element, results: Vec<Result<ItemOutput, ItemError>>) -> Self {
-        Self { element, results }
+        Self {
+            element,
+            results,
+            warnings: vec![],
+        }
     }
 
     /// Returns an element.
@@ -22,4 +27,37 @@ impl ElementOutput {
     pub fn results(&self) -> impl ExactSizeIterator<Item = &Result<ItemOutput, ItemError>> {
         self.results.iter()
     }
+
+    /// Returns errors reported as warnings.
+    pub fn warnings(&self) -> impl ExactSizeIterator<Item = &ItemError> {
+        self.warnings.iter()
+    }
+
+    /// Returns `true` if the element has neither results nor warnings.
+    pub const fn is_empty(&self) -> bool {
+        self.results.is_empty() && self.warnings.is_empty()
+    }
+
+    pub(crate) fn retain_results(
+        &mut self,
+        mut predicate: impl FnMut(&Element, &Result<ItemOutput, ItemError>) -> bool,
+    ) {
+        self.results
+            .retain(|result| predicate(&self.element, result));
+    }
+
+    /// Drops errors turned off and moves errors of a warning severity to
+    /// warnings.
+    pub(crate) fn apply_severities(&mut self, severity: impl Fn(&ItemError) -> Severity) {
+        for result in core::mem::take(&mut self.results) {
+            match result {
+                Err(error) => match severity(&error) {
+                    Severity::Error => self.results.push(Err(error)),
+                    Severity::Warning => self.warnings.push(error),
+                    Severity::Off => {}
+                },
+                result => self.results.push(result),
+            }
+        }
+    }
 }
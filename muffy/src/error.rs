@@ -1,6 +1,6 @@
 use crate::{
     cache::CacheError, document_parser::DocumentParseError, http_client::HttpClientError,
-    sitemap::SitemapError,
+    render::RenderFormat, sitemap::SitemapError, srcset::SrcsetError,
 };
 use core::{
     error,
@@ -11,7 +11,7 @@ use data_url::{DataUrlError, forgiving_base64::InvalidBase64};
 use http::StatusCode;
 use muffy_css::CssError;
 use muffy_validation::MarkupError;
-use serde::{Serialize, Serializer};
+use serde::{Serialize, Serializer, ser::SerializeMap};
 use std::io;
 use tokio::{sync::AcquireError, task::JoinError};
 use url::ParseError;
@@ -21,6 +21,8 @@ use url::ParseError;
 pub enum Error {
     /// Semaphore acquirement failure.
     Acquire(AcquireError),
+    /// An aggregated format for a single document.
+    AggregatedFormat(RenderFormat),
     /// A cache error.
     Cache(CacheError),
     /// A document parse error.
@@ -47,6 +49,12 @@ impl Display for Error {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
         match self {
             Self::Acquire(error) => write!(formatter, "{error}"),
+            Self::AggregatedFormat(format) => {
+                write!(
+                    formatter,
+                    "unsupported aggregated format for single document: {format:?}"
+                )
+            }
             Self::Cache(error) => write!(formatter, "{error}"),
             Self::DocumentParse(error) => write!(formatter, "{error}"),
             Self::Io(error) => write!(formatter, "{error}"),
@@ -134,8 +142,12 @@ pub enum ItemError {
     DataUrl(DataUrlError),
     /// A document parse error.
     DocumentParse(DocumentParseError),
+    /// A duplicate ID.
+    DuplicateId(String),
     /// An element not found.
     ElementNotFound(String),
+    /// An HTML syntax error.
+    HtmlSyntax(String),
     /// An HTTP client error.
     HttpClient(HttpClientError),
     /// An error status code in an HTTP response.
@@ -158,8 +170,16 @@ pub enum ItemError {
     InvalidScheme(String),
     /// A markup error.
     Markup(MarkupError),
+    /// A redirect by a `<meta http-equiv="refresh">` element.
+    MetaRefresh,
     /// A sitemap parse error.
     Sitemap(SitemapError),
+    /// An invalid `srcset` or `sizes` attribute.
+    Srcset(SrcsetError),
+    /// An undefined ID referred to by an attribute.
+    UndefinedId(String),
+    /// An unknown kind of errors in an inline suppression.
+    UnknownSuppressionKind(String),
     /// A URL parse error.
     UrlParse(ParseError),
     /// A UTF-8 error.
@@ -168,6 +188,67 @@ pub enum ItemError {
     XmlSyntax(String),
 }
 
+impl ItemError {
+    /// Returns codes of all error kinds.
+    pub fn codes() -> impl Iterator<Item = &'static str> {
+        [
+            "base64",
+            "content-type-invalid",
+            "css",
+            "css-syntax",
+            "data-url",
+            "document-parse",
+            "duplicate-id",
+            "element-not-found",
+            "html-syntax",
+            "http-client",
+            "http-status",
+            "invalid-namespace",
+            "invalid-root-element",
+            "invalid-scheme",
+            "meta-refresh",
+            "sitemap",
+            "undefined-id",
+            "unknown-suppression-kind",
+            "url-parse",
+            "utf8",
+            "xml-syntax",
+        ]
+        .into_iter()
+        .chain(MarkupError::CODES.iter().copied())
+        .chain(SrcsetError::CODES.iter().copied())
+    }
+
+    /// Returns a stable code of an error kind.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Base64(_) => "base64",
+            Self::ContentTypeInvalid { .. } => "content-type-invalid",
+            Self::Css(_) => "css",
+            Self::CssSyntax(_) => "css-syntax",
+            Self::DataUrl(_) => "data-url",
+            Self::DocumentParse(_) => "document-parse",
+            Self::DuplicateId(_) => "duplicate-id",
+            Self::ElementNotFound(_) => "element-not-found",
+            Self::HtmlSyntax(_) => "html-syntax",
+            Self::HttpClient(_) => "http-client",
+            Self::HttpStatus(_) => "http-status",
+            Self::InvalidNamespace { .. } => "invalid-namespace",
+            Self::InvalidRootElement { .. } => "invalid-root-element",
+            Self::InvalidScheme(_) => "invalid-scheme",
+            Self::Markup(error) => error.code(),
+            Self::MetaRefresh => "meta-refresh",
+            Self::Sitemap(_) => "sitemap",
+            Self::Srcset(error) => error.code(),
+            Self::UndefinedId(_) => "undefined-id",
+            Self::UnknownSuppressionKind(_) => "unknown-suppression-kind",
+            Self::UrlParse(_) => "url-parse",
+            Self::Utf8(_) => "utf8",
+            Self::XmlSyntax(_) => "xml-syntax",
+        }
+    }
+}
+
 impl error::Error for ItemError {}
 
 impl Display for ItemError {
@@ -184,9 +265,11 @@ impl Display for ItemError {
             Self::CssSyntax(message) => write!(formatter, "invalid CSS: {message}"),
             Self::DataUrl(error) => write!(formatter, "{error}"),
             Self::DocumentParse(error) => write!(formatter, "{error}"),
+            Self::DuplicateId(id) => write!(formatter, "duplicate ID \"{id}\""),
             Self::ElementNotFound(name) => {
                 write!(formatter, "element for #{name} not found")
             }
+            Self::HtmlSyntax(message) => write!(formatter, "invalid HTML: {message}"),
             Self::HttpClient(error) => write!(formatter, "{error}"),
             Self::HttpStatus(status) => write!(formatter, "invalid status {status}"),
             Self::InvalidNamespace { actual, expected } => {
@@ -204,7 +287,13 @@ impl Display for ItemError {
             }
             Self::InvalidScheme(scheme) => write!(formatter, "invalid scheme \"{scheme}\""),
             Self::Markup(error) => write!(formatter, "{error}"),
+            Self::MetaRefresh => write!(formatter, "meta refresh"),
             Self::Sitemap(error) => write!(formatter, "{error}"),
+            Self::Srcset(error) => write!(formatter, "{error}"),
+            Self::UndefinedId(id) => write!(formatter, "undefined ID \"{id}\""),
+            Self::UnknownSuppressionKind(kind) => {
+                write!(formatter, "unknown suppression kind \"{kind}\"")
+            }
             Self::UrlParse(error) => write!(formatter, "{error}"),
             Self::Utf8(error) => write!(formatter, "{error}"),
             Self::XmlSyntax(message) => write!(formatter, "invalid XML: {message}"),
@@ -214,7 +303,82 @@ impl Display for ItemError {
 
 impl Serialize for ItemError {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        serializer.serialize_str(&self.to_string())
+        let mut map = serializer.serialize_map(None)?;
+
+        map.serialize_entry("code", self.code())?;
+        map.serialize_entry("message", &self.to_string())?;
+
+        match self {
+            Self::ContentTypeInvalid { actual, expected } => {
+                map.serialize_entry("actual", actual)?;
+                map.serialize_entry("expected", expected)?;
+            }
+            Self::InvalidNamespace { actual, expected } => {
+                map.serialize_entry("actual", actual)?;
+                map.serialize_entry("expected", expected)?;
+            }
+            Self::InvalidRootElement { actual, expected } => {
+                map.serialize_entry("actual", actual)?;
+                map.serialize_entry("expected", expected)?;
+            }
+            Self::DuplicateId(id) | Self::UndefinedId(id) => map.serialize_entry("id", id)?,
+            Self::ElementNotFound(fragment) => map.serialize_entry("fragment", fragment)?,
+            Self::HttpStatus(status) => map.serialize_entry("status", &status.as_u16())?,
+            Self::InvalidScheme(scheme) => map.serialize_entry("scheme", scheme)?,
+            Self::UnknownSuppressionKind(kind) => map.serialize_entry("kind", kind)?,
+            Self::Srcset(error) => map.serialize_entry("attribute", error.attribute())?,
+            Self::Markup(MarkupError::UnknownTag(tag)) => map.serialize_entry("tag", tag)?,
+            Self::Markup(MarkupError::ExcludedElement(ancestor)) => {
+                map.serialize_entry("ancestor", ancestor)?
+            }
+            Self::Markup(MarkupError::InvalidElement {
+                invalid_attributes,
+                invalid_children,
+                missing_attributes,
+                missing_children,
+            }) => {
+                let attributes = invalid_attributes
+                    .keys()
+                    .chain(missing_attributes)
+                    .collect::<Vec<_>>();
+                let children = invalid_children
+                    .keys()
+                    .chain(missing_children)
+                    .collect::<Vec<_>>();
+
+                if !attributes.is_empty() {
+                    map.serialize_entry("attributes", &attributes)?;
+                }
+
+                if !children.is_empty() {
+                    map.serialize_entry("children", &children)?;
+                }
+            }
+            Self::Markup(MarkupError::InvalidAria {
+                invalid_attributes,
+                missing_attributes,
+            }) => map.serialize_entry(
+                "attributes",
+                &invalid_attributes
+                    .keys()
+                    .chain(missing_attributes)
+                    .collect::<Vec<_>>(),
+            )?,
+            Self::Base64(_)
+            | Self::Css(_)
+            | Self::CssSyntax(_)
+            | Self::DataUrl(_)
+            | Self::DocumentParse(_)
+            | Self::HtmlSyntax(_)
+            | Self::HttpClient(_)
+            | Self::MetaRefresh
+            | Self::Sitemap(_)
+            | Self::UrlParse(_)
+            | Self::Utf8(_)
+            | Self::XmlSyntax(_) => {}
+        }
+
+        map.end()
     }
 }
 
@@ -334,6 +498,208 @@ mod tests {
         );
     }
 
+    #[test]
+    fn code_item_error() {
+        assert_eq!(
+            ItemError::HttpStatus(StatusCode::NOT_FOUND).code(),
+            "http-status"
+        );
+        assert_eq!(
+            ItemError::InvalidRootElement {
+                actual: "circle".into(),
+                expected: "svg",
+            }
+            .code(),
+            "invalid-root-element"
+        );
+        assert_eq!(
+            ItemError::Markup(MarkupError::UnknownTag("foo".into())).code(),
+            "markup/unknown-tag"
+        );
+    }
+
+    #[test]
+    fn include_codes() {
+        for error in [
+            ItemError::CssSyntax("foo".into()),
+            ItemError::HttpStatus(StatusCode::NOT_FOUND),
+            ItemError::Markup(MarkupError::UnknownTag("foo".into())),
+            ItemError::MetaRefresh,
+            ItemError::Srcset(SrcsetError::MissingSizes),
+        ] {
+            assert!(
+                ItemError::codes().any(|code| code == error.code()),
+                "{error}"
+            );
+        }
+    }
+
+    #[test]
+    fn include_codes_of_all_kinds() {
+        let bytes = vec![0xff];
+        let utf8_error = str::from_utf8(&bytes).unwrap_err();
+
+        for error in [
+            ItemError::Base64(
+                DataUrl::process("data:;base64,a")
+                    .unwrap()
+                    .decode_to_vec()
+                    .err()
+                    .unwrap(),
+            ),
+            ItemError::ContentTypeInvalid {
+                actual: "text/plain".into(),
+                expected: "text/html",
+            },
+            ItemError::Css(CssError::Poison),
+            ItemError::CssSyntax("foo".into()),
+            ItemError::DataUrl(DataUrlError::NoComma),
+            ItemError::DocumentParse(DocumentParseError::Io(io::Error::other("foo").into())),
+            ItemError::DuplicateId("foo".into()),
+            ItemError::ElementNotFound("foo".into()),
+            ItemError::HtmlSyntax("foo".into()),
+            ItemError::HttpClient(HttpClientError::RobotsTxt),
+            ItemError::HttpStatus(StatusCode::NOT_FOUND),
+            ItemError::InvalidNamespace {
+                actual: None,
+                expected: "http://www.w3.org/2000/svg",
+            },
+            ItemError::InvalidRootElement {
+                actual: "circle".into(),
+                expected: "svg",
+            },
+            ItemError::InvalidScheme("foo".into()),
+            ItemError::Markup(MarkupError::UnknownTag("foo".into())),
+            ItemError::MetaRefresh,
+            ItemError::Sitemap(SitemapError::Utf8(utf8_error)),
+            ItemError::Srcset(SrcsetError::MissingSizes),
+            ItemError::UndefinedId("foo".into()),
+            ItemError::UnknownSuppressionKind("foo".into()),
+            ItemError::UrlParse(ParseError::EmptyHost),
+            ItemError::Utf8(utf8_error),
+            ItemError::XmlSyntax("foo".into()),
+        ] {
+            // Update the errors above on addition of error kinds.
+            match error {
+                ItemError::Base64(_)
+                | ItemError::ContentTypeInvalid { .. }
+                | ItemError::Css(_)
+                | ItemError::CssSyntax(_)
+                | ItemError::DataUrl(_)
+                | ItemError::DocumentParse(_)
+                | ItemError::DuplicateId(_)
+                | ItemError::ElementNotFound(_)
+                | ItemError::HtmlSyntax(_)
+                | ItemError::HttpClient(_)
+                | ItemError::HttpStatus(_)
+                | ItemError::InvalidNamespace { .. }
+                | ItemError::InvalidRootElement { .. }
+                | ItemError::InvalidScheme(_)
+                | ItemError::Markup(_)
+                | ItemError::MetaRefresh
+                | ItemError::Sitemap(_)
+                | ItemError::Srcset(_)
+                | ItemError::UndefinedId(_)
+                | ItemError::UnknownSuppressionKind(_)
+                | ItemError::UrlParse(_)
+                | ItemError::Utf8(_)
+                | ItemError::XmlSyntax(_) => {}
+            }
+
+            assert!(
+                ItemError::codes().any(|code| code == error.code()),
+                "{error}"
+            );
+        }
+    }
+
+    #[test]
+    fn serialize_http_status_error() {
+        assert_eq!(
+            serde_json::to_value(ItemError::HttpStatus(StatusCode::NOT_FOUND)).unwrap(),
+            serde_json::json!({
+                "code": "http-status",
+                "message": "invalid status 404 Not Found",
+                "status": 404,
+            })
+        );
+    }
+
+    #[test]
+    fn serialize_content_type_error() {
+        assert_eq!(
+            serde_json::to_value(ItemError::ContentTypeInvalid {
+                actual: "text/plain".into(),
+                expected: "text/html",
+            })
+            .unwrap(),
+            serde_json::json!({
+                "code": "content-type-invalid",
+                "message": "content type expected text/html but got text/plain",
+                "actual": "text/plain",
+                "expected": "text/html",
+            })
+        );
+    }
+
+    #[test]
+    fn serialize_element_not_found_error() {
+        assert_eq!(
+            serde_json::to_value(ItemError::ElementNotFound("foo".into())).unwrap(),
+            serde_json::json!({
+                "code": "element-not-found",
+                "message": "element for #foo not found",
+                "fragment": "foo",
+            })
+        );
+    }
+
+    #[test]
+    fn serialize_srcset_error() {
+        assert_eq!(
+            serde_json::to_value(ItemError::Srcset(SrcsetError::InvalidSizes)).unwrap(),
+            serde_json::json!({
+                "code": "srcset/invalid-sizes",
+                "message": "invalid source sizes",
+                "attribute": "sizes",
+            })
+        );
+    }
+
+    #[test]
+    fn serialize_undefined_id_error() {
+        assert_eq!(
+            serde_json::to_value(ItemError::UndefinedId("foo".into())).unwrap(),
+            serde_json::json!({
+                "code": "undefined-id",
+                "message": "undefined ID \"foo\"",
+                "id": "foo",
+            })
+        );
+    }
+
+    #[test]
+    fn serialize_markup_error() {
+        assert_eq!(
+            serde_json::to_value(ItemError::Markup(MarkupError::InvalidElement {
+                invalid_attributes: [(
+                    "foo".into(),
+                    [muffy_validation::AttributeError::InvalidValue].into()
+                )]
+                .into(),
+                invalid_children: Default::default(),
+                missing_attributes: Default::default(),
+                missing_children: Default::default(),
+            }))
+            .unwrap(),
+            serde_json::json!({
+                "code": "markup/invalid-attribute-value",
+                "message": "invalid attributes: foo (invalid value)",
+                "attributes": ["foo"],
+            })
+        );
+    }
+
     #[test]
     fn display_item_xml_syntax_error() {
         assert_eq!(
@@ -344,4 +710,12 @@ mod tests {
             "invalid XML: Unexpected element in end phase"
         );
     }
+
+    #[test]
+    fn display_item_html_syntax_error() {
+        assert_eq!(
+            format!("{}", ItemError::HtmlSyntax("Duplicate attribute".into())),
+            "invalid HTML: Duplicate attribute"
+        );
+    }
 }
@@ -1,5 +1,6 @@
 mod bare;
 mod cached_response;
+mod directory;
 mod error;
 mod reqwest;
 #[cfg(test)]
@@ -9,11 +10,12 @@ mod stub;
 pub use self::stub::{StubHttpClient, StubSequenceHttpClient, build_stub_response};
 pub use self::{
     bare::{BareHttpClient, BareRequest, BareResponse},
+    directory::DirectoryHttpClient,
     error::HttpClientError,
     reqwest::ReqwestHttpClient,
 };
 use crate::{
-    ConcurrencyConfig, MokaCache, RateLimitConfig,
+    CacheMode, ConcurrencyConfig, MokaCache, RateLimitConfig,
     cache::{GlobalCache, LocalCache},
     default_concurrency,
     rate_limiter::RateLimiter,
@@ -26,7 +28,7 @@ use alloc::sync::Arc;
 use async_recursion::async_recursion;
 use cached_response::CachedResponse;
 use core::{str, time::Duration};
-use http::StatusCode;
+use http::{Method, StatusCode};
 use std::collections::HashMap;
 use tokio::{
     sync::Semaphore,
@@ -35,6 +37,8 @@ use tokio::{
 
 pub(crate) const ROBOTS_PATH: &str = "/robots.txt";
 const INITIAL_CACHE_CAPACITY: usize = 1 << 8;
+// A maximum delay by `Retry-After` headers for retries without interval caps.
+const MAX_RETRY_AFTER: Duration = Duration::from_mins(1);
 
 /// A full-featured HTTP client.
 pub struct HttpClient {
@@ -144,7 +148,7 @@ impl HttpClient {
     ) -> Result<Arc<Response>, HttpClientError> {
         self.local_cache
             .get_with(
-                request.url().to_string(),
+                request.cache_key(),
                 Box::new(self.get_cached_globally(request, robots)),
             )
             .await?
@@ -155,17 +159,25 @@ impl HttpClient {
         request: &Request,
         robots: bool,
     ) -> Result<Arc<Response>, HttpClientError> {
-        let get = || async {
-            let result = self.get_filtered(request, robots).await;
+        let get = |cached| async move {
+            let result = self.get_filtered(request, robots, cached).await;
 
-            self.global_cache
-                .set(request.url().to_string(), result.clone())
-                .await?;
+            if request.cache_mode() == CacheMode::Headers
+                && let Ok(response) = &result
+                && !response.response().is_storable()
+            {
+                self.global_cache.remove(&request.cache_key()).await?;
+            } else {
+                self.global_cache
+                    .set(request.cache_key(), result.clone())
+                    .await?;
+            }
 
             result
         };
 
-        let result = self.global_cache.get(request.url().as_str()).await?;
+        let key = request.cache_key();
+        let result = self.global_cache.get(&key).await?;
         let result = if let Some(result) = &result
             && match &result {
                 Ok(response) => request
@@ -174,7 +186,7 @@ impl HttpClient {
                     .contains(&response.response().status()),
                 Err(_) => true,
             } {
-            self.global_cache.remove(request.url().as_str()).await?;
+            self.global_cache.remove(&key).await?;
 
             None
         } else {
@@ -182,19 +194,15 @@ impl HttpClient {
         };
 
         Ok(if let Some(Ok(response)) = result {
-            if response.is_expired(request.max_age()) {
-                let expired = response.is_expired(
-                    request
-                        .max_age()
-                        .saturating_add(request.stale_while_revalidate()),
-                );
+            let (max_age, stale_while_revalidate) = freshness(request, &response);
 
-                let result = get().await;
+            if response.is_expired(max_age) {
+                let expired = response.is_expired(max_age.saturating_add(stale_while_revalidate));
+
+                let result = get(Some(&response)).await;
 
                 if !expired && result.is_err() {
-                    self.global_cache
-                        .set(request.url().to_string(), Ok(response.clone()))
-                        .await?;
+                    self.global_cache.set(key, Ok(response.clone())).await?;
                 }
 
                 if expired { result? } else { response }
@@ -202,7 +210,7 @@ impl HttpClient {
                 response
             }
         } else {
-            get().await?
+            get(None).await?
         }
         .response()
         .clone())
@@ -212,12 +220,23 @@ impl HttpClient {
         &self,
         request: &Request,
         robots: bool,
+        cached: Option<&CachedResponse>,
     ) -> Result<Arc<CachedResponse>, HttpClientError> {
         if robots
             && let Some(robot) = self.get_robot(request).await?
             && !robot.is_allowed(request.url().path())
         {
             Err(HttpClientError::RobotsTxt)
+        } else if let Some(cached) = cached {
+            let response = self
+                .get_retried(&request.clone().revalidate(cached.response()))
+                .await?;
+
+            Ok(Arc::new(if response.status() == StatusCode::NOT_MODIFIED {
+                cached.refresh(response.headers())
+            } else {
+                response.into()
+            }))
         } else {
             Ok(Arc::new(self.get_retried(request).await?.into()))
         }
@@ -229,13 +248,16 @@ impl HttpClient {
         let mut backoff = retry.interval().initial();
 
         for _ in 0..retry.count() {
-            if let Ok(response) = &result
-                && !retry.statuses().contains(&response.status())
-            {
-                break;
-            }
+            let delay = match &result {
+                Ok(response) if !retry.statuses().contains(&response.status()) => break,
+                Ok(response) => response
+                    .retry_after()
+                    .map(|delay| delay.min(retry.interval().cap().unwrap_or(MAX_RETRY_AFTER)))
+                    .unwrap_or(backoff),
+                Err(_) => backoff,
+            };
 
-            sleep(backoff).await;
+            sleep(delay).await;
 
             backoff = backoff
                 .mul_f64(retry.factor())
@@ -269,7 +291,19 @@ impl HttpClient {
         if let Some(id) = request.site_id()
             && let Some(limiter) = &self.site_rate_limiters.get(id)
         {
-            limiter.run(future).await
+            let result = limiter.run(future).await;
+
+            // Back off from a site rejecting requests for their rate or asking
+            // clients to retry later while it is overloaded.
+            if let Ok(response) = &result
+                && (response.status() == StatusCode::TOO_MANY_REQUESTS
+                    || response.status() == StatusCode::SERVICE_UNAVAILABLE
+                        && response.retry_after().is_some())
+            {
+                limiter.throttle();
+            }
+
+            result
         } else {
             future.await
         }
@@ -277,7 +311,11 @@ impl HttpClient {
 
     async fn get_once(&self, request: &Request) -> Result<Response, HttpClientError> {
         let start = self.timer.now();
-        let response = timeout(request.timeout(), self.client.get(request.as_bare())).await??;
+        let response = if request.method() == Method::HEAD {
+            timeout(request.timeout(), self.client.head(request.as_bare())).await??
+        } else {
+            timeout(request.timeout(), self.client.get(request.as_bare())).await??
+        };
         let duration = self.timer.now().duration_since(start);
 
         Ok(Response::from_bare(response, duration))
@@ -287,7 +325,10 @@ impl HttpClient {
     async fn get_robot(&self, request: &Request) -> Result<Option<RobotList>, HttpClientError> {
         let response = self
             .get_inner(
-                &request.clone().set_url(request.url().join(ROBOTS_PATH)?),
+                &request
+                    .clone()
+                    .set_method(Method::GET)
+                    .set_url(request.url().join(ROBOTS_PATH)?),
                 false,
             )
             .await?;
@@ -300,11 +341,36 @@ impl HttpClient {
     }
 }
 
+// Returns a maximum age and a stale-while-revalidate period of a cached response.
+fn freshness(request: &Request, cached: &CachedResponse) -> (Duration, Duration) {
+    match request.cache_mode() {
+        CacheMode::Fixed => (
+            request.max_age().unwrap_or_default(),
+            request.stale_while_revalidate(),
+        ),
+        CacheMode::Headers => {
+            let response = cached.response();
+
+            (
+                match response.freshness_lifetime(cached.timestamp()) {
+                    // Zero lifetimes require revalidation explicitly.
+                    Some(Duration::ZERO) => Duration::ZERO,
+                    lifetime => lifetime.unwrap_or_default().max(request.min_age()),
+                }
+                .min(request.max_age().unwrap_or(Duration::MAX)),
+                response
+                    .stale_while_revalidate()
+                    .unwrap_or(request.stale_while_revalidate()),
+            )
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
-        ConcurrencyConfig, RetryConfig,
+        ConcurrencyConfig, RateLimitConfig, RetryConfig, RetryDurationConfig, SiteRateLimitConfig,
         cache::MemoryCache,
         http_client::{BareResponse, StubHttpClient, StubSequenceHttpClient, build_stub_response},
         timer::StubTimer,
@@ -315,10 +381,16 @@ mod tests {
         sync::atomic::{AtomicUsize, Ordering},
         time::Duration,
     };
-    use http::{HeaderName, HeaderValue, StatusCode, header::AUTHORIZATION};
+    use http::{
+        HeaderMap, HeaderName, HeaderValue, StatusCode,
+        header::{
+            AUTHORIZATION, CACHE_CONTROL, CONTENT_LENGTH, CONTENT_TYPE, ETAG, IF_NONE_MATCH,
+            LAST_MODIFIED, RETRY_AFTER,
+        },
+    };
     use pretty_assertions::assert_eq;
     use std::{collections::HashSet, sync::Mutex};
-    use tokio::spawn;
+    use tokio::{spawn, time::Instant};
     use url::Url;
 
     const CACHE_CAPACITY: usize = 1 << 16;
@@ -712,15 +784,596 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn get_cache() {
+    async fn get_cache() {
+        let url = Url::parse("https://foo.com").unwrap();
+        let response = BareResponse {
+            url: url.clone(),
+            status: StatusCode::OK,
+            headers: Default::default(),
+            body: vec![],
+        };
+
+        let cache = MemoryCache::new(CACHE_CAPACITY);
+
+        cache
+            .set(
+                url.as_str().into(),
+                Ok(Arc::new(
+                    Response::from_bare(
+                        BareResponse {
+                            body: b"stale".to_vec(),
+                            ..response.clone()
+                        },
+                        Duration::default(),
+                    )
+                    .into(),
+                )),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            HttpClient::new(
+                StubHttpClient::new(
+                    [
+                        build_stub_response(
+                            url.join("/robots.txt").unwrap().as_str(),
+                            StatusCode::OK,
+                            Default::default(),
+                            vec![],
+                        ),
+                        (url.as_str().into(), Ok(response.clone()))
+                    ]
+                    .into_iter()
+                    .collect()
+                ),
+                StubTimer::new(),
+                Box::new(cache),
+            )
+            .get(&Request::new(url, Default::default()).set_max_age(Some(CACHE_MAX_AGE)))
+            .await
+            .unwrap(),
+            Some(
+                Response::from_bare(
+                    BareResponse {
+                        body: b"stale".to_vec(),
+                        ..response
+                    },
+                    Duration::from_millis(0)
+                )
+                .into()
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn cache_responses_by_method() {
+        let url = Url::parse("https://foo.com").unwrap();
+        let response = BareResponse {
+            url: url.clone(),
+            status: StatusCode::OK,
+            headers: Default::default(),
+            body: vec![],
+        };
+
+        let cache = MemoryCache::new(CACHE_CAPACITY);
+
+        cache
+            .set(
+                url.as_str().into(),
+                Ok(Arc::new(
+                    Response::from_bare(
+                        BareResponse {
+                            body: b"cached".to_vec(),
+                            ..response.clone()
+                        },
+                        Duration::default(),
+                    )
+                    .into(),
+                )),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            HttpClient::new(
+                StubHttpClient::new(
+                    [build_stub_response(
+                        url.join("/robots.txt").unwrap().as_str(),
+                        StatusCode::OK,
+                        Default::default(),
+                        vec![],
+                    )]
+                    .into_iter()
+                    .collect()
+                )
+                .set_head_results([(url.as_str().into(), Ok(response.clone()))].into()),
+                StubTimer::new(),
+                Box::new(cache),
+            )
+            .get(
+                &Request::new(url, Default::default())
+                    .set_method(Method::HEAD)
+                    .set_max_age(Some(CACHE_MAX_AGE))
+            )
+            .await
+            .unwrap(),
+            Some(Response::from_bare(response, Duration::from_millis(0)).into())
+        );
+    }
+
+    #[tokio::test]
+    async fn update_cache() {
+        let url = Url::parse("https://foo.com").unwrap();
+        let response = BareResponse {
+            url: url.clone(),
+            status: StatusCode::OK,
+            headers: Default::default(),
+            body: vec![],
+        };
+
+        let cache = MemoryCache::new(CACHE_CAPACITY);
+
+        cache
+            .set(
+                url.as_str().into(),
+                Ok(Arc::new(
+                    Response::from_bare(
+                        BareResponse {
+                            body: b"stale".to_vec(),
+                            ..response.clone()
+                        },
+                        Duration::default(),
+                    )
+                    .into(),
+                )),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            HttpClient::new(
+                StubHttpClient::new(
+                    [
+                        build_stub_response(
+                            url.join("/robots.txt").unwrap().as_str(),
+                            StatusCode::OK,
+                            Default::default(),
+                            vec![],
+                        ),
+                        (url.as_str().into(), Ok(response.clone()))
+                    ]
+                    .into_iter()
+                    .collect()
+                ),
+                StubTimer::new(),
+                Box::new(cache),
+            )
+            .get(&Request::new(url, Default::default()))
+            .await
+            .unwrap(),
+            Some(Response::from_bare(response, Duration::from_millis(0)).into())
+        );
+    }
+
+    #[tokio::test]
+    async fn persist_response() {
+        let url = Url::parse("https://foo.com").unwrap();
+        let response = BareResponse {
+            url: url.clone(),
+            status: StatusCode::OK,
+            headers: Default::default(),
+            body: vec![],
+        };
+        let cache = Arc::new(MemoryCache::new(CACHE_CAPACITY));
+
+        HttpClient::new(
+            StubHttpClient::new(
+                [
+                    build_stub_response(
+                        url.join("/robots.txt").unwrap().as_str(),
+                        StatusCode::OK,
+                        Default::default(),
+                        vec![],
+                    ),
+                    (url.as_str().into(), Ok(response.clone())),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            StubTimer::new(),
+            Box::new(cache.clone()),
+        )
+        .get(&Request::new(url.clone(), Default::default()))
+        .await
+        .unwrap();
+
+        assert_eq!(
+            cache
+                .get(url.as_str())
+                .await
+                .unwrap()
+                .unwrap()
+                .unwrap()
+                .response()
+                .clone(),
+            Response::from_bare(response, Duration::from_millis(0)).into()
+        );
+    }
+
+    #[tokio::test]
+    async fn do_not_persist_error() {
+        let url = Url::parse("https://foo.com").unwrap();
+        let response = BareResponse {
+            url: url.clone(),
+            status: StatusCode::OK,
+            headers: Default::default(),
+            body: vec![],
+        };
+        let robots = build_stub_response(
+            url.join("/robots.txt").unwrap().as_str(),
+            StatusCode::OK,
+            Default::default(),
+            vec![],
+        );
+        let cache = Arc::new(MemoryCache::new(CACHE_CAPACITY));
+
+        assert!(
+            HttpClient::new(
+                StubHttpClient::new(
+                    [
+                        robots.clone(),
+                        (
+                            url.as_str().into(),
+                            Err(HttpClientError::Http("boom".into())),
+                        ),
+                    ]
+                    .into_iter()
+                    .collect(),
+                ),
+                StubTimer::new(),
+                Box::new(cache.clone()),
+            )
+            .get(&Request::new(url.clone(), Default::default()))
+            .await
+            .is_err()
+        );
+
+        assert_eq!(
+            HttpClient::new(
+                StubHttpClient::new(
+                    [robots, (url.as_str().into(), Ok(response.clone()))]
+                        .into_iter()
+                        .collect(),
+                ),
+                StubTimer::new(),
+                Box::new(cache),
+            )
+            .get(&Request::new(url, Default::default()))
+            .await
+            .unwrap(),
+            Some(Response::from_bare(response, Duration::from_millis(0)).into())
+        );
+    }
+
+    #[tokio::test]
+    async fn revalidate_cached_response() {
+        let url = Url::parse("https://foo.com").unwrap();
+        let cached_response = Response::from_bare(
+            BareResponse {
+                url: url.clone(),
+                status: StatusCode::OK,
+                headers: [(ETAG, HeaderValue::from_static("\"foo\""))]
+                    .into_iter()
+                    .collect(),
+                body: b"cached".to_vec(),
+            },
+            Duration::default(),
+        );
+        let cache = Arc::new(MemoryCache::new(CACHE_CAPACITY));
+
+        cache
+            .set(
+                url.as_str().into(),
+                Ok(Arc::new(cached_response.clone().into())),
+            )
+            .await
+            .unwrap();
+
+        sleep(Duration::from_millis(10)).await;
+
+        let requests = Arc::new(Mutex::new(vec![]));
+        let client = HttpClient::new(
+            RecordingHttpClient {
+                responses: [
+                    stub_response("https://foo.com/robots.txt", None),
+                    (
+                        url.as_str().into(),
+                        BareResponse {
+                            url: url.clone(),
+                            status: StatusCode::NOT_MODIFIED,
+                            headers: Default::default(),
+                            body: vec![],
+                        },
+                    ),
+                ]
+                .into(),
+                requests: requests.clone(),
+            },
+            StubTimer::new(),
+            Box::new(cache.clone()),
+        );
+
+        assert_eq!(
+            client
+                .get(&Request::new(url.clone(), Default::default()))
+                .await
+                .unwrap(),
+            Some(cached_response.clone().into())
+        );
+        assert_eq!(
+            requests
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|request| request.url == url)
+                .unwrap()
+                .headers
+                .get(IF_NONE_MATCH),
+            Some(&HeaderValue::from_static("\"foo\""))
+        );
+        assert_eq!(
+            cache
+                .get(url.as_str())
+                .await
+                .unwrap()
+                .unwrap()
+                .unwrap()
+                .response()
+                .clone(),
+            cached_response.into()
+        );
+    }
+
+    #[tokio::test]
+    async fn replace_modified_cached_response() {
+        let url = Url::parse("https://foo.com").unwrap();
+        let fresh_response = BareResponse {
+            url: url.clone(),
+            status: StatusCode::OK,
+            headers: Default::default(),
+            body: b"fresh".to_vec(),
+        };
+        let cache = MemoryCache::new(CACHE_CAPACITY);
+
+        cache
+            .set(
+                url.as_str().into(),
+                Ok(Arc::new(
+                    Response::from_bare(
+                        BareResponse {
+                            headers: [(
+                                LAST_MODIFIED,
+                                HeaderValue::from_static("Sun, 06 Nov 1994 08:49:37 GMT"),
+                            )]
+                            .into_iter()
+                            .collect(),
+                            body: b"cached".to_vec(),
+                            ..fresh_response.clone()
+                        },
+                        Duration::default(),
+                    )
+                    .into(),
+                )),
+            )
+            .await
+            .unwrap();
+
+        sleep(Duration::from_millis(10)).await;
+
+        assert_eq!(
+            HttpClient::new(
+                StubHttpClient::new(
+                    [
+                        build_stub_response(
+                            url.join("/robots.txt").unwrap().as_str(),
+                            StatusCode::OK,
+                            Default::default(),
+                            vec![],
+                        ),
+                        (url.as_str().into(), Ok(fresh_response.clone()))
+                    ]
+                    .into_iter()
+                    .collect()
+                ),
+                StubTimer::new(),
+                Box::new(cache),
+            )
+            .get(&Request::new(url, Default::default()))
+            .await
+            .unwrap(),
+            Some(Response::from_bare(fresh_response, Duration::from_millis(0)).into())
+        );
+    }
+
+    #[tokio::test]
+    async fn update_headers_of_revalidated_cached_response() {
+        let url = Url::parse("https://foo.com").unwrap();
+        let cached_response = BareResponse {
+            url: url.clone(),
+            status: StatusCode::OK,
+            headers: [
+                (ETAG, HeaderValue::from_static("\"foo\"")),
+                (CACHE_CONTROL, HeaderValue::from_static("max-age=0")),
+                (CONTENT_LENGTH, HeaderValue::from_static("6")),
+                (CONTENT_TYPE, HeaderValue::from_static("text/plain")),
+            ]
+            .into_iter()
+            .collect(),
+            body: b"cached".to_vec(),
+        };
+        let cache = Arc::new(MemoryCache::new(CACHE_CAPACITY));
+
+        cache
+            .set(
+                url.as_str().into(),
+                Ok(Arc::new(
+                    Response::from_bare(cached_response.clone(), Duration::default()).into(),
+                )),
+            )
+            .await
+            .unwrap();
+
+        sleep(Duration::from_millis(10)).await;
+
+        let response = HttpClient::new(
+            StubHttpClient::new(
+                [
+                    build_stub_response(
+                        url.join("/robots.txt").unwrap().as_str(),
+                        StatusCode::OK,
+                        Default::default(),
+                        vec![],
+                    ),
+                    build_stub_response(
+                        url.as_str(),
+                        StatusCode::NOT_MODIFIED,
+                        [
+                            (ETAG, HeaderValue::from_static("\"bar\"")),
+                            (CACHE_CONTROL, HeaderValue::from_static("max-age=60")),
+                            (CONTENT_LENGTH, HeaderValue::from_static("0")),
+                        ]
+                        .into_iter()
+                        .collect(),
+                        vec![],
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            StubTimer::new(),
+            Box::new(cache.clone()),
+        )
+        .get(&Request::new(url.clone(), Default::default()))
+        .await
+        .unwrap()
+        .unwrap();
+
+        let expected_response = Arc::new(Response::from_bare(
+            BareResponse {
+                headers: [
+                    (ETAG, HeaderValue::from_static("\"bar\"")),
+                    (CACHE_CONTROL, HeaderValue::from_static("max-age=60")),
+                    (CONTENT_LENGTH, HeaderValue::from_static("6")),
+                    (CONTENT_TYPE, HeaderValue::from_static("text/plain")),
+                ]
+                .into_iter()
+                .collect(),
+                ..cached_response
+            },
+            Duration::default(),
+        ));
+
+        assert_eq!(response, expected_response);
+        assert_eq!(
+            cache
+                .get(url.as_str())
+                .await
+                .unwrap()
+                .unwrap()
+                .unwrap()
+                .response(),
+            &expected_response
+        );
+    }
+
+    #[tokio::test]
+    async fn get_cache_fresh_by_headers_without_max_age() {
+        let url = Url::parse("https://foo.com").unwrap();
+        let response = Response::from_bare(
+            BareResponse {
+                url: url.clone(),
+                status: StatusCode::OK,
+                headers: [(
+                    CACHE_CONTROL,
+                    HeaderValue::from_static("max-age=31536000, immutable"),
+                )]
+                .into_iter()
+                .collect(),
+                body: b"cached".to_vec(),
+            },
+            Duration::default(),
+        );
+        let cache = MemoryCache::new(CACHE_CAPACITY);
+
+        cache
+            .set(url.as_str().into(), Ok(Arc::new(response.clone().into())))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            HttpClient::new(
+                StubHttpClient::new(Default::default()),
+                StubTimer::new(),
+                Box::new(cache),
+            )
+            .get(&Request::new(url, Default::default()).set_cache_mode(CacheMode::Headers))
+            .await
+            .unwrap(),
+            Some(response.into())
+        );
+    }
+
+    #[tokio::test]
+    async fn get_cache_fresh_by_headers_with_huge_max_age() {
+        let url = Url::parse("https://foo.com").unwrap();
+        let response = Response::from_bare(
+            BareResponse {
+                url: url.clone(),
+                status: StatusCode::OK,
+                headers: [(
+                    CACHE_CONTROL,
+                    HeaderValue::from_static(
+                        "max-age=18446744073709551615, stale-while-revalidate=18446744073709551615",
+                    ),
+                )]
+                .into_iter()
+                .collect(),
+                body: b"cached".to_vec(),
+            },
+            Duration::default(),
+        );
+        let cache = MemoryCache::new(CACHE_CAPACITY);
+
+        cache
+            .set(url.as_str().into(), Ok(Arc::new(response.clone().into())))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            HttpClient::new(
+                StubHttpClient::new(Default::default()),
+                StubTimer::new(),
+                Box::new(cache),
+            )
+            .get(&Request::new(url, Default::default()).set_cache_mode(CacheMode::Headers))
+            .await
+            .unwrap(),
+            Some(response.into())
+        );
+    }
+
+    #[tokio::test]
+    async fn clamp_freshness_lifetime_by_max_age() {
         let url = Url::parse("https://foo.com").unwrap();
         let response = BareResponse {
             url: url.clone(),
             status: StatusCode::OK,
-            headers: Default::default(),
+            headers: [(CACHE_CONTROL, HeaderValue::from_static("max-age=3600"))]
+                .into_iter()
+                .collect(),
             body: vec![],
         };
-
         let cache = MemoryCache::new(CACHE_CAPACITY);
 
         cache
@@ -729,7 +1382,7 @@ mod tests {
                 Ok(Arc::new(
                     Response::from_bare(
                         BareResponse {
-                            body: b"stale".to_vec(),
+                            body: b"cached".to_vec(),
                             ..response.clone()
                         },
                         Duration::default(),
@@ -740,6 +1393,8 @@ mod tests {
             .await
             .unwrap();
 
+        sleep(Duration::from_millis(10)).await;
+
         assert_eq!(
             HttpClient::new(
                 StubHttpClient::new(
@@ -758,88 +1413,121 @@ mod tests {
                 StubTimer::new(),
                 Box::new(cache),
             )
-            .get(&Request::new(url, Default::default()).set_max_age(CACHE_MAX_AGE))
+            .get(
+                &Request::new(url, Default::default())
+                    .set_cache_mode(CacheMode::Headers)
+                    .set_max_age(Some(Duration::from_millis(1)))
+            )
             .await
             .unwrap(),
-            Some(
-                Response::from_bare(
-                    BareResponse {
-                        body: b"stale".to_vec(),
-                        ..response
-                    },
-                    Duration::from_millis(0)
-                )
-                .into()
-            )
+            Some(Response::from_bare(response, Duration::default()).into())
         );
     }
 
     #[tokio::test]
-    async fn update_cache() {
+    async fn extend_freshness_lifetime_by_min_age() {
         let url = Url::parse("https://foo.com").unwrap();
-        let response = BareResponse {
-            url: url.clone(),
-            status: StatusCode::OK,
-            headers: Default::default(),
-            body: vec![],
-        };
-
+        let response = Response::from_bare(
+            BareResponse {
+                url: url.clone(),
+                status: StatusCode::OK,
+                headers: Default::default(),
+                body: b"cached".to_vec(),
+            },
+            Duration::default(),
+        );
         let cache = MemoryCache::new(CACHE_CAPACITY);
 
         cache
-            .set(
-                url.as_str().into(),
-                Ok(Arc::new(
-                    Response::from_bare(
-                        BareResponse {
-                            body: b"stale".to_vec(),
-                            ..response.clone()
-                        },
-                        Duration::default(),
-                    )
-                    .into(),
-                )),
-            )
+            .set(url.as_str().into(), Ok(Arc::new(response.clone().into())))
             .await
             .unwrap();
 
         assert_eq!(
             HttpClient::new(
-                StubHttpClient::new(
-                    [
-                        build_stub_response(
-                            url.join("/robots.txt").unwrap().as_str(),
-                            StatusCode::OK,
-                            Default::default(),
-                            vec![],
-                        ),
-                        (url.as_str().into(), Ok(response.clone()))
-                    ]
-                    .into_iter()
-                    .collect()
-                ),
+                StubHttpClient::new(Default::default()),
                 StubTimer::new(),
                 Box::new(cache),
             )
-            .get(&Request::new(url, Default::default()))
+            .get(
+                &Request::new(url, Default::default())
+                    .set_cache_mode(CacheMode::Headers)
+                    .set_min_age(CACHE_MAX_AGE)
+            )
             .await
             .unwrap(),
-            Some(Response::from_bare(response, Duration::from_millis(0)).into())
+            Some(response.into())
         );
     }
 
     #[tokio::test]
-    async fn persist_response() {
+    async fn revalidate_zero_freshness_lifetime_despite_min_age() {
+        for value in ["no-cache", "max-age=0"] {
+            let url = Url::parse("https://foo.com").unwrap();
+            let response = BareResponse {
+                url: url.clone(),
+                status: StatusCode::OK,
+                headers: [(CACHE_CONTROL, HeaderValue::from_static(value))]
+                    .into_iter()
+                    .collect(),
+                body: vec![],
+            };
+            let cache = MemoryCache::new(CACHE_CAPACITY);
+
+            cache
+                .set(
+                    url.as_str().into(),
+                    Ok(Arc::new(
+                        Response::from_bare(
+                            BareResponse {
+                                body: b"cached".to_vec(),
+                                ..response.clone()
+                            },
+                            Duration::default(),
+                        )
+                        .into(),
+                    )),
+                )
+                .await
+                .unwrap();
+
+            sleep(Duration::from_millis(10)).await;
+
+            assert_eq!(
+                HttpClient::new(
+                    StubHttpClient::new(
+                        [
+                            build_stub_response(
+                                url.join("/robots.txt").unwrap().as_str(),
+                                StatusCode::OK,
+                                Default::default(),
+                                vec![],
+                            ),
+                            (url.as_str().into(), Ok(response.clone()))
+                        ]
+                        .into_iter()
+                        .collect()
+                    ),
+                    StubTimer::new(),
+                    Box::new(cache),
+                )
+                .get(
+                    &Request::new(url, Default::default())
+                        .set_cache_mode(CacheMode::Headers)
+                        .set_min_age(CACHE_MAX_AGE)
+                )
+                .await
+                .unwrap(),
+                Some(Response::from_bare(response, Duration::default()).into())
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn skip_caching_no_store_response() {
         let url = Url::parse("https://foo.com").unwrap();
-        let response = BareResponse {
-            url: url.clone(),
-            status: StatusCode::OK,
-            headers: Default::default(),
-            body: vec![],
-        };
         let cache = Arc::new(MemoryCache::new(CACHE_CAPACITY));
-
-        HttpClient::new(
+        let client = HttpClient::new(
             StubHttpClient::new(
                 [
                     build_stub_response(
@@ -848,83 +1536,38 @@ mod tests {
                         Default::default(),
                         vec![],
                     ),
-                    (url.as_str().into(), Ok(response.clone())),
+                    build_stub_response(
+                        url.as_str(),
+                        StatusCode::OK,
+                        [(CACHE_CONTROL, HeaderValue::from_static("no-store"))]
+                            .into_iter()
+                            .collect(),
+                        vec![],
+                    ),
                 ]
                 .into_iter()
                 .collect(),
             ),
             StubTimer::new(),
             Box::new(cache.clone()),
-        )
-        .get(&Request::new(url.clone(), Default::default()))
-        .await
-        .unwrap();
-
-        assert_eq!(
-            cache
-                .get(url.as_str())
-                .await
-                .unwrap()
-                .unwrap()
-                .unwrap()
-                .response()
-                .clone(),
-            Response::from_bare(response, Duration::from_millis(0)).into()
-        );
-    }
-
-    #[tokio::test]
-    async fn do_not_persist_error() {
-        let url = Url::parse("https://foo.com").unwrap();
-        let response = BareResponse {
-            url: url.clone(),
-            status: StatusCode::OK,
-            headers: Default::default(),
-            body: vec![],
-        };
-        let robots = build_stub_response(
-            url.join("/robots.txt").unwrap().as_str(),
-            StatusCode::OK,
-            Default::default(),
-            vec![],
         );
-        let cache = Arc::new(MemoryCache::new(CACHE_CAPACITY));
 
-        assert!(
-            HttpClient::new(
-                StubHttpClient::new(
-                    [
-                        robots.clone(),
-                        (
-                            url.as_str().into(),
-                            Err(HttpClientError::Http("boom".into())),
-                        ),
-                    ]
-                    .into_iter()
-                    .collect(),
-                ),
-                StubTimer::new(),
-                Box::new(cache.clone()),
+        client
+            .get(
+                &Request::new(url.clone(), Default::default())
+                    .set_cache_mode(CacheMode::Headers)
+                    .set_max_age(Some(CACHE_MAX_AGE)),
             )
-            .get(&Request::new(url.clone(), Default::default()))
             .await
-            .is_err()
-        );
+            .unwrap();
 
-        assert_eq!(
-            HttpClient::new(
-                StubHttpClient::new(
-                    [robots, (url.as_str().into(), Ok(response.clone()))]
-                        .into_iter()
-                        .collect(),
-                ),
-                StubTimer::new(),
-                Box::new(cache),
-            )
-            .get(&Request::new(url, Default::default()))
-            .await
-            .unwrap(),
-            Some(Response::from_bare(response, Duration::from_millis(0)).into())
+        assert!(cache.get(url.as_str()).await.unwrap().is_none());
+        assert!(
+            cache
+                .get(url.join("/robots.txt").unwrap().as_str())
+                .await
+                .unwrap()
+                .is_some()
         );
     }
 
@@ -962,7 +1605,7 @@ mod tests {
             .unwrap();
 
         let request = Request::new(url.clone(), Default::default())
-            .set_max_age(CACHE_MAX_AGE)
+            .set_max_age(Some(CACHE_MAX_AGE))
             .set_retry(
                 RetryConfig::default()
                     .set_statuses([StatusCode::TOO_MANY_REQUESTS].into())
@@ -1014,7 +1657,7 @@ mod tests {
             .unwrap();
 
         let request = Request::new(url.clone(), Default::default())
-            .set_max_age(CACHE_MAX_AGE)
+            .set_max_age(Some(CACHE_MAX_AGE))
             .set_retry(
                 RetryConfig::default()
                     .set_statuses([StatusCode::INTERNAL_SERVER_ERROR].into())
@@ -1610,7 +2253,7 @@ mod tests {
                 )
                 .get(
                     &Request::new(url, Default::default())
-                        .set_max_age(CACHE_MAX_AGE)
+                        .set_max_age(Some(CACHE_MAX_AGE))
                         .set_retry(
                             RetryConfig::default()
                                 .set_count(1)
@@ -1654,7 +2297,7 @@ mod tests {
                 )
                 .get(
                     &Request::new(url, Default::default())
-                        .set_max_age(CACHE_MAX_AGE)
+                        .set_max_age(Some(CACHE_MAX_AGE))
                         .set_retry(RetryConfig::default().set_count(1).into())
                 )
                 .await
@@ -1697,7 +2340,7 @@ mod tests {
                 )
                 .get(
                     &Request::new(url, Default::default())
-                        .set_max_age(CACHE_MAX_AGE)
+                        .set_max_age(Some(CACHE_MAX_AGE))
                         .set_retry(RetryConfig::default().set_count(1).into())
                 )
                 .await
@@ -1740,7 +2383,7 @@ mod tests {
                 )
                 .get(
                     &Request::new(url, Default::default())
-                        .set_max_age(CACHE_MAX_AGE)
+                        .set_max_age(Some(CACHE_MAX_AGE))
                         .set_retry(
                             RetryConfig::default()
                                 .set_count(2)
@@ -1787,7 +2430,7 @@ mod tests {
                 )
                 .get(
                     &Request::new(url, Default::default())
-                        .set_max_age(CACHE_MAX_AGE)
+                        .set_max_age(Some(CACHE_MAX_AGE))
                         .set_retry(
                             RetryConfig::default()
                                 .set_count(1)
@@ -1828,7 +2471,7 @@ mod tests {
                 )
                 .get(
                     &Request::new(url, Default::default())
-                        .set_max_age(CACHE_MAX_AGE)
+                        .set_max_age(Some(CACHE_MAX_AGE))
                         .set_retry(
                             RetryConfig::default()
                                 .set_count(1)
@@ -1841,5 +2484,142 @@ mod tests {
                 Some(Response::from_bare(retry_response, Duration::from_millis(0)).into())
             );
         }
+
+        async fn get_after_retry(retry_after: &'static str, cap: Option<Duration>) -> Duration {
+            let url = Url::parse("https://foo.com").unwrap();
+            let time = Instant::now();
+
+            HttpClient::new(
+                StubSequenceHttpClient::new(vec![
+                    build_stub_response(
+                        url.join("/robots.txt").unwrap().as_str(),
+                        StatusCode::OK,
+                        Default::default(),
+                        vec![],
+                    ),
+                    build_stub_response(
+                        url.as_str(),
+                        StatusCode::SERVICE_UNAVAILABLE,
+                        [(RETRY_AFTER, HeaderValue::from_static(retry_after))]
+                            .into_iter()
+                            .collect(),
+                        vec![],
+                    ),
+                    build_stub_response(url.as_str(), StatusCode::OK, Default::default(), vec![]),
+                ]),
+                StubTimer::new(),
+                Box::new(MemoryCache::new(CACHE_CAPACITY)),
+            )
+            .get(
+                &Request::new(url, Default::default()).set_retry(
+                    RetryConfig::default()
+                        .set_count(1)
+                        .set_interval(RetryDurationConfig::default().set_cap(cap))
+                        .set_statuses([StatusCode::SERVICE_UNAVAILABLE].into())
+                        .into(),
+                ),
+            )
+            .await
+            .unwrap();
+
+            time.elapsed()
+        }
+
+        #[tokio::test(start_paused = true)]
+        async fn retry_after_delay() {
+            assert_eq!(get_after_retry("42", None).await, Duration::from_secs(42));
+        }
+
+        #[tokio::test(start_paused = true)]
+        async fn retry_after_capped_delay() {
+            assert_eq!(
+                get_after_retry("42", Some(Duration::from_secs(1))).await,
+                Duration::from_secs(1)
+            );
+        }
+
+        #[tokio::test(start_paused = true)]
+        async fn retry_after_delay_limited_without_cap() {
+            assert_eq!(get_after_retry("999999999", None).await, MAX_RETRY_AFTER);
+        }
+
+        const THROTTLE_WINDOW: Duration = Duration::from_secs(1);
+
+        async fn get_throttled_site(status: StatusCode, headers: HeaderMap) -> Duration {
+            let url = Url::parse("https://foo.com").unwrap();
+            let paths = ["foo", "bar", "baz", "qux", "quux"];
+            let client =
+                HttpClient::new(
+                    StubHttpClient::new(
+                        [
+                            build_stub_response(
+                                url.join("/robots.txt").unwrap().as_str(),
+                                StatusCode::OK,
+                                Default::default(),
+                                vec![],
+                            ),
+                            build_stub_response(url.as_str(), status, headers, vec![]),
+                        ]
+                        .into_iter()
+                        .chain(paths.iter().map(|path| {
+                            build_stub_response(
+                                url.join(path).unwrap().as_str(),
+                                StatusCode::OK,
+                                Default::default(),
+                                vec![],
+                            )
+                        }))
+                        .collect(),
+                    ),
+                    StubTimer::new(),
+                    Box::new(MemoryCache::new(CACHE_CAPACITY)),
+                )
+                .set_rate_limit(&RateLimitConfig::default().set_sites(
+                    [("foo".into(), SiteRateLimitConfig::new(8, THROTTLE_WINDOW))].into(),
+                ));
+            let time = Instant::now();
+
+            for url in [url.clone()]
+                .into_iter()
+                .chain(paths.iter().map(|path| url.join(path).unwrap()))
+            {
+                client
+                    .get(&Request::new(url, Default::default()).set_site_id(Some("foo".into())))
+                    .await
+                    .unwrap();
+            }
+
+            time.elapsed()
+        }
+
+        #[tokio::test(start_paused = true)]
+        async fn throttle_site_on_too_many_requests() {
+            assert_eq!(
+                get_throttled_site(StatusCode::TOO_MANY_REQUESTS, Default::default()).await,
+                THROTTLE_WINDOW
+            );
+        }
+
+        #[tokio::test(start_paused = true)]
+        async fn throttle_site_on_service_unavailable_with_retry_after() {
+            assert_eq!(
+                get_throttled_site(
+                    StatusCode::SERVICE_UNAVAILABLE,
+                    [(RETRY_AFTER, HeaderValue::from_static("1"))]
+                        .into_iter()
+                        .collect(),
+                )
+                .await,
+                THROTTLE_WINDOW
+            );
+        }
+
+        #[tokio::test(start_paused = true)]
+        async fn skip_throttling_site_on_service_unavailable_without_retry_after() {
+            assert_eq!(
+                get_throttled_site(StatusCode::SERVICE_UNAVAILABLE, Default::default()).await,
+                Duration::ZERO
+            );
+        }
     }
 }
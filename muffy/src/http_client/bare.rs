@@ -1,4 +1,6 @@
 use super::HttpClientError;
+use crate::ProxyConfig;
+use alloc::sync::Arc;
 use async_trait::async_trait;
 use http::{HeaderMap, StatusCode};
 use url::Url;
@@ -8,12 +10,22 @@ use url::Url;
 pub trait BareHttpClient: Send + Sync {
     /// Sends a GET request.
     async fn get(&self, request: &BareRequest) -> Result<BareResponse, HttpClientError>;
+
+    /// Sends a HEAD request.
+    ///
+    /// By default, it sends a GET request and discards its response body.
+    async fn head(&self, request: &BareRequest) -> Result<BareResponse, HttpClientError> {
+        let mut response = self.get(request).await?;
+        response.body.clear();
+        Ok(response)
+    }
 }
 
 #[derive(Clone, Debug)]
 pub struct BareRequest {
     pub url: Url,
     pub headers: HeaderMap,
+    pub proxy: Option<Arc<ProxyConfig>>,
 }
 
 #[derive(Debug)]
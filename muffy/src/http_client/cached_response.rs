@@ -1,6 +1,7 @@
 use crate::response::Response;
 use alloc::sync::Arc;
 use core::time::Duration;
+use http::{HeaderMap, header::CONTENT_LENGTH};
 use serde::{Deserialize, Serialize};
 use std::time::SystemTime;
 
@@ -22,8 +23,44 @@ impl CachedResponse {
         &self.response
     }
 
+    pub const fn timestamp(&self) -> SystemTime {
+        self.timestamp
+    }
+
     pub fn is_expired(&self, duration: Duration) -> bool {
-        SystemTime::now() > self.timestamp + duration
+        // Responses never expire if their expiration times overflow.
+        self.timestamp
+            .checked_add(duration)
+            .is_some_and(|time| SystemTime::now() > time)
+    }
+
+    // Refreshes a response validated by a server with headers of its
+    // `304 Not Modified` response.
+    pub fn refresh(&self, headers: &HeaderMap) -> Self {
+        let response = &self.response;
+        let mut merged_headers = response.headers().clone();
+
+        // Content lengths of `304 Not Modified` responses do not describe
+        // stored bodies.
+        for name in headers.keys().filter(|name| *name != CONTENT_LENGTH) {
+            merged_headers.remove(name);
+        }
+
+        for (name, value) in headers.iter().filter(|(name, _)| *name != CONTENT_LENGTH) {
+            merged_headers.append(name, value.clone());
+        }
+
+        Self {
+            response: Response::new(
+                response.url().clone(),
+                response.status(),
+                merged_headers,
+                response.body().to_vec(),
+                response.duration(),
+            )
+            .into(),
+            timestamp: SystemTime::now(),
+        }
     }
 }
 
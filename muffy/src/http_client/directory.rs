@@ -0,0 +1,352 @@
+use super::{BareHttpClient, BareRequest, BareResponse, HttpClientError};
+use async_trait::async_trait;
+use http::{
+    HeaderMap, HeaderValue, StatusCode,
+    header::{CONTENT_TYPE, LOCATION},
+};
+use log::trace;
+use std::{
+    io::{self, ErrorKind},
+    path::{Component, Path, PathBuf, absolute},
+};
+use tokio::fs::{metadata, read};
+use url::Url;
+
+const INDEX_FILE: &str = "index.html";
+const DEFAULT_MEDIA_TYPE: &str = "application/octet-stream";
+
+/// An HTTP client serving files in a local directory.
+///
+/// It maps URLs under a base URL onto files in a directory and delegates the
+/// other requests to another HTTP client.
+pub struct DirectoryHttpClient {
+    url: Url,
+    directory: Url,
+    client: Box<dyn BareHttpClient>,
+}
+
+impl DirectoryHttpClient {
+    /// Creates an HTTP client.
+    pub fn new(
+        mut url: Url,
+        directory: &Path,
+        client: impl BareHttpClient + 'static,
+    ) -> Result<Self, io::Error> {
+        if !url.path().ends_with('/') {
+            url.set_path(&format!("{}/", url.path()));
+        }
+
+        Ok(Self {
+            url,
+            directory: Url::from_directory_path(absolute(directory)?).map_err(|_| {
+                io::Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("invalid directory: {}", directory.display()),
+                )
+            })?,
+            client: Box::new(client),
+        })
+    }
+
+    fn resolve_path(&self, url: &Url) -> Option<Option<PathBuf>> {
+        if url.origin() != self.url.origin() {
+            return None;
+        }
+
+        let path = url
+            .path()
+            .strip_prefix(self.url.path().trim_end_matches('/'))?;
+
+        if !path.is_empty() && !path.starts_with('/') {
+            return None;
+        }
+
+        Some(
+            self.directory
+                .join(&format!(".{path}"))
+                .ok()
+                .and_then(|url| url.to_file_path().ok())
+                .filter(|path| {
+                    !path
+                        .components()
+                        .any(|component| component == Component::ParentDir)
+                }),
+        )
+    }
+
+    async fn read_file(&self, url: &Url, path: &Path) -> Result<BareResponse, HttpClientError> {
+        if !url.path().ends_with('/')
+            && metadata(path).await.is_ok_and(|metadata| metadata.is_dir())
+        {
+            let mut location = url.clone();
+            location.set_path(&format!("{}/", url.path()));
+
+            return Ok(BareResponse {
+                url: url.clone(),
+                status: StatusCode::MOVED_PERMANENTLY,
+                headers: HeaderMap::from_iter([(
+                    LOCATION,
+                    HeaderValue::try_from(location.as_str())?,
+                )]),
+                body: vec![],
+            });
+        }
+
+        let path = if url.path().ends_with('/') {
+            path.join(INDEX_FILE)
+        } else {
+            path.into()
+        };
+
+        match read(&path).await {
+            Ok(body) => Ok(BareResponse {
+                url: url.clone(),
+                status: StatusCode::OK,
+                headers: HeaderMap::from_iter([(
+                    CONTENT_TYPE,
+                    HeaderValue::from_static(infer_media_type(&path)),
+                )]),
+                body,
+            }),
+            Err(error)
+                if matches!(
+                    error.kind(),
+                    ErrorKind::NotFound | ErrorKind::NotADirectory | ErrorKind::IsADirectory
+                ) =>
+            {
+                Ok(build_not_found_response(url))
+            }
+            Err(error) => Err(error.into()),
+        }
+    }
+}
+
+#[async_trait]
+impl BareHttpClient for DirectoryHttpClient {
+    async fn get(&self, request: &BareRequest) -> Result<BareResponse, HttpClientError> {
+        let Some(path) = self.resolve_path(&request.url) else {
+            return self.client.get(request).await;
+        };
+
+        trace!("reading a file for {}", request.url);
+
+        if let Some(path) = path {
+            self.read_file(&request.url, &path).await
+        } else {
+            Ok(build_not_found_response(&request.url))
+        }
+    }
+
+    async fn head(&self, request: &BareRequest) -> Result<BareResponse, HttpClientError> {
+        if self.resolve_path(&request.url).is_none() {
+            return self.client.head(request).await;
+        }
+
+        let mut response = self.get(request).await?;
+        response.body.clear();
+        Ok(response)
+    }
+}
+
+fn build_not_found_response(url: &Url) -> BareResponse {
+    BareResponse {
+        url: url.clone(),
+        status: StatusCode::NOT_FOUND,
+        headers: Default::default(),
+        body: vec![],
+    }
+}
+
+fn infer_media_type(path: &Path) -> &'static str {
+    let Some(extension) = path.extension().and_then(|extension| extension.to_str()) else {
+        return DEFAULT_MEDIA_TYPE;
+    };
+
+    match extension.to_ascii_lowercase().as_str() {
+        "avif" => "image/avif",
+        "css" => "text/css",
+        "gif" => "image/gif",
+        "htm" | "html" => "text/html",
+        "ico" => "image/vnd.microsoft.icon",
+        "jpeg" | "jpg" => "image/jpeg",
+        "js" | "mjs" => "text/javascript",
+        "json" => "application/json",
+        "md" => "text/markdown",
+        "mp3" => "audio/mpeg",
+        "mp4" => "video/mp4",
+        "otf" => "font/otf",
+        "pdf" => "application/pdf",
+        "png" => "image/png",
+        "svg" => "image/svg+xml",
+        "ttf" => "font/ttf",
+        "txt" => "text/plain",
+        "wasm" => "application/wasm",
+        "webm" => "video/webm",
+        "webmanifest" => "application/manifest+json",
+        "webp" => "image/webp",
+        "woff" => "font/woff",
+        "woff2" => "font/woff2",
+        "xhtml" => "application/xhtml+xml",
+        "xml" => "application/xml",
+        _ => DEFAULT_MEDIA_TYPE,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http_client::{StubHttpClient, build_stub_response};
+    use pretty_assertions::assert_eq;
+    use std::fs::{create_dir_all, write};
+    use tempfile::{TempDir, tempdir};
+
+    fn create_client(files: &[(&str, &str)]) -> (TempDir, DirectoryHttpClient) {
+        let directory = tempdir().unwrap();
+
+        for (path, content) in files {
+            let path = directory.path().join(path);
+
+            create_dir_all(path.parent().unwrap()).unwrap();
+            write(path, content).unwrap();
+        }
+
+        let client = DirectoryHttpClient::new(
+            Url::parse("https://foo.com/").unwrap(),
+            directory.path(),
+            StubHttpClient::new(
+                [build_stub_response(
+                    "https://bar.com/",
+                    StatusCode::OK,
+                    Default::default(),
+                    vec![],
+                )]
+                .into_iter()
+                .collect(),
+            ),
+        )
+        .unwrap();
+
+        (directory, client)
+    }
+
+    async fn get(client: &DirectoryHttpClient, url: &str) -> BareResponse {
+        client
+            .get(&BareRequest {
+                url: Url::parse(url).unwrap(),
+                headers: Default::default(),
+                proxy: None,
+            })
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn read_file() {
+        let (_directory, client) = create_client(&[("foo.html", "foo")]);
+
+        let response = get(&client, "https://foo.com/foo.html").await;
+
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(response.headers[CONTENT_TYPE], "text/html");
+        assert_eq!(response.body, b"foo");
+    }
+
+    #[tokio::test]
+    async fn read_index_file() {
+        let (_directory, client) =
+            create_client(&[("index.html", "foo"), ("bar/index.html", "bar")]);
+
+        assert_eq!(get(&client, "https://foo.com/").await.body, b"foo");
+        assert_eq!(get(&client, "https://foo.com/bar/").await.body, b"bar");
+    }
+
+    #[tokio::test]
+    async fn redirect_to_directory_with_trailing_slash() {
+        let (_directory, client) = create_client(&[("foo/index.html", "foo")]);
+
+        let response = get(&client, "https://foo.com/foo?bar#baz").await;
+
+        assert_eq!(response.status, StatusCode::MOVED_PERMANENTLY);
+        assert_eq!(response.headers[LOCATION], "https://foo.com/foo/?bar#baz");
+    }
+
+    #[tokio::test]
+    async fn read_file_with_encoded_path() {
+        let (_directory, client) = create_client(&[("foo bar.txt", "foo")]);
+
+        let response = get(&client, "https://foo.com/foo%20bar.txt").await;
+
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(response.headers[CONTENT_TYPE], "text/plain");
+    }
+
+    #[tokio::test]
+    async fn read_file_under_base_path() {
+        let directory = tempdir().unwrap();
+        write(directory.path().join("foo.css"), "").unwrap();
+        let client = DirectoryHttpClient::new(
+            Url::parse("https://foo.com/bar").unwrap(),
+            directory.path(),
+            StubHttpClient::new(Default::default()),
+        )
+        .unwrap();
+
+        let response = get(&client, "https://foo.com/bar/foo.css").await;
+
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(response.headers[CONTENT_TYPE], "text/css");
+    }
+
+    #[tokio::test]
+    async fn return_not_found() {
+        let (_directory, client) = create_client(&[("foo.html", "foo")]);
+
+        for url in [
+            "https://foo.com/",
+            "https://foo.com/bar.html",
+            "https://foo.com/foo.html/",
+            "https://foo.com/..%2Ffoo.html",
+        ] {
+            assert_eq!(get(&client, url).await.status, StatusCode::NOT_FOUND);
+        }
+    }
+
+    #[tokio::test]
+    async fn read_file_headers() {
+        let (_directory, client) = create_client(&[("foo.html", "foo")]);
+
+        let response = client
+            .head(&BareRequest {
+                url: Url::parse("https://foo.com/foo.html").unwrap(),
+                headers: Default::default(),
+                proxy: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(response.headers[CONTENT_TYPE], "text/html");
+        assert_eq!(response.body, b"");
+    }
+
+    #[tokio::test]
+    async fn delegate_other_urls() {
+        let (_directory, client) = create_client(&[]);
+
+        let response = get(&client, "https://bar.com/").await;
+
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(response.url.as_str(), "https://bar.com/");
+    }
+
+    #[test]
+    fn infer_media_types() {
+        assert_eq!(infer_media_type(Path::new("foo.HTML")), "text/html");
+        assert_eq!(infer_media_type(Path::new("foo.svg")), "image/svg+xml");
+        assert_eq!(
+            infer_media_type(Path::new("sitemap.xml")),
+            "application/xml"
+        );
+        assert_eq!(infer_media_type(Path::new("foo")), DEFAULT_MEDIA_TYPE);
+    }
+}
@@ -12,6 +12,7 @@ pub enum HttpClientError {
     Cache(CacheError),
     HostNotDefined,
     Http(Arc<str>),
+    Io(Arc<str>),
     RedirectLocation,
     RobotsTxt,
     Timeout(Arc<str>),
@@ -28,6 +29,7 @@ impl Display for HttpClientError {
             Self::Cache(error) => write!(formatter, "{error}"),
             Self::HostNotDefined => write!(formatter, "host not defined"),
             Self::Http(error) => write!(formatter, "{error}"),
+            Self::Io(error) => write!(formatter, "{error}"),
             Self::RedirectLocation => write!(formatter, "location header not found on redirect"),
             Self::RobotsTxt => write!(formatter, "rejected by robots.txt"),
             Self::Timeout(error) => write!(formatter, "{error}"),
@@ -44,6 +46,18 @@ impl From<CacheError> for HttpClientError {
     }
 }
 
+impl From<http::header::InvalidHeaderValue> for HttpClientError {
+    fn from(error: http::header::InvalidHeaderValue) -> Self {
+        Self::Http(error.to_string().into())
+    }
+}
+
+impl From<std::io::Error> for HttpClientError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error.to_string().into())
+    }
+}
+
 impl From<tokio::time::error::Elapsed> for HttpClientError {
     fn from(error: tokio::time::error::Elapsed) -> Self {
         Self::Timeout(error.to_string().into())
@@ -1,36 +1,53 @@
 use super::{BareHttpClient, BareRequest, BareResponse, HttpClientError};
+use crate::ProxyConfig;
+use alloc::sync::Arc;
 use async_trait::async_trait;
 use log::trace;
-use reqwest::{Client, ClientBuilder, redirect::Policy};
+use reqwest::{Client, ClientBuilder, Method, Proxy, redirect::Policy};
+use scc::{HashMap, hash_map::Entry};
 
 /// An HTTP client backed by [`reqwest`].
+///
+/// Without any proxy configurations in requests, proxies are configured by the
+/// `HTTP_PROXY`, `HTTPS_PROXY`, and `NO_PROXY` environment variables.
 #[derive(Debug, Default)]
 pub struct ReqwestHttpClient {
     client: Client,
+    proxy_clients: HashMap<Arc<ProxyConfig>, Client>,
 }
 
 impl ReqwestHttpClient {
     /// Creates an HTTP client.
     pub fn new() -> Result<Self, reqwest::Error> {
         Ok(Self {
-            client: ClientBuilder::new()
-                .tcp_keepalive(None)
-                .redirect(Policy::none())
-                .build()?,
+            client: build_client(None)?,
+            proxy_clients: Default::default(),
         })
     }
-}
 
-#[async_trait]
-impl BareHttpClient for ReqwestHttpClient {
-    async fn get(&self, request: &BareRequest) -> Result<BareResponse, HttpClientError> {
-        trace!("sending a request to {}", request.url);
+    async fn client(&self, proxy: Option<&Arc<ProxyConfig>>) -> Result<Client, reqwest::Error> {
+        let Some(proxy) = proxy else {
+            return Ok(self.client.clone());
+        };
+
+        Ok(match self.proxy_clients.entry_async(proxy.clone()).await {
+            Entry::Occupied(entry) => entry.get().clone(),
+            Entry::Vacant(entry) => entry.insert_entry(build_client(Some(proxy))?).get().clone(),
+        })
+    }
+
+    async fn send(
+        &self,
+        method: Method,
+        request: &BareRequest,
+    ) -> Result<BareResponse, HttpClientError> {
+        trace!("sending a {method} request to {}", request.url);
 
-        let response = self
-            .client
+        let client = self.client(request.proxy.as_ref()).await?;
+        let response = client
             .execute(
-                self.client
-                    .get(request.url.clone())
+                client
+                    .request(method, request.url.clone())
                     .headers(request.headers.clone())
                     .build()?,
             )
@@ -47,18 +64,155 @@ impl BareHttpClient for ReqwestHttpClient {
     }
 }
 
+#[async_trait]
+impl BareHttpClient for ReqwestHttpClient {
+    async fn get(&self, request: &BareRequest) -> Result<BareResponse, HttpClientError> {
+        self.send(Method::GET, request).await
+    }
+
+    async fn head(&self, request: &BareRequest) -> Result<BareResponse, HttpClientError> {
+        self.send(Method::HEAD, request).await
+    }
+}
+
 impl From<reqwest::Error> for HttpClientError {
     fn from(error: reqwest::Error) -> Self {
         Self::Http(error.to_string().into())
     }
 }
 
+fn build_client(proxy: Option<&ProxyConfig>) -> Result<Client, reqwest::Error> {
+    let builder = ClientBuilder::new()
+        .tcp_keepalive(None)
+        .redirect(Policy::none());
+
+    match proxy {
+        None => builder,
+        Some(config) => {
+            if let Some(url) = config.url() {
+                let proxy = Proxy::all(url.clone())?;
+
+                builder.proxy(if let Some(username) = config.username() {
+                    proxy.basic_auth(username, config.password().unwrap_or_default())
+                } else {
+                    proxy
+                })
+            } else {
+                builder.no_proxy()
+            }
+        }
+    }
+    .build()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use http::StatusCode;
+    use pretty_assertions::assert_eq;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+        spawn,
+        task::JoinHandle,
+    };
+    use url::Url;
+
+    // Serves a response to a request and returns a head of the request.
+    async fn serve() -> (Url, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = Url::parse(&format!("http://{}", listener.local_addr().unwrap())).unwrap();
+
+        (
+            url,
+            spawn(async move {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut head = vec![];
+
+                while !head.ends_with(b"\r\n\r\n") {
+                    head.push(stream.read_u8().await.unwrap());
+                }
+
+                stream
+                    .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\nconnection: close\r\n\r\n")
+                    .await
+                    .unwrap();
+
+                String::from_utf8(head).unwrap()
+            }),
+        )
+    }
 
     #[test]
     fn create() {
         ReqwestHttpClient::new().unwrap();
     }
+
+    #[tokio::test]
+    async fn send_request_through_proxy() {
+        let (url, server) = serve().await;
+
+        let response = ReqwestHttpClient::new()
+            .unwrap()
+            .get(&BareRequest {
+                url: Url::parse("http://foo.com/bar").unwrap(),
+                headers: Default::default(),
+                proxy: Some(ProxyConfig::new().set_url(Some(url)).into()),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, StatusCode::OK);
+        assert!(
+            server
+                .await
+                .unwrap()
+                .starts_with("GET http://foo.com/bar HTTP/1.1\r\n")
+        );
+    }
+
+    #[tokio::test]
+    async fn send_request_through_proxy_with_basic_auth() {
+        let (url, server) = serve().await;
+
+        ReqwestHttpClient::new()
+            .unwrap()
+            .get(&BareRequest {
+                url: Url::parse("http://foo.com/bar").unwrap(),
+                headers: Default::default(),
+                proxy: Some(
+                    ProxyConfig::new()
+                        .set_url(Some(url))
+                        .set_username(Some("foo".into()))
+                        .set_password(Some("bar".into()))
+                        .into(),
+                ),
+            })
+            .await
+            .unwrap();
+
+        assert!(
+            server
+                .await
+                .unwrap()
+                .contains("\r\nproxy-authorization: Basic Zm9vOmJhcg==\r\n")
+        );
+    }
+
+    #[tokio::test]
+    async fn bypass_proxy() {
+        let (url, server) = serve().await;
+
+        ReqwestHttpClient::new()
+            .unwrap()
+            .get(&BareRequest {
+                url: url.join("/bar").unwrap(),
+                headers: Default::default(),
+                proxy: Some(ProxyConfig::new().into()),
+            })
+            .await
+            .unwrap();
+
+        assert!(server.await.unwrap().starts_with("GET /bar HTTP/1.1\r\n"));
+    }
 }
@@ -12,6 +12,7 @@ use url::Url;
 #[derive(Debug)]
 pub struct StubHttpClient {
     results: HashMap<String, Result<BareResponse, HttpClientError>>,
+    head_results: Option<HashMap<String, Result<BareResponse, HttpClientError>>>,
     delay: Duration,
 }
 
@@ -19,6 +20,7 @@ impl StubHttpClient {
     pub fn new(results: HashMap<String, Result<BareResponse, HttpClientError>>) -> Self {
         Self {
             results,
+            head_results: None,
             delay: Default::default(),
         }
     }
@@ -27,6 +29,14 @@ impl StubHttpClient {
         self.delay = delay;
         self
     }
+
+    pub fn set_head_results(
+        mut self,
+        results: HashMap<String, Result<BareResponse, HttpClientError>>,
+    ) -> Self {
+        self.head_results = Some(results);
+        self
+    }
 }
 
 #[async_trait]
@@ -39,6 +49,19 @@ impl BareHttpClient for StubHttpClient {
             .expect("stub response")
             .clone()
     }
+
+    async fn head(&self, request: &BareRequest) -> Result<BareResponse, HttpClientError> {
+        let Some(results) = &self.head_results else {
+            panic!("unexpected HEAD request to {}", request.url);
+        };
+
+        sleep(self.delay).await;
+
+        results
+            .get(request.url.as_str())
+            .expect("stub response")
+            .clone()
+    }
 }
 
 #[derive(Debug)]
@@ -2,7 +2,9 @@
 
 extern crate alloc;
 
+mod baseline;
 mod cache;
+mod cache_mode;
 mod config;
 mod document_output;
 mod document_parser;
@@ -14,24 +16,34 @@ mod http_client;
 mod item_output;
 mod metrics;
 mod rate_limiter;
+mod refresh;
 mod render;
 mod request;
+mod request_method;
 mod response;
 mod robot_list;
+mod severity;
 mod sitemap;
+mod srcset;
 mod timer;
 mod web_validator;
 
 pub use self::{
+    baseline::{Baseline, BaselineEntry, read_baseline, write_baseline},
     cache::{FjallCache, GlobalCache, LocalCache, MemoryCache, MokaCache, SledCache},
+    cache_mode::CacheMode,
     config::*,
     document_output::DocumentOutput,
     document_parser::DocumentParser,
     error::{Error, ItemError},
-    http_client::{BareHttpClient, HttpClient, ReqwestHttpClient},
+    http_client::{BareHttpClient, DirectoryHttpClient, HttpClient, ReqwestHttpClient},
     metrics::Metrics,
     rate_limiter::RateLimiter,
-    render::{RenderFormat, RenderOptions, render_document},
+    render::{
+        RenderFormat, RenderOptions, render_document, render_documents, render_stale_entries,
+    },
+    request_method::RequestMethod,
+    severity::Severity,
     timer::ClockTimer,
     web_validator::WebValidator,
 };
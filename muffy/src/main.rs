@@ -9,10 +9,10 @@ use futures::StreamExt;
 use http::{HeaderName, HeaderValue, StatusCode};
 use itertools::Itertools;
 use muffy::{
-    CacheConfig, ClockTimer, ConcurrencyConfig, Config, DocumentParser, FjallCache, HttpClient,
-    MarkupConfig, MokaCache, RateLimitConfig, RenderFormat, RenderOptions, ReqwestHttpClient,
-    RetryConfig, RetryDurationConfig, SchemeConfig, SiteConfig, SiteRateLimitConfig, StatusConfig,
-    WebValidator,
+    BareHttpClient, Baseline, CacheConfig, ClockTimer, ConcurrencyConfig, Config,
+    DirectoryHttpClient, DocumentParser, FjallCache, HttpClient, MarkupConfig, MokaCache,
+    ProxyConfig, RateLimitConfig, RenderFormat, RenderOptions, ReqwestHttpClient, RetryConfig,
+    RetryDurationConfig, SchemeConfig, SiteConfig, SiteRateLimitConfig, StatusConfig, WebValidator,
 };
 use regex::Regex;
 use rlimit::{Resource, getrlimit, increase_nofile_limit};
@@ -28,7 +28,7 @@ use tabled::{
 };
 use tokio::{
     fs::{create_dir_all, remove_dir_all, try_exists, write},
-    io::{AsyncWriteExt, stdout},
+    io::{AsyncWriteExt, stderr, stdout},
 };
 use url::Url;
 
@@ -64,6 +64,19 @@ struct Arguments {
     /// Be verbose.
     #[arg(long, global = true)]
     verbose: bool,
+    /// Report only errors not recorded in a baseline file.
+    #[arg(long, global = true)]
+    baseline: Option<PathBuf>,
+    /// Record errors in a baseline file.
+    #[arg(long, global = true)]
+    write_baseline: Option<PathBuf>,
+}
+
+struct RunOptions {
+    format: RenderFormat,
+    verbose: bool,
+    baseline: Option<PathBuf>,
+    write_baseline: Option<PathBuf>,
 }
 
 #[derive(clap::Subcommand)]
@@ -72,6 +85,8 @@ enum Command {
     Check(CheckArguments),
     /// Validates a website.
     CheckSite(Box<CheckSiteArguments>),
+    /// Validates a website built in a local directory.
+    CheckDir(Box<CheckDirArguments>),
     /// Manages the persistent cache.
     Cache(CacheArguments),
     /// Initializes a configuration file in the current directory.
@@ -90,6 +105,24 @@ struct CheckSiteArguments {
     /// Website URLs.
     #[arg(required(true))]
     url: Vec<String>,
+    #[command(flatten)]
+    site: SiteArguments,
+}
+
+#[derive(clap::Args, Debug)]
+struct CheckDirArguments {
+    /// A directory of a website.
+    #[arg()]
+    directory: PathBuf,
+    /// Set a base URL mapped onto the directory.
+    #[arg(long, default_value = "http://localhost/")]
+    base_url: Url,
+    #[command(flatten)]
+    site: SiteArguments,
+}
+
+#[derive(clap::Args, Debug)]
+struct SiteArguments {
     /// Use a persistent cache.
     #[arg(long)]
     cache: bool,
@@ -115,6 +148,10 @@ struct CheckSiteArguments {
     /// Set an HTTP timeout.
     #[arg(long, default_value = "30s")]
     timeout: DurationString,
+    /// Set a proxy URL. It defaults to ones in the `HTTP_PROXY` and
+    /// `HTTPS_PROXY` environment variables.
+    #[arg(long)]
+    proxy: Option<Url>,
     /// Set concurrency. It defaults to a half of an open file limit.
     #[arg(long)]
     concurrency: Option<usize>,
@@ -182,8 +219,12 @@ async fn run() -> Result<(), Box<dyn Error>> {
 
     increase_nofile_limit(arguments.open_file_limit)?;
 
-    let format = arguments.format;
-    let verbose = arguments.verbose;
+    let options = RunOptions {
+        format: arguments.format,
+        verbose: arguments.verbose,
+        baseline: arguments.baseline,
+        write_baseline: arguments.write_baseline,
+    };
 
     match arguments
         .command
@@ -213,13 +254,33 @@ async fn run() -> Result<(), Box<dyn Error>> {
 
             run_config(
                 &muffy::compile_config(muffy::read_config(&config_file).await?)?,
-                format,
-                verbose,
+                ReqwestHttpClient::new()?,
+                &options,
             )
             .await
         }
         Command::CheckSite(sub_arguments) => {
-            run_config(&compile_check_site_config(&sub_arguments)?, format, verbose).await
+            run_config(
+                &compile_check_site_config(&sub_arguments.url, &sub_arguments.site)?,
+                ReqwestHttpClient::new()?,
+                &options,
+            )
+            .await
+        }
+        Command::CheckDir(sub_arguments) => {
+            run_config(
+                &compile_check_site_config(
+                    &[sub_arguments.base_url.to_string()],
+                    &sub_arguments.site,
+                )?,
+                DirectoryHttpClient::new(
+                    sub_arguments.base_url,
+                    &sub_arguments.directory,
+                    ReqwestHttpClient::new()?,
+                )?,
+                &options,
+            )
+            .await
         }
         Command::Init => initialize_config(&current_dir()?).await,
     }
@@ -227,8 +288,8 @@ async fn run() -> Result<(), Box<dyn Error>> {
 
 async fn run_config(
     config: &Config,
-    format: RenderFormat,
-    verbose: bool,
+    client: impl BareHttpClient + 'static,
+    options: &RunOptions,
 ) -> Result<(), Box<dyn Error>> {
     let mut output = stdout();
     let db = if config.persistent_cache() {
@@ -239,7 +300,7 @@ async fn run_config(
     };
     let validator = WebValidator::new(
         HttpClient::new(
-            ReqwestHttpClient::new()?,
+            client,
             ClockTimer::new(),
             if let Some(db) = &db {
                 Box::new(FjallCache::new(
@@ -257,25 +318,56 @@ async fn run_config(
     let mut documents = validator.validate(config).await?;
     let mut document_metrics = muffy::Metrics::default();
     let mut element_metrics = muffy::Metrics::default();
+    let format = options.format;
+    let render_options = RenderOptions::default()
+        .set_format(format)
+        .set_verbose(options.verbose);
+    let baseline = if let Some(file) = &options.baseline {
+        Some(muffy::read_baseline(file).await?)
+    } else {
+        None
+    };
+    let mut current_baseline = Baseline::new();
+    let mut aggregated_documents = vec![];
 
     while let Some(document) = documents.next().await {
-        let document = document?;
+        let mut document = document?;
+
+        current_baseline.insert_document(&document);
+
+        if let Some(baseline) = &baseline {
+            document = baseline.filter_document(document);
+        }
 
         document_metrics.add(document.metrics().has_error());
         element_metrics.merge(&document.metrics());
 
-        muffy::render_document(
-            &document,
-            &RenderOptions::default()
-                .set_format(format)
-                .set_verbose(verbose),
-            &mut output,
-        )
-        .await?;
+        if format.is_aggregated() {
+            aggregated_documents.push(document);
+        } else {
+            muffy::render_document(&document, &render_options, &mut output).await?;
+        }
+    }
+
+    if format.is_aggregated() {
+        muffy::render_documents(&aggregated_documents, &render_options, &mut output).await?;
     }
 
     output.flush().await?;
 
+    if let Some(file) = &options.write_baseline {
+        muffy::write_baseline(file, &current_baseline).await?;
+    }
+
+    if let Some(baseline) = &baseline {
+        muffy::render_stale_entries(
+            baseline.stale_entries(&current_baseline),
+            &render_options,
+            stderr(),
+        )
+        .await?;
+    }
+
     eprintln!();
     eprintln!(
         "{}",
@@ -352,11 +444,14 @@ async fn initialize_config(directory: &Path) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-fn compile_check_site_config(arguments: &CheckSiteArguments) -> Result<Config, Box<dyn Error>> {
+fn compile_check_site_config(
+    urls: &[String],
+    arguments: &SiteArguments,
+) -> Result<Config, Box<dyn Error>> {
     let site = SiteConfig::default()
         .set_cache(
             CacheConfig::default()
-                .set_max_age(*arguments.max_age)
+                .set_max_age(Some(*arguments.max_age))
                 .set_stale_while_revalidate(*arguments.stale_while_revalidate),
         )
         .set_status(StatusConfig::new(
@@ -406,13 +501,25 @@ fn compile_check_site_config(arguments: &CheckSiteArguments) -> Result<Config, B
                 .into(),
         )
         .set_timeout(Some(*arguments.timeout))
+        .set_proxy(
+            arguments
+                .proxy
+                .clone()
+                .map(|url| ProxyConfig::new().set_url(Some(url)).into()),
+        )
         .set_validation(
             muffy::ValidationConfig::default()
+                .set_duplicate_ids(arguments.experimental_validation)
                 .set_html(
                     arguments
                         .experimental_validation
                         .then(MarkupConfig::default),
                 )
+                .set_mathml(
+                    arguments
+                        .experimental_validation
+                        .then(MarkupConfig::default),
+                )
                 .set_svg(
                     arguments
                         .experimental_validation
@@ -421,11 +528,9 @@ fn compile_check_site_config(arguments: &CheckSiteArguments) -> Result<Config, B
         );
 
     Ok(Config::new(
-        arguments.url.to_vec(),
+        urls.to_vec(),
         site.clone().into(),
-        arguments
-            .url
-            .iter()
+        urls.iter()
             .map(|url| Url::parse(url))
             .collect::<Result<Vec<_>, _>>()?
             .into_iter()
@@ -484,15 +589,15 @@ mod tests {
         };
 
         assert_eq!(
-            arguments.accept_status,
+            arguments.site.accept_status,
             muffy::DEFAULT_ACCEPTED_STATUS_CODES
         );
-        assert_eq!(arguments.timeout, muffy::DEFAULT_TIMEOUT);
-        assert_eq!(arguments.max_age, Duration::default());
-        assert_eq!(arguments.stale_while_revalidate, Duration::default());
-        assert_eq!(arguments.retry_status, Vec::<u16>::new());
-        assert_eq!(arguments.concurrency, None);
-        assert!(!arguments.experimental_validation);
+        assert_eq!(arguments.site.timeout, muffy::DEFAULT_TIMEOUT);
+        assert_eq!(arguments.site.max_age, Duration::default());
+        assert_eq!(arguments.site.stale_while_revalidate, Duration::default());
+        assert_eq!(arguments.site.retry_status, Vec::<u16>::new());
+        assert_eq!(arguments.site.concurrency, None);
+        assert!(!arguments.site.experimental_validation);
     }
 
     #[test]
@@ -509,7 +614,27 @@ mod tests {
             panic!()
         };
 
-        assert_eq!(arguments.concurrency, Some(42));
+        assert_eq!(arguments.site.concurrency, Some(42));
+    }
+
+    #[test]
+    fn parse_proxy_check_site_arguments() {
+        let Command::CheckSite(arguments) = Arguments::parse_from([
+            "command",
+            "check-site",
+            "https://foo.com",
+            "--proxy",
+            "http://proxy.com:3128",
+        ])
+        .command
+        .unwrap() else {
+            panic!()
+        };
+
+        assert_eq!(
+            arguments.site.proxy,
+            Some(Url::parse("http://proxy.com:3128").unwrap())
+        );
     }
 
     #[test]
@@ -536,11 +661,14 @@ mod tests {
             panic!()
         };
 
-        assert_eq!(arguments.retry_count, 3);
-        assert_eq!(arguments.retry_factor, 3.0);
-        assert_eq!(*arguments.initial_retry_interval, Duration::from_secs(2));
-        assert_eq!(*arguments.retry_interval_cap, Duration::from_secs(20));
-        assert_eq!(arguments.retry_status, vec![429, 503]);
+        assert_eq!(arguments.site.retry_count, 3);
+        assert_eq!(arguments.site.retry_factor, 3.0);
+        assert_eq!(
+            *arguments.site.initial_retry_interval,
+            Duration::from_secs(2)
+        );
+        assert_eq!(*arguments.site.retry_interval_cap, Duration::from_secs(20));
+        assert_eq!(arguments.site.retry_status, vec![429, 503]);
     }
 
     #[test]
@@ -558,7 +686,7 @@ mod tests {
         };
 
         assert_eq!(
-            *arguments.stale_while_revalidate,
+            *arguments.site.stale_while_revalidate,
             Duration::from_secs(30 * 60)
         );
     }
@@ -576,7 +704,7 @@ mod tests {
             panic!()
         };
 
-        assert!(arguments.experimental_validation);
+        assert!(arguments.site.experimental_validation);
     }
 
     #[test]
@@ -640,6 +768,47 @@ mod tests {
         }
     }
 
+    mod check_dir {
+        use super::*;
+
+        #[test]
+        fn parse_directory() {
+            let Command::CheckDir(arguments) =
+                Arguments::parse_from(["command", "check-dir", "dist"])
+                    .command
+                    .unwrap()
+            else {
+                panic!()
+            };
+
+            assert_eq!(arguments.directory, PathBuf::from("dist"));
+            assert_eq!(arguments.base_url.as_str(), "http://localhost/");
+            assert_eq!(
+                arguments.site.accept_status,
+                muffy::DEFAULT_ACCEPTED_STATUS_CODES
+            );
+        }
+
+        #[test]
+        fn parse_base_url() {
+            let Command::CheckDir(arguments) = Arguments::parse_from([
+                "command",
+                "check-dir",
+                "dist",
+                "--base-url",
+                "https://foo.com/bar/",
+                "--experimental-validation",
+            ])
+            .command
+            .unwrap() else {
+                panic!()
+            };
+
+            assert_eq!(arguments.base_url.as_str(), "https://foo.com/bar/");
+            assert!(arguments.site.experimental_validation);
+        }
+    }
+
     mod init {
         use super::*;
         use tempfile::tempdir;
@@ -687,12 +856,12 @@ mod tests {
 
             let external = config.site(&Url::parse("https://foo.com/bar").unwrap());
 
-            assert_eq!(external.cache().max_age(), week);
+            assert_eq!(external.cache().max_age(), Some(week));
             assert_eq!(external.cache().stale_while_revalidate(), week);
 
             let crawled = config.site(&Url::parse("https://example.com/foo").unwrap());
 
-            assert_eq!(crawled.cache().max_age(), Duration::default());
+            assert_eq!(crawled.cache().max_age(), Some(Duration::default()));
             assert_eq!(
                 crawled.cache().stale_while_revalidate(),
                 Duration::default()
@@ -10,6 +10,7 @@ pub struct RateLimiter {
     window_count: AtomicU64,
     time: Instant,
     supply: u64,
+    current_supply: AtomicU64,
     window: Duration,
 }
 
@@ -21,10 +22,27 @@ impl RateLimiter {
             window_count: Default::default(),
             time: Instant::now(),
             supply,
+            current_supply: AtomicU64::new(supply),
             window,
         }
     }
 
+    /// Halves a supply temporarily.
+    ///
+    /// The supply recovers by doubling in every window.
+    pub fn throttle(&self) {
+        let _ = self
+            .current_supply
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |supply| {
+                Some((supply / 2).max(1))
+            });
+
+        self.token_count.fetch_min(
+            self.current_supply.load(Ordering::Relaxed),
+            Ordering::Relaxed,
+        );
+    }
+
     /// Runs a future with rate limiting.
     pub async fn run<T>(&self, future: impl Future<Output = T>) -> T {
         while {
@@ -58,7 +76,14 @@ impl RateLimiter {
                 .compare_exchange(old, new, Ordering::Relaxed, Ordering::Relaxed)
                 .is_ok()
         {
-            self.token_count.store(self.supply, Ordering::Relaxed);
+            let supply = self
+                .current_supply
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |supply| {
+                    Some(supply.saturating_mul(2).min(self.supply))
+                })
+                .unwrap_or(self.supply);
+
+            self.token_count.store(supply, Ordering::Relaxed);
         }
     }
 }
@@ -101,6 +126,54 @@ mod tests {
         assert!(time.elapsed() < duration.mul_f64(1.5));
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn throttle() {
+        const WINDOW: Duration = Duration::from_secs(1);
+
+        let time = Instant::now();
+        let limiter = RateLimiter::new(4, WINDOW);
+
+        limiter.throttle();
+
+        for _ in 0..2 {
+            limiter.run(async {}).await;
+        }
+
+        assert_eq!(time.elapsed(), Duration::ZERO);
+
+        limiter.run(async {}).await;
+
+        assert_eq!(time.elapsed(), WINDOW);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn recover_from_throttle() {
+        const WINDOW: Duration = Duration::from_secs(1);
+
+        let limiter = RateLimiter::new(4, WINDOW);
+
+        limiter.throttle();
+        limiter.throttle();
+
+        advance(WINDOW).await;
+
+        let time = Instant::now();
+
+        for _ in 0..2 {
+            limiter.run(async {}).await;
+        }
+
+        assert_eq!(time.elapsed(), WINDOW);
+
+        let time = Instant::now();
+
+        for _ in 0..4 {
+            limiter.run(async {}).await;
+        }
+
+        assert_eq!(time.elapsed(), WINDOW);
+    }
+
     #[tokio::test(start_paused = true)]
     async fn run_after_many_windows() {
         const WINDOW: Duration = Duration::from_micros(1);
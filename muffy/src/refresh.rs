@@ -0,0 +1,112 @@
+/// Parses a URL in a `content` attribute of a `<meta http-equiv="refresh">`
+/// element.
+///
+/// It follows the [shared declarative refresh steps](https://html.spec.whatwg.org/multipage/document-lifecycle.html#shared-declarative-refresh-steps)
+/// in the HTML specification. It returns `None` if the attribute is invalid or
+/// refreshes the document itself.
+pub fn parse(content: &str) -> Option<&str> {
+    let rest = skip_whitespace(content);
+    let time = rest.trim_start_matches(|character: char| character.is_ascii_digit());
+
+    if time.len() == rest.len() && !time.starts_with('.') {
+        return None;
+    }
+
+    let rest =
+        time.trim_start_matches(|character: char| character.is_ascii_digit() || character == '.');
+
+    if !rest.starts_with(|character: char| {
+        character.is_ascii_whitespace() || character == ';' || character == ','
+    }) {
+        return None;
+    }
+
+    let rest = skip_whitespace(rest);
+    let rest = skip_whitespace(rest.strip_prefix([';', ',']).unwrap_or(rest));
+    let url = parse_url(rest).trim_matches(|character: char| character.is_ascii_whitespace());
+
+    (!url.is_empty()).then_some(url)
+}
+
+fn parse_url(source: &str) -> &str {
+    let Some(rest) = strip_prefix_ignore_case(source, "url") else {
+        return unquote(source).unwrap_or(source);
+    };
+    let Some(rest) = skip_whitespace(rest).strip_prefix('=') else {
+        return source;
+    };
+    let rest = skip_whitespace(rest);
+
+    unquote(rest).unwrap_or(rest)
+}
+
+fn unquote(source: &str) -> Option<&str> {
+    let quote = source
+        .chars()
+        .next()
+        .filter(|&quote| quote == '"' || quote == '\'')?;
+    let rest = &source[1..];
+
+    Some(rest.split_once(quote).map_or(rest, |(url, _)| url))
+}
+
+fn strip_prefix_ignore_case<'a>(source: &'a str, prefix: &str) -> Option<&'a str> {
+    source
+        .get(..prefix.len())
+        .filter(|head| head.eq_ignore_ascii_case(prefix))
+        .map(|_| &source[prefix.len()..])
+}
+
+fn skip_whitespace(source: &str) -> &str {
+    source.trim_start_matches(|character: char| character.is_ascii_whitespace())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse_prefixed_url() {
+        assert_eq!(parse("0; url=/foo"), Some("/foo"));
+        assert_eq!(parse("0;url=/foo"), Some("/foo"));
+        assert_eq!(parse("0, url=/foo"), Some("/foo"));
+        assert_eq!(parse("0 url=/foo"), Some("/foo"));
+        assert_eq!(parse("  5 ; URL = /foo  "), Some("/foo"));
+        assert_eq!(parse("1.5; url=/foo"), Some("/foo"));
+        assert_eq!(parse(".5; url=/foo"), Some("/foo"));
+    }
+
+    #[test]
+    fn parse_url_without_prefix() {
+        assert_eq!(parse("0; /foo"), Some("/foo"));
+        assert_eq!(parse("0; https://foo.com/"), Some("https://foo.com/"));
+    }
+
+    #[test]
+    fn parse_quoted_url() {
+        assert_eq!(parse("0; url='/foo'"), Some("/foo"));
+        assert_eq!(parse(r#"0; url="/foo" bar"#), Some("/foo"));
+        assert_eq!(parse("0; '/foo"), Some("/foo"));
+    }
+
+    #[test]
+    fn parse_url_with_prefix_like_path() {
+        assert_eq!(parse("0; urlfoo"), Some("urlfoo"));
+        assert_eq!(parse("0; u/foo"), Some("u/foo"));
+    }
+
+    #[test]
+    fn parse_self_refresh() {
+        assert_eq!(parse("0"), None);
+        assert_eq!(parse("5; "), None);
+        assert_eq!(parse("5; url="), None);
+    }
+
+    #[test]
+    fn parse_invalid_content() {
+        assert_eq!(parse(""), None);
+        assert_eq!(parse("url=/foo"), None);
+        assert_eq!(parse("0x; url=/foo"), None);
+    }
+}
@@ -2,27 +2,111 @@ mod document_output;
 mod element;
 mod element_output;
 mod item_output;
+mod junit;
 mod options;
 mod response;
 mod result;
+mod sarif;
 mod utility;
 
 pub use self::options::{RenderFormat, RenderOptions};
-use self::{document_output::RenderedDocumentOutput, result::RenderedResult};
-use crate::{DocumentOutput, error::Error};
+use self::{
+    document_output::RenderedDocumentOutput, junit::render_junit, result::RenderedResult,
+    sarif::SarifLog,
+};
+use crate::{BaselineEntry, DocumentOutput, error::Error, severity::Severity};
 use colored::Colorize;
 use core::pin::pin;
 use tokio::io::{AsyncWrite, AsyncWriteExt};
 
 /// Renders a result of document validation.
+///
+/// Aggregated formats, such as SARIF and JUnit, are not supported. Use
+/// [`render_documents`] for them instead.
 pub async fn render_document(
     document: &DocumentOutput,
     options: &RenderOptions,
     writer: impl AsyncWrite,
 ) -> Result<(), Error> {
-    let mut document = RenderedDocumentOutput::from(document);
+    if options.format().is_aggregated() {
+        return Err(Error::AggregatedFormat(options.format()));
+    }
+
+    render_streamed_document(document, options, &mut pin!(writer)).await
+}
+
+/// Renders results of document validation at once.
+///
+/// Aggregated formats, such as SARIF and JUnit, render a single report for all
+/// documents while the other formats render documents one by one.
+pub async fn render_documents(
+    documents: &[DocumentOutput],
+    options: &RenderOptions,
+    writer: impl AsyncWrite,
+) -> Result<(), Error> {
     let mut writer = pin!(writer);
 
+    match options.format() {
+        RenderFormat::Text | RenderFormat::Json => {
+            for document in documents {
+                render_streamed_document(document, options, &mut writer).await?;
+            }
+
+            Ok(())
+        }
+        RenderFormat::Sarif => {
+            render_line(
+                &serde_json::to_string(&SarifLog::new(&render_error_documents(documents)))?,
+                &mut writer,
+            )
+            .await
+        }
+        RenderFormat::Junit => {
+            writer.write_all(&render_junit(documents)?).await?;
+
+            Ok(())
+        }
+    }
+}
+
+/// Renders baseline entries not found in the current validation.
+///
+/// Entries are rendered as JSON lines in the JSON format and as text lines in
+/// the other formats.
+pub async fn render_stale_entries(
+    entries: impl IntoIterator<Item = &BaselineEntry>,
+    options: &RenderOptions,
+    writer: impl AsyncWrite,
+) -> Result<(), Error> {
+    let mut writer = pin!(writer);
+
+    for entry in entries {
+        let line = if options.format() == RenderFormat::Json {
+            serde_json::to_string(entry)?
+        } else {
+            format!(
+                "{}\t{}\t{}\t{}\t{}",
+                "stale baseline entry:".yellow(),
+                entry.url(),
+                entry.element(),
+                entry.code(),
+                entry.error()
+            )
+        };
+
+        render_line(&line, &mut writer).await?;
+    }
+
+    Ok(())
+}
+
+async fn render_streamed_document(
+    document: &DocumentOutput,
+    options: &RenderOptions,
+    writer: &mut (impl AsyncWrite + Unpin),
+) -> Result<(), Error> {
+    let mut document = RenderedDocumentOutput::from(document);
+
     if !options.verbose() {
         document.retain_error();
     }
@@ -36,15 +120,20 @@ pub async fn render_document(
     }
 
     if options.format() == RenderFormat::Json {
-        return render_json_document(&document, &mut writer).await;
+        return render_json_document(&document, writer).await;
     }
 
-    render_line(&format!("{}", document.url().yellow()), &mut writer).await?;
+    render_line(&format!("{}", document.url().yellow()), writer).await?;
 
     for output in document.elements() {
         render_line(
             &format!(
-                "\t{} {}",
+                "\t{}{} {}",
+                output
+                    .element()
+                    .span()
+                    .map(|span| format!("{}:{} ", span.start().line(), span.start().column()))
+                    .unwrap_or_default(),
                 output.element().name(),
                 output
                     .element()
@@ -54,7 +143,7 @@ pub async fn render_document(
                     .collect::<Vec<_>>()
                     .join(" "),
             ),
-            &mut writer,
+            writer,
         )
         .await?;
 
@@ -76,12 +165,20 @@ pub async fn render_document(
                                 },
                             )
                         ),
-                        &mut writer,
+                        writer,
                     )
                     .await?
                 }
                 Err(error) => {
-                    render_line(&format!("\t\t❌ {}", error.to_string().red()), &mut writer).await?
+                    render_line(
+                        &if result.severity() == Some(Severity::Warning) {
+                            format!("\t\t⚠️ {}", error.to_string().yellow())
+                        } else {
+                            format!("\t\t❌ {}", error.to_string().red())
+                        },
+                        writer,
+                    )
+                    .await?
                 }
             }
         }
@@ -90,6 +187,17 @@ pub async fn render_document(
     Ok(())
 }
 
+fn render_error_documents(documents: &[DocumentOutput]) -> Vec<RenderedDocumentOutput<'_>> {
+    documents
+        .iter()
+        .map(|document| {
+            let mut document = RenderedDocumentOutput::from(document);
+            document.retain_error();
+            document
+        })
+        .collect()
+}
+
 pub async fn render_json_document(
     document: &RenderedDocumentOutput<'_>,
     writer: &mut (impl AsyncWrite + Unpin),
@@ -108,11 +216,12 @@ async fn render_line(string: &str, writer: &mut (impl AsyncWrite + Unpin)) -> Re
 mod tests {
     use super::*;
     use crate::{
-        element::Element, element_output::ElementOutput, error::ItemError, item_output::ItemOutput,
-        response::Response,
+        Baseline, element::Element, element_output::ElementOutput, error::ItemError,
+        item_output::ItemOutput, response::Response,
     };
     use core::str;
     use insta::assert_snapshot;
+    use muffy_document::document::{Position, Span};
     use muffy_validation::{AttributeError, ChildError, MarkupError};
     use url::Url;
 
@@ -120,7 +229,8 @@ mod tests {
         DocumentOutput::new(
             Url::parse("https://foo.com").unwrap(),
             vec![ElementOutput::new(
-                Element::new("foo".into(), vec![]),
+                Element::new("foo".into(), vec![])
+                    .set_span(Some(Span::new(Position::new(1, 2), Position::new(3, 4)))),
                 vec![
                     Ok(ItemOutput::default().with_response(
                         Response::new(
@@ -164,6 +274,27 @@ mod tests {
         )
     }
 
+    fn warning_document_output() -> DocumentOutput {
+        let mut document = DocumentOutput::new(
+            Url::parse("https://foo.com").unwrap(),
+            vec![ElementOutput::new(
+                Element::new("foo".into(), vec![])
+                    .set_span(Some(Span::new(Position::new(1, 2), Position::new(3, 4)))),
+                vec![
+                    Err(ItemError::Markup(MarkupError::UnknownTag("foo".into()))),
+                    Err(ItemError::DuplicateId("bar".into())),
+                ],
+            )],
+        );
+
+        document.apply_severities(|error| match error {
+            ItemError::DuplicateId(_) => Severity::Warning,
+            _ => Severity::Error,
+        });
+
+        document
+    }
+
     fn data_document_output() -> DocumentOutput {
         DocumentOutput::new(
             Url::parse(
@@ -190,9 +321,45 @@ mod tests {
         )
     }
 
+    fn stale_baseline() -> Baseline {
+        let mut baseline = Baseline::new();
+        baseline.insert_document(&warning_document_output());
+        baseline
+    }
+
     mod json {
         use super::*;
 
+        #[tokio::test]
+        async fn render_stale_baseline_entries() {
+            let mut string = vec![];
+
+            render_stale_entries(
+                stale_baseline().stale_entries(&Baseline::new()),
+                &RenderOptions::default().set_format(RenderFormat::Json),
+                &mut string,
+            )
+            .await
+            .unwrap();
+
+            assert_snapshot!(str::from_utf8(&string).unwrap());
+        }
+
+        #[tokio::test]
+        async fn render_warning() {
+            let mut string = vec![];
+
+            render_document(
+                &warning_document_output(),
+                &RenderOptions::default().set_format(RenderFormat::Json),
+                &mut string,
+            )
+            .await
+            .unwrap();
+
+            assert_snapshot!(str::from_utf8(&string).unwrap());
+        }
+
         #[tokio::test]
         async fn render_error() {
             let mut string = vec![];
@@ -275,9 +442,178 @@ mod tests {
         }
     }
 
+    mod junit {
+        use super::*;
+
+        #[tokio::test]
+        async fn render_warning() {
+            let mut string = vec![];
+
+            super::render_documents(
+                &[warning_document_output()],
+                &RenderOptions::default().set_format(RenderFormat::Junit),
+                &mut string,
+            )
+            .await
+            .unwrap();
+
+            assert_snapshot!(str::from_utf8(&string).unwrap());
+        }
+
+        #[tokio::test]
+        async fn render_documents() {
+            let mut string = vec![];
+
+            super::render_documents(
+                &[
+                    mixed_document_output(),
+                    successful_document_output().set_site_id(Some("main".into())),
+                    data_document_output(),
+                ],
+                &RenderOptions::default().set_format(RenderFormat::Junit),
+                &mut string,
+            )
+            .await
+            .unwrap();
+
+            assert_snapshot!(str::from_utf8(&string).unwrap());
+        }
+
+        #[tokio::test]
+        async fn render_sites() {
+            let mut string = vec![];
+
+            super::render_documents(
+                &[
+                    mixed_document_output().set_site_id(Some("foo".into())),
+                    successful_document_output().set_site_id(Some("bar".into())),
+                    data_document_output().set_site_id(Some("foo".into())),
+                ],
+                &RenderOptions::default().set_format(RenderFormat::Junit),
+                &mut string,
+            )
+            .await
+            .unwrap();
+
+            assert_snapshot!(str::from_utf8(&string).unwrap());
+        }
+
+        #[tokio::test]
+        async fn render_no_document() {
+            let mut string = vec![];
+
+            super::render_documents(
+                &[],
+                &RenderOptions::default().set_format(RenderFormat::Junit),
+                &mut string,
+            )
+            .await
+            .unwrap();
+
+            assert_snapshot!(str::from_utf8(&string).unwrap());
+        }
+    }
+
+    mod sarif {
+        use super::*;
+
+        #[tokio::test]
+        async fn render_warning() {
+            let mut string = vec![];
+
+            render_documents(
+                &[warning_document_output()],
+                &RenderOptions::default().set_format(RenderFormat::Sarif),
+                &mut string,
+            )
+            .await
+            .unwrap();
+
+            assert_snapshot!(str::from_utf8(&string).unwrap());
+        }
+
+        #[tokio::test]
+        async fn render_errors() {
+            let mut string = vec![];
+
+            render_documents(
+                &[mixed_document_output(), data_document_output()],
+                &RenderOptions::default().set_format(RenderFormat::Sarif),
+                &mut string,
+            )
+            .await
+            .unwrap();
+
+            assert_snapshot!(str::from_utf8(&string).unwrap());
+        }
+
+        #[tokio::test]
+        async fn render_success() {
+            let mut string = vec![];
+
+            render_documents(
+                &[successful_document_output()],
+                &RenderOptions::default().set_format(RenderFormat::Sarif),
+                &mut string,
+            )
+            .await
+            .unwrap();
+
+            assert_snapshot!(str::from_utf8(&string).unwrap());
+        }
+
+        #[tokio::test]
+        async fn reject_single_document() {
+            let mut string = vec![];
+
+            assert!(matches!(
+                render_document(
+                    &mixed_document_output(),
+                    &RenderOptions::default().set_format(RenderFormat::Sarif),
+                    &mut string,
+                )
+                .await,
+                Err(Error::AggregatedFormat(RenderFormat::Sarif))
+            ));
+            assert!(string.is_empty());
+        }
+    }
+
     mod text {
         use super::*;
 
+        #[tokio::test]
+        async fn render_stale_baseline_entries() {
+            colored::control::set_override(false);
+            let mut string = vec![];
+
+            render_stale_entries(
+                stale_baseline().stale_entries(&Baseline::new()),
+                &RenderOptions::default(),
+                &mut string,
+            )
+            .await
+            .unwrap();
+
+            assert_snapshot!(str::from_utf8(&string).unwrap());
+        }
+
+        #[tokio::test]
+        async fn render_warning() {
+            colored::control::set_override(false);
+            let mut string = vec![];
+
+            render_document(
+                &warning_document_output(),
+                &RenderOptions::default(),
+                &mut string,
+            )
+            .await
+            .unwrap();
+
+            assert_snapshot!(str::from_utf8(&string).unwrap());
+        }
+
         #[tokio::test]
         async fn render_error() {
             colored::control::set_override(false);
@@ -1,12 +1,14 @@
 use super::utility::truncate_url;
 use crate::element::Element;
 use alloc::borrow::Cow;
+use muffy_document::document::Span;
 use serde::Serialize;
 
 #[derive(Debug, Serialize)]
 pub struct RenderedElement<'a> {
     name: &'a str,
     attributes: Vec<(&'a str, Cow<'a, str>)>,
+    span: Option<Span>,
 }
 
 impl<'a> RenderedElement<'a> {
@@ -18,6 +20,10 @@ impl<'a> RenderedElement<'a> {
     pub fn attributes(&self) -> &[(&'a str, Cow<'a, str>)] {
         &self.attributes
     }
+
+    pub const fn span(&self) -> Option<Span> {
+        self.span
+    }
 }
 
 impl<'a> From<&'a Element> for RenderedElement<'a> {
@@ -29,6 +35,7 @@ impl<'a> From<&'a Element> for RenderedElement<'a> {
                 .iter()
                 .map(|(name, value)| (name.as_str(), truncate_url(value)))
                 .collect(),
+            span: element.span(),
         }
     }
 }
@@ -31,6 +31,7 @@ impl<'a> From<&'a crate::element_output::ElementOutput> for RenderedElementOutpu
             results: output
                 .results()
                 .map(|result| result.as_ref().map(RenderedItemOutput::from).into())
+                .chain(output.warnings().map(RenderedResult::warning))
                 .collect(),
         }
     }
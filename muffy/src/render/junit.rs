@@ -0,0 +1,173 @@
+use super::{document_output::RenderedDocumentOutput, element_output::RenderedElementOutput};
+use crate::{DocumentOutput, severity::Severity};
+use alloc::collections::BTreeMap;
+use quick_xml::{
+    Writer,
+    events::{BytesDecl, Event},
+};
+use std::io;
+
+const INDENT_SIZE: usize = 2;
+const SUITES_NAME: &str = "muffy";
+
+/// Renders documents into a JUnit XML report.
+///
+/// Documents of each site are nested in a test suite of the site.
+pub fn render_junit(documents: &[DocumentOutput]) -> Result<Vec<u8>, io::Error> {
+    let mut sites = BTreeMap::<_, Vec<_>>::new();
+
+    for document in documents {
+        sites
+            .entry(document.site_id().map(AsRef::as_ref))
+            .or_default()
+            .push(RenderedDocumentOutput::from(document));
+    }
+
+    let documents = sites.values().flatten().collect::<Vec<_>>();
+    let mut writer = Writer::new_with_indent(vec![], b' ', INDENT_SIZE);
+
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+    writer
+        .create_element("testsuites")
+        .with_attributes([
+            ("name", SUITES_NAME),
+            ("tests", &count_tests(documents.iter().copied()).to_string()),
+            (
+                "failures",
+                &count_suite_failures(documents.iter().copied()).to_string(),
+            ),
+        ])
+        .write_inner_content(|writer| {
+            for (site_id, documents) in &sites {
+                if let Some(site_id) = site_id {
+                    render_site_suite(writer, site_id, documents)?;
+                } else {
+                    for document in documents {
+                        render_suite(writer, document)?;
+                    }
+                }
+            }
+
+            Ok(())
+        })?;
+
+    let mut bytes = writer.into_inner();
+    bytes.push(b'\n');
+
+    Ok(bytes)
+}
+
+fn render_site_suite(
+    writer: &mut Writer<Vec<u8>>,
+    site_id: &str,
+    documents: &[RenderedDocumentOutput],
+) -> Result<(), io::Error> {
+    writer
+        .create_element("testsuite")
+        .with_attributes([
+            ("name", site_id),
+            ("tests", &count_tests(documents).to_string()),
+            ("failures", &count_suite_failures(documents).to_string()),
+        ])
+        .write_inner_content(|writer| {
+            for document in documents {
+                render_suite(writer, document)?;
+            }
+
+            Ok(())
+        })?;
+
+    Ok(())
+}
+
+fn render_suite(
+    writer: &mut Writer<Vec<u8>>,
+    document: &RenderedDocumentOutput,
+) -> Result<(), io::Error> {
+    writer
+        .create_element("testsuite")
+        .with_attributes([
+            ("name", document.url()),
+            ("tests", &document.elements().len().to_string()),
+            ("failures", &count_failures(document).to_string()),
+        ])
+        .write_inner_content(|writer| {
+            for output in document.elements() {
+                render_case(writer, document.url(), output)?;
+            }
+
+            Ok(())
+        })?;
+
+    Ok(())
+}
+
+fn render_case(
+    writer: &mut Writer<Vec<u8>>,
+    url: &str,
+    output: &RenderedElementOutput,
+) -> Result<(), io::Error> {
+    let element = output.element();
+    let name = [element.name().into()]
+        .into_iter()
+        .chain(
+            element
+                .attributes()
+                .iter()
+                .map(|(key, value)| format!("{key}=\"{value}\"")),
+        )
+        .collect::<Vec<_>>()
+        .join(" ");
+    let errors = output
+        .results()
+        .filter(|result| result.severity() == Some(Severity::Error))
+        .filter_map(|result| result.result().err())
+        .collect::<Vec<_>>();
+    let case = writer
+        .create_element("testcase")
+        .with_attributes([("name", name.as_str()), ("classname", url)]);
+
+    if errors.is_empty() {
+        case.write_empty()?;
+    } else {
+        case.write_inner_content(|writer| {
+            for error in errors {
+                writer
+                    .create_element("failure")
+                    .with_attributes([
+                        ("message", error.to_string().as_str()),
+                        ("type", error.code()),
+                    ])
+                    .write_empty()?;
+            }
+
+            Ok(())
+        })?;
+    }
+
+    Ok(())
+}
+
+fn count_failures(document: &RenderedDocumentOutput) -> usize {
+    document
+        .elements()
+        .filter(|output| {
+            output
+                .results()
+                .any(|result| result.severity() == Some(Severity::Error))
+        })
+        .count()
+}
+
+fn count_tests<'a>(documents: impl IntoIterator<Item = &'a RenderedDocumentOutput<'a>>) -> usize {
+    documents
+        .into_iter()
+        .map(|document| document.elements().len())
+        .sum()
+}
+
+fn count_suite_failures<'a>(
+    documents: impl IntoIterator<Item = &'a RenderedDocumentOutput<'a>>,
+) -> usize {
+    documents.into_iter().map(count_failures).sum()
+}
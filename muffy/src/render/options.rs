@@ -6,6 +6,17 @@ pub enum RenderFormat {
     Text,
     /// JSON.
     Json,
+    /// SARIF 2.1.0 for a whole run.
+    Sarif,
+    /// JUnit XML for a whole run.
+    Junit,
+}
+
+impl RenderFormat {
+    /// Returns whether a format renders all documents at once.
+    pub const fn is_aggregated(self) -> bool {
+        matches!(self, Self::Sarif | Self::Junit)
+    }
 }
 
 /// Rendering options.
@@ -1,3 +1,4 @@
+use crate::severity::Severity;
 use serde::Serialize;
 
 #[derive(Debug, Serialize)]
@@ -19,16 +20,33 @@ impl<T, E> RenderedResult<T, E> {
     pub const fn result(&self) -> Result<&T, &E> {
         match &self {
             Self::Ok(value) => Ok(value),
-            Self::Err(RenderedError { error }) => Err(error),
+            Self::Err(RenderedError { error, .. }) => Err(error),
         }
     }
+
+    pub const fn severity(&self) -> Option<Severity> {
+        match &self {
+            Self::Ok(_) => None,
+            Self::Err(RenderedError { severity, .. }) => Some(*severity),
+        }
+    }
+
+    pub const fn warning(error: E) -> Self {
+        Self::Err(RenderedError {
+            error,
+            severity: Severity::Warning,
+        })
+    }
 }
 
 impl<T, E> From<Result<T, E>> for RenderedResult<T, E> {
     fn from(result: Result<T, E>) -> Self {
         match result {
             Ok(value) => Self::Ok(value),
-            Err(error) => Self::Err(RenderedError { error }),
+            Err(error) => Self::Err(RenderedError {
+                error,
+                severity: Severity::Error,
+            }),
         }
     }
 }
@@ -36,4 +54,5 @@ impl<T, E> From<Result<T, E>> for RenderedResult<T, E> {
 #[derive(Debug, Serialize)]
 pub(crate) struct RenderedError<E> {
     error: E,
+    severity: Severity,
 }
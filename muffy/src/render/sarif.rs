@@ -0,0 +1,183 @@
+use super::{document_output::RenderedDocumentOutput, element::RenderedElement};
+use crate::{error::ItemError, severity::Severity};
+use alloc::borrow::Cow;
+use itertools::Itertools;
+use serde::Serialize;
+
+const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const VERSION: &str = "2.1.0";
+const TOOL_NAME: &str = "muffy";
+const TOOL_URI: &str = "https://github.com/raviqqe/muffy";
+const ELEMENT_KIND: &str = "element";
+const ERROR_LEVEL: &str = "error";
+const WARNING_LEVEL: &str = "warning";
+
+#[derive(Debug, Serialize)]
+pub struct SarifLog<'a> {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: [SarifRun<'a>; 1],
+}
+
+impl<'a> SarifLog<'a> {
+    pub fn new(documents: &'a [RenderedDocumentOutput<'_>]) -> Self {
+        let errors = documents
+            .iter()
+            .flat_map(|document| {
+                document.elements().flat_map(move |output| {
+                    output
+                        .results()
+                        .filter_map(|result| Some((result.result().err()?, result.severity()?)))
+                        .map(move |(error, severity)| {
+                            (document.url(), output.element(), *error, severity)
+                        })
+                })
+            })
+            .collect::<Vec<_>>();
+        let rules = errors
+            .iter()
+            .map(|(_, _, error, _)| error.code())
+            .unique()
+            .sorted()
+            .collect::<Vec<_>>();
+
+        Self {
+            schema: SCHEMA,
+            version: VERSION,
+            runs: [SarifRun {
+                tool: SarifTool {
+                    driver: SarifDriver {
+                        name: TOOL_NAME,
+                        information_uri: TOOL_URI,
+                        rules: rules.iter().map(|&id| SarifRule { id }).collect(),
+                    },
+                },
+                results: errors
+                    .into_iter()
+                    .map(|(url, element, error, severity)| {
+                        SarifResult::new(
+                            url,
+                            element,
+                            error,
+                            severity,
+                            rules.binary_search(&error.code()).unwrap(),
+                        )
+                    })
+                    .collect(),
+            }],
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct SarifRun<'a> {
+    tool: SarifTool,
+    results: Vec<SarifResult<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifTool {
+    driver: SarifDriver,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver {
+    name: &'static str,
+    information_uri: &'static str,
+    rules: Vec<SarifRule>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifRule {
+    id: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult<'a> {
+    rule_id: &'static str,
+    rule_index: usize,
+    level: &'static str,
+    message: SarifMessage,
+    locations: [SarifLocation<'a>; 1],
+}
+
+impl<'a> SarifResult<'a> {
+    fn new(
+        url: &'a str,
+        element: &'a RenderedElement<'_>,
+        error: &ItemError,
+        severity: Severity,
+        rule_index: usize,
+    ) -> Self {
+        Self {
+            rule_id: error.code(),
+            rule_index,
+            level: if severity == Severity::Warning {
+                WARNING_LEVEL
+            } else {
+                ERROR_LEVEL
+            },
+            message: SarifMessage {
+                text: error.to_string(),
+            },
+            locations: [SarifLocation {
+                physical_location: SarifPhysicalLocation {
+                    artifact_location: SarifArtifactLocation { uri: url.into() },
+                    region: element.span().map(|span| SarifRegion {
+                        start_line: span.start().line(),
+                        start_column: span.start().column(),
+                        end_line: span.end().line(),
+                        end_column: span.end().column(),
+                    }),
+                },
+                logical_locations: [SarifLogicalLocation {
+                    name: element.name(),
+                    kind: ELEMENT_KIND,
+                }],
+            }],
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct SarifMessage {
+    text: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation<'a> {
+    physical_location: SarifPhysicalLocation<'a>,
+    logical_locations: [SarifLogicalLocation<'a>; 1],
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation<'a> {
+    artifact_location: SarifArtifactLocation<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<SarifRegion>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifArtifactLocation<'a> {
+    uri: Cow<'a, str>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifLogicalLocation<'a> {
+    name: &'a str,
+    kind: &'static str,
+}
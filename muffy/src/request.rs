@@ -1,17 +1,23 @@
-use crate::{RetryConfig, http_client::BareRequest};
+use crate::{CacheMode, ProxyConfig, RetryConfig, http_client::BareRequest, response::Response};
 use alloc::sync::Arc;
 use core::time::Duration;
 use http::{
-    HeaderMap,
-    header::{AUTHORIZATION, COOKIE, PROXY_AUTHORIZATION},
+    HeaderMap, Method,
+    header::{
+        AUTHORIZATION, COOKIE, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED,
+        PROXY_AUTHORIZATION,
+    },
 };
 use url::Url;
 
 #[derive(Clone, Debug)]
 pub struct Request {
     bare: BareRequest,
-    max_age: Duration,
+    cache_mode: CacheMode,
+    max_age: Option<Duration>,
     max_redirects: usize,
+    method: Method,
+    min_age: Duration,
     retry: Arc<RetryConfig>,
     site_id: Option<Arc<str>>,
     stale_while_revalidate: Duration,
@@ -21,10 +27,17 @@ pub struct Request {
 impl Request {
     pub fn new(url: Url, headers: HeaderMap) -> Self {
         Self {
-            bare: BareRequest { url, headers },
+            bare: BareRequest {
+                url,
+                headers,
+                proxy: None,
+            },
             site_id: None,
+            cache_mode: Default::default(),
             max_age: Default::default(),
             max_redirects: Default::default(),
+            method: Method::GET,
+            min_age: Default::default(),
             retry: Default::default(),
             stale_while_revalidate: Default::default(),
             timeout: Default::default(),
@@ -43,6 +56,20 @@ impl Request {
         self.max_redirects
     }
 
+    pub const fn method(&self) -> &Method {
+        &self.method
+    }
+
+    pub fn cache_key(&self) -> String {
+        // Keys of GET requests are their URLs for compatibility with existing
+        // caches.
+        if self.method == Method::GET {
+            self.url().to_string()
+        } else {
+            format!("{} {}", self.method, self.url())
+        }
+    }
+
     pub fn site_id(&self) -> Option<&str> {
         self.site_id.as_deref()
     }
@@ -51,10 +78,18 @@ impl Request {
         self.timeout.unwrap_or(Duration::MAX)
     }
 
-    pub const fn max_age(&self) -> Duration {
+    pub const fn cache_mode(&self) -> CacheMode {
+        self.cache_mode
+    }
+
+    pub const fn max_age(&self) -> Option<Duration> {
         self.max_age
     }
 
+    pub const fn min_age(&self) -> Duration {
+        self.min_age
+    }
+
     pub const fn stale_while_revalidate(&self) -> Duration {
         self.stale_while_revalidate
     }
@@ -63,11 +98,21 @@ impl Request {
         &self.retry
     }
 
-    pub const fn set_max_age(mut self, max_age: Duration) -> Self {
+    pub const fn set_cache_mode(mut self, mode: CacheMode) -> Self {
+        self.cache_mode = mode;
+        self
+    }
+
+    pub const fn set_max_age(mut self, max_age: Option<Duration>) -> Self {
         self.max_age = max_age;
         self
     }
 
+    pub const fn set_min_age(mut self, min_age: Duration) -> Self {
+        self.min_age = min_age;
+        self
+    }
+
     pub const fn set_max_redirects(mut self, max_redirects: usize) -> Self {
         self.max_redirects = max_redirects;
         self
@@ -78,6 +123,16 @@ impl Request {
         self
     }
 
+    pub fn set_method(mut self, method: Method) -> Self {
+        self.method = method;
+        self
+    }
+
+    pub fn set_proxy(mut self, proxy: Option<Arc<ProxyConfig>>) -> Self {
+        self.bare.proxy = proxy;
+        self
+    }
+
     pub fn set_retry(mut self, config: Arc<RetryConfig>) -> Self {
         self.retry = config;
         self
@@ -107,12 +162,23 @@ impl Request {
 
         self.set_url(url)
     }
+
+    // Makes a request conditional on validators of a cached response.
+    pub fn revalidate(mut self, response: &Response) -> Self {
+        for (validator, condition) in [(ETAG, IF_NONE_MATCH), (LAST_MODIFIED, IF_MODIFIED_SINCE)] {
+            if let Some(value) = response.headers().get(validator) {
+                self.bare.headers.insert(condition, value.clone());
+            }
+        }
+
+        self
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use http::{HeaderValue, header::ACCEPT};
+    use http::{HeaderValue, StatusCode, header::ACCEPT};
     use pretty_assertions::assert_eq;
 
     fn credentialed_request(url: &str) -> Request {
@@ -169,6 +235,60 @@ mod tests {
         assert_eq!(request.as_bare().headers.get(AUTHORIZATION), None);
     }
 
+    #[test]
+    fn revalidate_with_validators() {
+        let request = Request::new(Url::parse("https://foo.com/").unwrap(), Default::default())
+            .revalidate(&Response::new(
+                Url::parse("https://foo.com/").unwrap(),
+                StatusCode::OK,
+                [
+                    (ETAG, HeaderValue::from_static("\"foo\"")),
+                    (
+                        LAST_MODIFIED,
+                        HeaderValue::from_static("Sun, 06 Nov 1994 08:49:37 GMT"),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+                vec![],
+                Default::default(),
+            ));
+
+        assert_eq!(
+            request.as_bare().headers.get(IF_NONE_MATCH),
+            Some(&HeaderValue::from_static("\"foo\""))
+        );
+        assert_eq!(
+            request.as_bare().headers.get(IF_MODIFIED_SINCE),
+            Some(&HeaderValue::from_static("Sun, 06 Nov 1994 08:49:37 GMT"))
+        );
+    }
+
+    #[test]
+    fn revalidate_without_validators() {
+        let request = Request::new(Url::parse("https://foo.com/").unwrap(), Default::default())
+            .revalidate(&Response::new(
+                Url::parse("https://foo.com/").unwrap(),
+                StatusCode::OK,
+                Default::default(),
+                vec![],
+                Default::default(),
+            ));
+
+        assert!(request.as_bare().headers.is_empty());
+    }
+
+    #[test]
+    fn key_cache_by_method() {
+        let request = Request::new(Url::parse("https://foo.com/").unwrap(), Default::default());
+
+        assert_eq!(request.cache_key(), "https://foo.com/");
+        assert_eq!(
+            request.set_method(Method::HEAD).cache_key(),
+            "HEAD https://foo.com/"
+        );
+    }
+
     #[test]
     fn keep_other_header_on_cross_origin_redirect() {
         let request = credentialed_request("https://foo.com/page")
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+/// A policy of HTTP request methods for links.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RequestMethod {
+    /// GET requests for all links.
+    #[default]
+    Get,
+    /// HEAD requests for links whose bodies are not parsed.
+    ///
+    /// GET requests follow only if servers do not support HEAD requests.
+    Head,
+    /// HEAD requests for links whose bodies are not parsed.
+    ///
+    /// GET requests follow if servers do not support HEAD requests or respond
+    /// with status codes not accepted.
+    Auto,
+}
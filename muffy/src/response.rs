@@ -5,11 +5,16 @@ use core::{
 };
 use http::{
     StatusCode,
-    header::{CONTENT_TYPE, HeaderMap},
+    header::{CACHE_CONTROL, CONTENT_TYPE, DATE, EXPIRES, HeaderMap, HeaderValue, RETRY_AFTER},
 };
+use jiff::fmt::rfc2822::DateTimeParser;
 use serde::{Deserialize, Serialize};
+use std::time::SystemTime;
 use url::Url;
 
+// A maximum number of delta seconds in `Cache-Control` headers.
+const MAX_DELTA_SECONDS: u64 = 1 << 31;
+
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Response {
     url: Url,
@@ -84,12 +89,102 @@ impl Response {
     pub const fn duration(&self) -> Duration {
         self.duration
     }
+
+    pub fn retry_after(&self) -> Option<Duration> {
+        let value = str::from_utf8(self.headers.get(RETRY_AFTER)?.as_bytes())
+            .ok()?
+            .trim();
+
+        if let Ok(seconds) = value.parse() {
+            Some(Duration::from_secs(seconds))
+        } else {
+            Some(
+                parse_date(value)?
+                    .duration_since(SystemTime::now())
+                    .unwrap_or_default(),
+            )
+        }
+    }
+
+    // Returns a freshness lifetime from `Cache-Control` or `Expires` headers.
+    //
+    // The `Expires` header is relative to the `Date` header or a given time
+    // when the response is received.
+    pub fn freshness_lifetime(&self, time: SystemTime) -> Option<Duration> {
+        if let Some(age) = self
+            .cache_control_duration("s-maxage")
+            .or_else(|| self.cache_control_duration("max-age"))
+        {
+            return Some(age);
+        } else if self.cache_control("no-cache").is_some() {
+            return Some(Duration::ZERO);
+        }
+
+        let expires = self.headers.get(EXPIRES)?;
+
+        // Invalid dates represent times in the past.
+        Some(
+            parse_header_date(expires)
+                .and_then(|expires| {
+                    expires
+                        .duration_since(
+                            self.headers
+                                .get(DATE)
+                                .and_then(parse_header_date)
+                                .unwrap_or(time),
+                        )
+                        .ok()
+                })
+                .unwrap_or_default(),
+        )
+    }
+
+    pub fn stale_while_revalidate(&self) -> Option<Duration> {
+        self.cache_control_duration("stale-while-revalidate")
+    }
+
+    pub fn is_storable(&self) -> bool {
+        self.cache_control("no-store").is_none()
+    }
+
+    // Returns a value of a `Cache-Control` directive or an empty string for a
+    // directive without any value.
+    fn cache_control(&self, name: &str) -> Option<&str> {
+        self.headers
+            .get_all(CACHE_CONTROL)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .find_map(|directive| {
+                let (key, value) = directive.split_once('=').unwrap_or((directive, ""));
+
+                key.trim()
+                    .eq_ignore_ascii_case(name)
+                    .then(|| value.trim().trim_matches('"'))
+            })
+    }
+
+    // Invalid durations are considered as absent and large ones are capped as
+    // described in RFC 9111 section 1.2.2.
+    fn cache_control_duration(&self, name: &str) -> Option<Duration> {
+        self.cache_control(name)
+            .and_then(|value| value.parse::<u64>().ok())
+            .map(|seconds| Duration::from_secs(seconds.min(MAX_DELTA_SECONDS)))
+    }
+}
+
+fn parse_header_date(value: &HeaderValue) -> Option<SystemTime> {
+    parse_date(value.to_str().ok()?.trim())
+}
+
+fn parse_date(value: &str) -> Option<SystemTime> {
+    Some(DateTimeParser::new().parse_timestamp(value).ok()?.into())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use http::HeaderValue;
+    use jiff::{Timestamp, fmt::rfc2822::DateTimePrinter};
     use pretty_assertions::assert_eq;
 
     fn create_response(headers: HeaderMap) -> Response {
@@ -155,6 +250,213 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_retry_after_in_seconds() {
+        assert_eq!(
+            create_response(HeaderMap::from_iter([(
+                RETRY_AFTER,
+                HeaderValue::from_static("42")
+            )]))
+            .retry_after(),
+            Some(Duration::from_secs(42))
+        );
+    }
+
+    #[test]
+    fn parse_retry_after_in_date() {
+        let time = Timestamp::try_from(SystemTime::now() + Duration::from_secs(60)).unwrap();
+        let duration = create_response(HeaderMap::from_iter([(
+            RETRY_AFTER,
+            HeaderValue::try_from(
+                DateTimePrinter::new()
+                    .timestamp_to_rfc9110_string(&time)
+                    .unwrap(),
+            )
+            .unwrap(),
+        )]))
+        .retry_after()
+        .unwrap();
+
+        assert!(duration > Duration::from_secs(50));
+        assert!(duration <= Duration::from_secs(60));
+    }
+
+    #[test]
+    fn parse_retry_after_in_past_date() {
+        assert_eq!(
+            create_response(HeaderMap::from_iter([(
+                RETRY_AFTER,
+                HeaderValue::from_static("Sun, 06 Nov 1994 08:49:37 GMT")
+            )]))
+            .retry_after(),
+            Some(Duration::ZERO)
+        );
+    }
+
+    #[test]
+    fn parse_no_retry_after() {
+        assert_eq!(create_response(Default::default()).retry_after(), None);
+        assert_eq!(
+            create_response(HeaderMap::from_iter([(
+                RETRY_AFTER,
+                HeaderValue::from_static("foo")
+            )]))
+            .retry_after(),
+            None
+        );
+    }
+
+    #[test]
+    fn parse_freshness_lifetime_from_max_age() {
+        assert_eq!(
+            create_response(HeaderMap::from_iter([(
+                CACHE_CONTROL,
+                HeaderValue::from_static("public, max-age=60")
+            )]))
+            .freshness_lifetime(SystemTime::now()),
+            Some(Duration::from_secs(60))
+        );
+    }
+
+    #[test]
+    fn prefer_shared_max_age() {
+        assert_eq!(
+            create_response(HeaderMap::from_iter([(
+                CACHE_CONTROL,
+                HeaderValue::from_static("max-age=60, S-MaxAge=\"120\"")
+            )]))
+            .freshness_lifetime(SystemTime::now()),
+            Some(Duration::from_secs(120))
+        );
+    }
+
+    #[test]
+    fn cap_freshness_lifetime() {
+        assert_eq!(
+            create_response(HeaderMap::from_iter([(
+                CACHE_CONTROL,
+                HeaderValue::from_static("max-age=18446744073709551615")
+            )]))
+            .freshness_lifetime(SystemTime::now()),
+            Some(Duration::from_secs(MAX_DELTA_SECONDS))
+        );
+    }
+
+    #[test]
+    fn ignore_invalid_max_age() {
+        assert_eq!(
+            create_response(HeaderMap::from_iter([(
+                CACHE_CONTROL,
+                HeaderValue::from_static("max-age=abc")
+            )]))
+            .freshness_lifetime(SystemTime::now()),
+            None
+        );
+    }
+
+    #[test]
+    fn parse_freshness_lifetime_from_multiple_headers() {
+        let mut headers = HeaderMap::new();
+
+        headers.append(EXPIRES, HeaderValue::from_static("0"));
+        headers.append(CACHE_CONTROL, HeaderValue::from_static("public"));
+        headers.append(CACHE_CONTROL, HeaderValue::from_static("max-age=60"));
+
+        assert_eq!(
+            create_response(headers).freshness_lifetime(SystemTime::now()),
+            Some(Duration::from_secs(60))
+        );
+    }
+
+    #[test]
+    fn parse_freshness_lifetime_from_no_cache() {
+        assert_eq!(
+            create_response(HeaderMap::from_iter([(
+                CACHE_CONTROL,
+                HeaderValue::from_static("no-cache")
+            )]))
+            .freshness_lifetime(SystemTime::now()),
+            Some(Duration::ZERO)
+        );
+    }
+
+    #[test]
+    fn parse_freshness_lifetime_from_expires_and_date() {
+        assert_eq!(
+            create_response(HeaderMap::from_iter([
+                (
+                    EXPIRES,
+                    HeaderValue::from_static("Sun, 06 Nov 1994 09:49:37 GMT")
+                ),
+                (
+                    DATE,
+                    HeaderValue::from_static("Sun, 06 Nov 1994 08:49:37 GMT")
+                ),
+            ]))
+            .freshness_lifetime(SystemTime::now()),
+            Some(Duration::from_hours(1))
+        );
+    }
+
+    #[test]
+    fn parse_freshness_lifetime_from_expires() {
+        let time = SystemTime::from(Timestamp::from_second(784_111_777).unwrap());
+
+        assert_eq!(
+            create_response(HeaderMap::from_iter([(
+                EXPIRES,
+                HeaderValue::from_static("Sun, 06 Nov 1994 09:49:37 GMT")
+            )]))
+            .freshness_lifetime(time),
+            Some(Duration::from_hours(1))
+        );
+    }
+
+    #[test]
+    fn parse_freshness_lifetime_from_invalid_expires() {
+        assert_eq!(
+            create_response(HeaderMap::from_iter([(
+                EXPIRES,
+                HeaderValue::from_static("0")
+            )]))
+            .freshness_lifetime(SystemTime::now()),
+            Some(Duration::ZERO)
+        );
+    }
+
+    #[test]
+    fn parse_no_freshness_lifetime() {
+        assert_eq!(
+            create_response(Default::default()).freshness_lifetime(SystemTime::now()),
+            None
+        );
+    }
+
+    #[test]
+    fn parse_stale_while_revalidate() {
+        let response = create_response(HeaderMap::from_iter([(
+            CACHE_CONTROL,
+            HeaderValue::from_static("max-age=60, stale-while-revalidate=30"),
+        )]));
+
+        assert_eq!(
+            response.stale_while_revalidate(),
+            Some(Duration::from_secs(30))
+        );
+        assert!(response.is_storable());
+    }
+
+    #[test]
+    fn parse_no_store() {
+        assert!(
+            !create_response(HeaderMap::from_iter([(
+                CACHE_CONTROL,
+                HeaderValue::from_static("no-store")
+            )]))
+            .is_storable()
+        );
+    }
+
     #[test]
     fn fail_on_invalid_media_type_encoding() {
         assert!(
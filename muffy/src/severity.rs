@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// A severity of validation errors.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// An error that fails validation.
+    #[default]
+    Error,
+    /// A warning that is reported but does not fail validation.
+    Warning,
+    /// An ignored error.
+    Off,
+}
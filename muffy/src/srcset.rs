@@ -0,0 +1,410 @@
+mod error;
+
+pub use self::error::SrcsetError;
+use alloc::collections::BTreeSet;
+use core::fmt::{self, Display, Formatter};
+
+/// An image candidate in a `srcset` attribute.
+///
+/// An empty candidate between commas has an empty URL.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct Candidate<'a> {
+    url: &'a str,
+    descriptors: Vec<&'a str>,
+}
+
+impl<'a> Candidate<'a> {
+    /// Returns a URL.
+    pub const fn url(&self) -> &'a str {
+        self.url
+    }
+
+    /// Returns descriptors.
+    pub fn descriptors(&self) -> &[&'a str] {
+        &self.descriptors
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Descriptor {
+    Density(f64),
+    Width(u64),
+}
+
+impl Display for Descriptor {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Density(density) => write!(formatter, "{density}x"),
+            Self::Width(width) => write!(formatter, "{width}w"),
+        }
+    }
+}
+
+/// Parses image candidates in a `srcset` attribute.
+///
+/// It follows the algorithm of [parsing a `srcset` attribute](https://html.spec.whatwg.org/multipage/images.html#parsing-a-srcset-attribute)
+/// in the HTML specification without validating descriptors.
+pub fn parse(srcset: &str) -> Vec<Candidate<'_>> {
+    let mut candidates = vec![];
+    let mut rest = srcset;
+    // Whether a candidate is expected at the start or after a comma.
+    let mut expected = true;
+
+    loop {
+        rest = rest.trim_start_matches(|character: char| character.is_ascii_whitespace());
+
+        if let Some(next) = rest.strip_prefix(',') {
+            if expected {
+                candidates.push(Candidate::default());
+            }
+
+            expected = true;
+            rest = next;
+            continue;
+        } else if rest.is_empty() {
+            if expected {
+                candidates.push(Candidate::default());
+            }
+
+            break;
+        }
+
+        let token = &rest[..rest
+            .find(|character: char| character.is_ascii_whitespace())
+            .unwrap_or(rest.len())];
+        let url = token.trim_end_matches(',');
+
+        rest = &rest[token.len()..];
+
+        if url.len() < token.len() {
+            candidates.push(Candidate {
+                url,
+                descriptors: vec![],
+            });
+            candidates.extend((1..token.len() - url.len()).map(|_| Candidate::default()));
+            expected = true;
+            continue;
+        }
+
+        let (descriptors, next) = tokenize_descriptors(rest);
+
+        candidates.push(Candidate { url, descriptors });
+        expected = next.is_some();
+        rest = next.unwrap_or_default();
+    }
+
+    candidates
+}
+
+// Splits descriptors until a comma outside parentheses, and returns them with
+// the rest after the comma if any.
+fn tokenize_descriptors(source: &str) -> (Vec<&str>, Option<&str>) {
+    let mut descriptors = vec![];
+    let mut start = None;
+    let mut parenthesized = false;
+
+    for (index, character) in source.char_indices() {
+        if parenthesized {
+            parenthesized = character != ')';
+        } else if character.is_ascii_whitespace() || character == ',' {
+            if let Some(start) = start.take() {
+                descriptors.push(&source[start..index]);
+            }
+
+            if character == ',' {
+                return (descriptors, Some(&source[index + 1..]));
+            }
+        } else {
+            start.get_or_insert(index);
+            parenthesized = character == '(';
+        }
+    }
+
+    if let Some(start) = start {
+        descriptors.push(&source[start..]);
+    }
+
+    (descriptors, None)
+}
+
+/// Validates a `srcset` attribute and a `sizes` attribute of an image.
+pub fn validate(srcset: &str, sizes: Option<&str>) -> BTreeSet<SrcsetError> {
+    let mut errors = BTreeSet::new();
+    let mut descriptors = Vec::<Descriptor>::new();
+
+    for candidate in parse(srcset) {
+        let descriptor = match candidate.descriptors() {
+            _ if candidate.url().is_empty() => {
+                errors.insert(SrcsetError::EmptyCandidate);
+                continue;
+            }
+            [] => Descriptor::Density(1.0),
+            [descriptor] => {
+                if let Some(descriptor) = parse_descriptor(descriptor) {
+                    descriptor
+                } else {
+                    errors.insert(SrcsetError::InvalidDescriptor((*descriptor).into()));
+                    continue;
+                }
+            }
+            descriptors => {
+                errors.insert(SrcsetError::InvalidDescriptor(descriptors.join(" ")));
+                continue;
+            }
+        };
+
+        if descriptors.contains(&descriptor) {
+            errors.insert(SrcsetError::DuplicateDescriptor(descriptor.to_string()));
+        } else {
+            descriptors.push(descriptor);
+        }
+    }
+
+    let width = descriptors
+        .iter()
+        .any(|descriptor| matches!(descriptor, Descriptor::Width(_)));
+
+    if width
+        && descriptors
+            .iter()
+            .any(|descriptor| matches!(descriptor, Descriptor::Density(_)))
+    {
+        errors.insert(SrcsetError::MixedDescriptors);
+    }
+
+    if let Some(sizes) = sizes {
+        if !muffy_css::is_source_size_list(sizes) {
+            errors.insert(SrcsetError::InvalidSizes);
+        }
+    } else if width {
+        errors.insert(SrcsetError::MissingSizes);
+    }
+
+    errors
+}
+
+fn parse_descriptor(descriptor: &str) -> Option<Descriptor> {
+    if let Some(width) = descriptor.strip_suffix('w') {
+        is_digits(width)
+            .then(|| width.parse().ok())
+            .flatten()
+            .filter(|&width| width > 0)
+            .map(Descriptor::Width)
+    } else if let Some(density) = descriptor.strip_suffix('x') {
+        is_float(density)
+            .then(|| density.parse().ok())
+            .flatten()
+            .filter(|&density| density > 0.0)
+            .map(Descriptor::Density)
+    } else {
+        None
+    }
+}
+
+// Checks if a string is a valid floating-point number in HTML.
+fn is_float(source: &str) -> bool {
+    let source = source.strip_prefix('-').unwrap_or(source);
+    let (mantissa, exponent) = source
+        .split_once(['e', 'E'])
+        .map_or((source, None), |(mantissa, exponent)| {
+            (mantissa, Some(exponent))
+        });
+    let (integer, fraction) = mantissa
+        .split_once('.')
+        .map_or((mantissa, None), |(integer, fraction)| {
+            (integer, Some(fraction))
+        });
+
+    (is_digits(integer) || integer.is_empty() && fraction.is_some())
+        && fraction.is_none_or(is_digits)
+        && exponent
+            .is_none_or(|exponent| is_digits(exponent.strip_prefix(['-', '+']).unwrap_or(exponent)))
+}
+
+fn is_digits(source: &str) -> bool {
+    !source.is_empty() && source.bytes().all(|byte| byte.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn urls(srcset: &str) -> Vec<&str> {
+        parse(srcset)
+            .into_iter()
+            .map(|candidate| candidate.url())
+            .filter(|url| !url.is_empty())
+            .collect()
+    }
+
+    fn errors(srcset: &str, sizes: Option<&str>) -> Vec<SrcsetError> {
+        validate(srcset, sizes).into_iter().collect()
+    }
+
+    #[test]
+    fn parse_url() {
+        assert_eq!(urls("/foo.png"), ["/foo.png"]);
+    }
+
+    #[test]
+    fn parse_url_with_descriptor() {
+        assert_eq!(urls("/foo.png 2x"), ["/foo.png"]);
+    }
+
+    #[test]
+    fn parse_multiple_urls() {
+        assert_eq!(
+            urls("/foo.png, /bar.png 2x, /baz.png 800w"),
+            ["/foo.png", "/bar.png", "/baz.png"]
+        );
+    }
+
+    #[test]
+    fn skip_empty_entry() {
+        assert_eq!(urls("/foo.png,, /bar.png"), ["/foo.png", "/bar.png"]);
+    }
+
+    #[test]
+    fn skip_trailing_comma() {
+        assert_eq!(urls("/foo.png,"), ["/foo.png"]);
+    }
+
+    #[test]
+    fn parse_data_url() {
+        assert_eq!(
+            urls("data:image/png;base64,abc 2x, /bar.png"),
+            ["data:image/png;base64,abc", "/bar.png"]
+        );
+    }
+
+    #[test]
+    fn parse_urls_with_width_descriptors() {
+        assert_eq!(
+            urls("small.jpg 500w, medium.jpg 1000w, large.jpg 1500w"),
+            ["small.jpg", "medium.jpg", "large.jpg"]
+        );
+    }
+
+    #[test]
+    fn parse_descriptors_with_parentheses() {
+        assert_eq!(
+            parse("/foo.png 2x (bar, baz) , /qux.png"),
+            vec![
+                Candidate {
+                    url: "/foo.png",
+                    descriptors: vec!["2x", "(bar, baz)"],
+                },
+                Candidate {
+                    url: "/qux.png",
+                    descriptors: vec![],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_empty_candidates() {
+        assert_eq!(parse(""), vec![Candidate::default()]);
+        assert_eq!(
+            parse(", /foo.png,, /bar.png,"),
+            vec![
+                Candidate::default(),
+                Candidate {
+                    url: "/foo.png",
+                    descriptors: vec![],
+                },
+                Candidate::default(),
+                Candidate {
+                    url: "/bar.png",
+                    descriptors: vec![],
+                },
+                Candidate::default(),
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_density_descriptors() {
+        assert_eq!(errors("/foo.png, /bar.png 1.5x, /baz.png 2x", None), vec![]);
+    }
+
+    #[test]
+    fn validate_width_descriptors() {
+        assert_eq!(
+            errors(
+                "/foo.png 500w, /bar.png 1000w",
+                Some("(max-width: 600px) 480px, 800px")
+            ),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn report_empty_candidates() {
+        assert_eq!(errors("", None), vec![SrcsetError::EmptyCandidate]);
+        assert_eq!(
+            errors("/foo.png,, /bar.png 2x", None),
+            vec![SrcsetError::EmptyCandidate]
+        );
+    }
+
+    #[test]
+    fn report_invalid_descriptors() {
+        assert_eq!(
+            errors(
+                "/foo.png 0w, /bar.png 2.x, /baz.png 1x 2x, /qux.png 100h",
+                Some("100vw")
+            ),
+            vec![
+                SrcsetError::InvalidDescriptor("0w".into()),
+                SrcsetError::InvalidDescriptor("100h".into()),
+                SrcsetError::InvalidDescriptor("1x 2x".into()),
+                SrcsetError::InvalidDescriptor("2.x".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn report_duplicate_descriptors() {
+        assert_eq!(
+            errors("/foo.png, /bar.png 1x, /baz.png 2x, /qux.png 2.0x", None),
+            vec![
+                SrcsetError::DuplicateDescriptor("1x".into()),
+                SrcsetError::DuplicateDescriptor("2x".into()),
+            ]
+        );
+        assert_eq!(
+            errors("/foo.png 500w, /bar.png 500w", Some("100vw")),
+            vec![SrcsetError::DuplicateDescriptor("500w".into())]
+        );
+    }
+
+    #[test]
+    fn report_mixed_descriptors() {
+        assert_eq!(
+            errors("/foo.png 500w, /bar.png 2x", Some("100vw")),
+            vec![SrcsetError::MixedDescriptors]
+        );
+        assert_eq!(
+            errors("/foo.png 500w, /bar.png", Some("100vw")),
+            vec![SrcsetError::MixedDescriptors]
+        );
+    }
+
+    #[test]
+    fn report_missing_sizes() {
+        assert_eq!(
+            errors("/foo.png 500w", None),
+            vec![SrcsetError::MissingSizes]
+        );
+    }
+
+    #[test]
+    fn report_invalid_sizes() {
+        assert_eq!(
+            errors("/foo.png 500w", Some("(max-width 600px) 480px, 800px")),
+            vec![SrcsetError::InvalidSizes]
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use core::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+/// A `srcset` or `sizes` attribute error.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum SrcsetError {
+    /// Image candidates with the same descriptor.
+    DuplicateDescriptor(String),
+    /// An empty image candidate.
+    EmptyCandidate,
+    /// An invalid descriptor.
+    InvalidDescriptor(String),
+    /// An invalid `sizes` attribute.
+    InvalidSizes,
+    /// A missing `sizes` attribute for width descriptors.
+    MissingSizes,
+    /// Width and density descriptors mixed in a `srcset` attribute.
+    MixedDescriptors,
+}
+
+impl SrcsetError {
+    /// Codes of all error kinds.
+    pub const CODES: &[&str] = &[
+        "srcset/duplicate-descriptor",
+        "srcset/empty-candidate",
+        "srcset/invalid-descriptor",
+        "srcset/invalid-sizes",
+        "srcset/missing-sizes",
+        "srcset/mixed-descriptors",
+    ];
+
+    /// Returns a stable code of an error kind.
+    pub const fn code(&self) -> &'static str {
+        match self {
+            Self::DuplicateDescriptor(_) => "srcset/duplicate-descriptor",
+            Self::EmptyCandidate => "srcset/empty-candidate",
+            Self::InvalidDescriptor(_) => "srcset/invalid-descriptor",
+            Self::InvalidSizes => "srcset/invalid-sizes",
+            Self::MissingSizes => "srcset/missing-sizes",
+            Self::MixedDescriptors => "srcset/mixed-descriptors",
+        }
+    }
+
+    /// Returns a name of an attribute where an error is.
+    pub const fn attribute(&self) -> &'static str {
+        match self {
+            Self::InvalidSizes => "sizes",
+            Self::DuplicateDescriptor(_)
+            | Self::EmptyCandidate
+            | Self::InvalidDescriptor(_)
+            | Self::MissingSizes
+            | Self::MixedDescriptors => "srcset",
+        }
+    }
+}
+
+impl Error for SrcsetError {}
+
+impl Display for SrcsetError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateDescriptor(descriptor) => {
+                write!(formatter, "duplicate descriptor \"{descriptor}\" in srcset")
+            }
+            Self::EmptyCandidate => write!(formatter, "empty image candidate in srcset"),
+            Self::InvalidDescriptor(descriptor) => {
+                write!(formatter, "invalid descriptor \"{descriptor}\" in srcset")
+            }
+            Self::InvalidSizes => write!(formatter, "invalid source sizes"),
+            Self::MissingSizes => write!(formatter, "missing sizes for width descriptors"),
+            Self::MixedDescriptors => {
+                write!(formatter, "mixed width and density descriptors in srcset")
+            }
+        }
+    }
+}
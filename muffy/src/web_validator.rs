@@ -1,8 +1,16 @@
 mod context;
-
-use self::context::Context;
+mod document_context;
+mod suppression;
+mod xml_language;
+
+use self::{
+    context::Context,
+    document_context::DocumentContext,
+    suppression::{LineSuppressions, Suppression},
+    xml_language::XmlLanguage,
+};
 use crate::{
-    config::Config,
+    config::{Config, MarkupConfig, SchemaConfig, SiteConfig, ValidationConfig},
     document_output::DocumentOutput,
     document_parser::DocumentParser,
     document_type::DocumentType,
@@ -11,28 +19,32 @@ use crate::{
     error::{Error, ItemError},
     http_client::{HttpClient, ROBOTS_PATH},
     item_output::ItemOutput,
+    refresh,
     request::Request,
+    request_method::RequestMethod,
     response::Response,
     robot_list::RobotList,
-    sitemap,
+    sitemap, srcset,
 };
 use alloc::sync::Arc;
-use core::{iter, str, time::Duration};
+use core::{str, time::Duration};
 use data_url::DataUrl;
 use futures::{Stream, StreamExt, future::try_join_all};
 use http::{
-    HeaderValue, StatusCode,
+    HeaderValue, Method, StatusCode,
     header::{CONTENT_TYPE, HeaderMap},
 };
 use itertools::Itertools;
-use muffy_document::document::{self, Node};
+use muffy_document::document::{self, Node, ParseError, Position, Span};
 use muffy_validation::MarkupError;
-use std::collections::HashMap;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
 use tokio::{spawn, sync::mpsc::channel, task::JoinHandle};
 use tokio_stream::wrappers::ReceiverStream;
 use url::Url;
 
 type ElementFuture = (Element, Vec<JoinHandle<Result<ItemOutput, ItemError>>>);
+type Links<'a> = Vec<(Vec<(&'a str, &'a str)>, Vec<(String, Option<DocumentType>)>)>;
 
 const JOB_CAPACITY: usize = 1 << 16;
 const JOB_COMPLETION_BUFFER: usize = 1 << 8;
@@ -40,11 +52,51 @@ const JOB_COMPLETION_BUFFER: usize = 1 << 8;
 const DATA_SCHEME: &str = "data";
 const DOCUMENT_SCHEMES: &[&str] = &["http", "https"];
 const SVG_MEDIA_TYPE: &str = "image/svg+xml";
+const MATHML_MEDIA_TYPE: &str = "application/mathml+xml";
 const PSEUDO_DOCUMENT_ELEMENT: &str = "#document";
 const FRAGMENT_ATTRIBUTES: &[&str] = &["id", "name", "xml:id"];
 const HREF_ATTRIBUTES: &[&str] = &["href", "xlink:href"];
+const ID_ATTRIBUTES: &[&str] = &["id", "xml:id"];
+// SVG `href` attributes are not included as their fragments are validated as
+// links.
+const ID_REFERENCE_ATTRIBUTES: &[&str] = &[
+    "aria-controls",
+    "aria-owns",
+    "commandfor",
+    "for",
+    "form",
+    "headers",
+    "list",
+    "popovertarget",
+];
+const HASH_NAME_REFERENCE_ATTRIBUTES: &[&str] = &["usemap"];
+const SVG_REFERENCE_ATTRIBUTES: &[&str] = &[
+    "clip-path",
+    "fill",
+    "filter",
+    "marker-end",
+    "marker-mid",
+    "marker-start",
+    "mask",
+    "stroke",
+    "style",
+];
 const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
 const SVG_ROOT_ELEMENT: &str = "svg";
+const MATHML_NAMESPACE: &str = "http://www.w3.org/1998/Math/MathML";
+const MATHML_ROOT_ELEMENT: &str = "math";
+const SVG_LANGUAGE: XmlLanguage = XmlLanguage::new(
+    SVG_NAMESPACE,
+    SVG_ROOT_ELEMENT,
+    ValidationConfig::svg,
+    muffy_validation::validate_html_element,
+);
+const MATHML_LANGUAGE: XmlLanguage = XmlLanguage::new(
+    MATHML_NAMESPACE,
+    MATHML_ROOT_ELEMENT,
+    ValidationConfig::mathml,
+    muffy_validation::validate_mathml_element,
+);
 const META_LINK_PROPERTIES: &[&str] = &[
     "og:image",
     "og:audio",
@@ -128,48 +180,13 @@ impl WebValidator {
         document_url.set_fragment(None);
 
         let site = context.config().site(&url);
-        let Some(response) = self
-            .0
-            .http_client
-            .get(
-                &Request::new(document_url, site.headers().clone())
-                    .set_max_age(site.cache().max_age())
-                    .set_max_redirects(site.max_redirects())
-                    .set_retry(site.retry().clone())
-                    .set_site_id(site.id().cloned())
-                    .set_stale_while_revalidate(site.cache().stale_while_revalidate())
-                    .set_timeout(site.timeout()),
-            )
-            .await?
-        else {
-            return Ok(ItemOutput::default());
-        };
-
-        if !context
-            .config()
-            .site(&url)
-            .status()
-            .accepted(response.status())
-        {
-            return Err(ItemError::HttpStatus(response.status()));
-        }
-
-        let Some(document_type) = Self::validate_document_type(&response, document_type)? else {
-            return Ok(ItemOutput::new().with_response(response));
-        };
-
-        if let Some(fragment) = url.fragment()
-            && matches!(document_type, DocumentType::Html | DocumentType::Svg)
-            && !site
+        let fragment = url.fragment().filter(|fragment| {
+            !site
                 .ignored_fragments()
                 .iter()
                 .any(|pattern| pattern.is_match(fragment))
-            && !self.has_element(&response, fragment).await?
-        {
-            return Err(ItemError::ElementNotFound(fragment.into()));
-        }
-
-        if url
+        });
+        let recursive = url
             .host_str()
             .map(|host| {
                 context
@@ -183,9 +200,64 @@ impl WebValidator {
                     })
                     .unwrap_or_default()
             })
-            .unwrap_or_default()
-            && context.insert_document(response.url().to_string()).await
+            .unwrap_or_default();
+        let request = Request::new(document_url, site.headers().clone())
+            .set_cache_mode(site.cache().mode())
+            .set_max_age(site.cache().max_age())
+            .set_max_redirects(site.max_redirects())
+            .set_min_age(site.cache().min_age())
+            .set_proxy(site.proxy().cloned())
+            .set_retry(site.retry().clone())
+            .set_site_id(site.id().cloned())
+            .set_stale_while_revalidate(site.cache().stale_while_revalidate())
+            .set_timeout(site.timeout());
+        let Some(response) =
+            (if site.method() == RequestMethod::Get || recursive || fragment.is_some() {
+                self.0.http_client.get(&request).await?
+            } else {
+                match self
+                    .0
+                    .http_client
+                    .get(&request.clone().set_method(Method::HEAD))
+                    .await?
+                {
+                    Some(response) if Self::requires_get(site, &response) => {
+                        self.0.http_client.get(&request).await?
+                    }
+                    response => response,
+                }
+            })
+        else {
+            return Ok(ItemOutput::default());
+        };
+
+        if !site.status().accepted(response.status()) {
+            return Err(ItemError::HttpStatus(response.status()));
+        }
+
+        // Schemas take priority over built-in document types for links of
+        // unknown document types.
+        let document_type =
+            if document_type.is_none() && Self::find_schema(site, &response)?.is_some() {
+                Some(DocumentType::Xml)
+            } else {
+                Self::validate_document_type(&response, document_type)?
+            };
+        let Some(document_type) = document_type else {
+            return Ok(ItemOutput::new().with_response(response));
+        };
+
+        if let Some(fragment) = fragment
+            && matches!(
+                document_type,
+                DocumentType::Html | DocumentType::MathMl | DocumentType::Svg | DocumentType::Xml
+            )
+            && !self.has_element(&response, fragment).await?
         {
+            return Err(ItemError::ElementNotFound(fragment.into()));
+        }
+
+        if recursive && context.insert_document(response.url().to_string()).await {
             let handle = spawn({
                 let context = context.clone();
                 let response = response.clone();
@@ -218,20 +290,34 @@ impl WebValidator {
         let futures = match document_type {
             DocumentType::Css => self.validate_css(&context, &response, &site),
             DocumentType::Html => self.validate_html(&context, &response).await?,
+            DocumentType::MathMl => {
+                self.validate_xml(&context, &response, &site, &MATHML_LANGUAGE)
+                    .await?
+            }
             DocumentType::Robots => self.validate_robots(&context, &response)?,
             DocumentType::Sitemap => self.validate_sitemap(&context, &response),
-            DocumentType::Svg => self.validate_svg(&context, &response, &site).await?,
+            DocumentType::Svg => {
+                self.validate_xml(&context, &response, &site, &SVG_LANGUAGE)
+                    .await?
+            }
+            DocumentType::Xml => self.validate_schema(&context, &response, &site).await?,
         };
         let (elements, futures) = futures.into_iter().unzip::<_, _, Vec<_>, Vec<_>>();
 
-        Ok(DocumentOutput::new(
+        let config = context.config().site(&site);
+        let mut document = DocumentOutput::new(
             response.url().clone(),
             elements
                 .into_iter()
                 .zip(try_join_all(futures.into_iter().map(try_join_all)).await?)
                 .map(|(element, results)| ElementOutput::new(element, results))
                 .collect(),
-        ))
+        )
+        .set_site_id(config.id().cloned());
+
+        document.apply_severities(|error| config.severity(error.code()));
+
+        Ok(document)
     }
 
     async fn validate_element_link(
@@ -278,9 +364,13 @@ impl WebValidator {
 
         let data_url = DataUrl::process(url.as_str())?;
 
-        if !data_url.mime_type().matches("image", "svg+xml") {
+        let (media_type, document_type) = if data_url.mime_type().matches("image", "svg+xml") {
+            (SVG_MEDIA_TYPE, DocumentType::Svg)
+        } else if data_url.mime_type().matches("application", "mathml+xml") {
+            (MATHML_MEDIA_TYPE, DocumentType::MathMl)
+        } else {
             return Ok(ItemOutput::new());
-        }
+        };
 
         let mut document_url = url.clone();
         document_url.set_fragment(None);
@@ -288,7 +378,7 @@ impl WebValidator {
         let response = Arc::new(Response::new(
             document_url,
             StatusCode::OK,
-            HeaderMap::from_iter([(CONTENT_TYPE, HeaderValue::from_static(SVG_MEDIA_TYPE))]),
+            HeaderMap::from_iter([(CONTENT_TYPE, HeaderValue::from_static(media_type))]),
             data_url.decode_to_vec()?.0,
             Duration::default(),
         ));
@@ -311,7 +401,7 @@ impl WebValidator {
                 let response = response.clone();
 
                 async move {
-                    self.validate_document(context, response, site, DocumentType::Svg)
+                    self.validate_document(context, response, site, document_type)
                         .await
                 }
             });
@@ -379,35 +469,70 @@ impl WebValidator {
     ) -> Result<Vec<ElementFuture>, Error> {
         let mut futures = vec![];
         let document = self.0.document_parser.parse(response).await?;
-        let base = document
-            .base()
-            .map(|href| response.url().join(href))
-            .transpose()?
-            .unwrap_or_else(|| response.url().clone())
-            .into();
+        let base = Arc::new(
+            document
+                .base()
+                .map(|href| response.url().join(href))
+                .transpose()?
+                .unwrap_or_else(|| response.url().clone()),
+        );
+
+        if let Some(config) = context.config().site(&base).validation().html() {
+            futures.extend(Self::spawn_syntax_errors(
+                document.errors(),
+                config.ignored_syntax_errors(),
+                ItemError::HtmlSyntax,
+            ));
+        }
+
+        let document_context = DocumentContext::new(
+            base.clone(),
+            base,
+            LineSuppressions::new(document.children()),
+        )
+        .set_defined_ids(Self::collect_ids(document.children(), ID_ATTRIBUTES))
+        .set_defined_fragments(Self::collect_ids(document.children(), FRAGMENT_ATTRIBUTES));
 
         for node in document.children() {
-            self.validate_html_element(context, &base, node, &mut futures)?;
+            self.validate_html_element(
+                context,
+                &document_context,
+                node,
+                &mut vec![],
+                &mut futures,
+            )?;
         }
 
         Ok(futures)
     }
 
-    fn validate_html_element(
+    fn validate_html_element<'a>(
         &self,
         context: &Arc<Context>,
-        base: &Arc<Url>,
-        node: &Node,
+        document_context: &DocumentContext,
+        node: &'a Node,
+        ancestors: &mut Vec<&'a document::Element>,
         futures: &mut Vec<ElementFuture>,
     ) -> Result<(), Error> {
         if let Node::Element(element) = &node {
-            if let Some(future) = self.validate_html_element_content(context, base, element) {
+            if let Some(future) = self.validate_html_element_content(
+                context,
+                document_context,
+                element,
+                ancestors,
+                Suppression::from_element(element)
+                    .merge(document_context.suppressions().get(element)),
+            ) {
                 futures.push(future);
             }
 
+            ancestors.push(element);
+
             for node in element.children() {
-                self.validate_html_element(context, base, node, futures)?;
+                self.validate_html_element(context, document_context, node, ancestors, futures)?;
             }
+
+            ancestors.pop();
         }
 
         Ok(())
@@ -416,14 +541,28 @@ impl WebValidator {
     fn validate_html_element_content(
         &self,
         context: &Arc<Context>,
-        base: &Arc<Url>,
+        document_context: &DocumentContext,
         element: &document::Element,
+        ancestors: &[&document::Element],
+        suppression: Suppression,
     ) -> Option<ElementFuture> {
+        let base = document_context.base();
+        let site = document_context.site();
         let attributes = HashMap::<_, _>::from_iter(element.attributes());
-        let mut links = vec![];
+        let (mut links, style_errors) = Self::parse_inline_styles(element, &attributes);
+        let style_errors = if suppression.markup() {
+            vec![]
+        } else {
+            style_errors
+        };
+        let style_error_attributes = style_errors
+            .iter()
+            .filter_map(|(name, _)| *name)
+            .collect::<Vec<_>>();
+        let mut meta_refresh = false;
 
         match element.name() {
-            "base" => {}
+            "base" | "style" => {}
             "link" => {
                 if !attributes
                     .get("rel")
@@ -453,6 +592,16 @@ impl WebValidator {
                         vec![("property", property), ("content", content)],
                         vec![(content.to_string(), None)],
                     ));
+                } else if let Some(content) = attributes.get("content")
+                    && let Some(equiv) = attributes.get("http-equiv")
+                    && equiv.eq_ignore_ascii_case("refresh")
+                    && let Some(url) = refresh::parse(content)
+                {
+                    meta_refresh = true;
+                    links.push((
+                        vec![("http-equiv", equiv), ("content", content)],
+                        vec![(url.into(), None)],
+                    ));
                 }
             }
             _ => {
@@ -469,7 +618,10 @@ impl WebValidator {
                 if let Some(value) = attributes.get("srcset") {
                     links.push((
                         vec![("srcset", value)],
-                        Self::parse_srcset(value)
+                        srcset::parse(value)
+                            .into_iter()
+                            .map(|candidate| candidate.url())
+                            .filter(|url| !url.is_empty())
                             .map(|url| (url.into(), None))
                             .collect(),
                     ));
@@ -477,16 +629,59 @@ impl WebValidator {
             }
         }
 
-        let validation_result =
-            if let Some(config) = context.config().site(base).validation().html() {
+        let missing_fragments = if suppression.link() {
+            links.clear();
+            vec![]
+        } else {
+            Self::find_missing_svg_fragments(context, document_context, element, &attributes)
+        };
+
+        let validation = context.config().site(site).validation();
+        let meta_refresh = meta_refresh && !suppression.markup() && validation.meta_refresh();
+        // MathML elements in HTML documents are validated against the HTML schema
+        // rather than the standalone MathML one as the former extends them for
+        // HTML, such as HTML phrasing content in `mtext` elements. Only their
+        // options come from the MathML validation configuration.
+        let (validation_result, exclusion_result) = if !suppression.markup()
+            && let Some(config) = if element.namespace() == Some(MATHML_NAMESPACE) {
+                validation.mathml().or(validation.html())
+            } else {
+                validation.html()
+            } {
+            (
                 muffy_validation::validate_html_element(
                     element,
+                    ancestors,
                     config.ignored_attributes(),
                     config.ignored_elements(),
-                )
-            } else {
-                Ok(())
-            };
+                    config.custom_elements(),
+                ),
+                muffy_validation::validate_html_exclusions(
+                    element,
+                    ancestors,
+                    config.ignored_elements(),
+                ),
+            )
+        } else {
+            (Ok(()), Ok(()))
+        };
+
+        let srcset_errors = if !suppression.markup()
+            && validation.html().is_some()
+            && matches!(element.name(), "img" | "source")
+            && let Some(value) = attributes.get("srcset")
+        {
+            srcset::validate(value, attributes.get("sizes").copied())
+        } else {
+            Default::default()
+        };
+        let aria_result = if !suppression.markup() && validation.aria() {
+            muffy_validation::validate_aria_element(element, |id| document_context.has_id(id))
+        } else {
+            Ok(())
+        };
+        let duplicate_ids = Self::find_duplicate_ids(context, document_context, &attributes);
+        let undefined_ids = Self::find_undefined_ids(context, document_context, &attributes);
 
         let mut items = links
             .iter()
@@ -496,17 +691,44 @@ impl WebValidator {
                         context.clone(),
                         link.to_string(),
                         base.clone(),
-                        base.clone(),
+                        site.clone(),
                         *document_type,
                     ))
                 })
             })
             .collect::<Vec<_>>();
 
-        if let Err(error) = &validation_result {
-            items.extend(Self::spawn_markup_errors(error));
+        items.extend(Self::spawn_errors(&missing_fragments, |(_, fragment)| {
+            ItemError::ElementNotFound(fragment.clone())
+        }));
+        items.extend(Self::spawn_errors(style_errors, |(_, error)| error));
+        items.extend(Self::spawn_errors(&srcset_errors, |error| {
+            ItemError::Srcset(error.clone())
+        }));
+
+        if meta_refresh {
+            items.push(spawn(async { Err(ItemError::MetaRefresh) }));
+        }
+
+        for result in [&validation_result, &exclusion_result, &aria_result] {
+            if let Err(error) = result {
+                items.extend(Self::spawn_markup_errors(error));
+            }
+        }
+
+        if !suppression.markup() {
+            items.extend(Self::spawn_errors(&duplicate_ids, |(_, id)| {
+                ItemError::DuplicateId(id.to_string())
+            }));
+            items.extend(Self::spawn_errors(&undefined_ids, |(_, id)| {
+                ItemError::UndefinedId(id.to_string())
+            }));
         }
 
+        items.extend(Self::spawn_errors(suppression.unknown_kinds(), |kind| {
+            ItemError::UnknownSuppressionKind(kind.into())
+        }));
+
         if items.is_empty() {
             None
         } else {
@@ -516,8 +738,14 @@ impl WebValidator {
                     &attributes,
                     links
                         .iter()
-                        .flat_map(|(attributes, _)| attributes.iter().map(|(name, _)| *name)),
-                    &validation_result,
+                        .flat_map(|(attributes, _)| attributes.iter().map(|(name, _)| *name))
+                        .chain(missing_fragments.iter().map(|(name, _)| *name))
+                        .chain(style_error_attributes)
+                        .chain(srcset_errors.iter().map(|error| error.attribute()))
+                        .chain(meta_refresh.then_some("http-equiv"))
+                        .chain(duplicate_ids.iter().map(|(name, _)| *name))
+                        .chain(undefined_ids.iter().map(|(name, _)| *name)),
+                    [&validation_result, &aria_result],
                 ),
                 items,
             ))
@@ -575,52 +803,74 @@ impl WebValidator {
         }
     }
 
-    async fn validate_svg(
+    async fn validate_xml(
         &self,
         context: &Arc<Context>,
         response: &Arc<Response>,
         site: &Arc<Url>,
+        language: &XmlLanguage,
     ) -> Result<Vec<ElementFuture>, Error> {
         let mut futures = vec![];
         let base = Arc::new(response.url().clone());
         let document = self.0.document_parser.parse(response).await?;
 
-        for error in document.errors().unique().sorted() {
-            let error = ItemError::XmlSyntax(error.into());
+        futures.extend(Self::spawn_syntax_errors(
+            document.errors(),
+            language
+                .config(context.config().site(site).validation())
+                .map(MarkupConfig::ignored_syntax_errors)
+                .unwrap_or_default(),
+            ItemError::XmlSyntax,
+        ));
 
-            futures.push((
-                Element::new(PSEUDO_DOCUMENT_ELEMENT.into(), vec![]),
-                vec![spawn(async move { Err(error) })],
-            ));
-        }
+        let document_context = DocumentContext::new(
+            base,
+            site.clone(),
+            LineSuppressions::new(document.children()),
+        )
+        .set_defined_ids(Self::collect_ids(document.children(), ID_ATTRIBUTES))
+        .set_defined_fragments(Self::collect_ids(document.children(), FRAGMENT_ATTRIBUTES));
 
         for node in document.children() {
-            self.validate_svg_element(context, &base, site, node, true, &mut futures);
+            self.validate_xml_element(
+                context,
+                &document_context,
+                language,
+                node,
+                &mut vec![],
+                &mut futures,
+            );
         }
 
         Ok(futures)
     }
 
-    fn validate_svg_element(
+    fn validate_xml_element<'a>(
         &self,
         context: &Arc<Context>,
-        base: &Arc<Url>,
-        site: &Arc<Url>,
-        node: &Node,
-        root: bool,
+        document_context: &DocumentContext,
+        language: &XmlLanguage,
+        node: &'a Node,
+        ancestors: &mut Vec<&'a document::Element>,
         futures: &mut Vec<ElementFuture>,
     ) {
         let Node::Element(element) = node else { return };
 
+        let base = document_context.base();
+        let site = document_context.site();
         let attributes = HashMap::from_iter(element.attributes());
-        let config = context.config().site(site).validation().svg();
+        let suppression =
+            Suppression::from_element(element).merge(document_context.suppressions().get(element));
+        let config = language
+            .config(context.config().site(site).validation())
+            .filter(|_| !suppression.markup());
         let link_attributes = HREF_ATTRIBUTES
             .iter()
             .copied()
-            .filter(|name| attributes.contains_key(name))
+            .filter(|name| !suppression.link() && attributes.contains_key(name))
             .collect::<Vec<_>>();
 
-        let mut items = if root
+        let mut items = if ancestors.is_empty()
             && let Some(config) = config
             && !config
                 .ignored_elements()
@@ -628,13 +878,17 @@ impl WebValidator {
                 .any(|pattern| pattern.is_match(element.name()))
         {
             [
-                (element.namespace() != Some(SVG_NAMESPACE)).then(|| ItemError::InvalidNamespace {
-                    actual: element.namespace().map(Into::into),
-                    expected: SVG_NAMESPACE,
+                (element.namespace() != Some(language.namespace())).then(|| {
+                    ItemError::InvalidNamespace {
+                        actual: element.namespace().map(Into::into),
+                        expected: language.namespace(),
+                    }
                 }),
-                (element.name() != SVG_ROOT_ELEMENT).then(|| ItemError::InvalidRootElement {
-                    actual: element.name().into(),
-                    expected: SVG_ROOT_ELEMENT,
+                (element.name() != language.root_element()).then(|| {
+                    ItemError::InvalidRootElement {
+                        actual: element.name().into(),
+                        expected: language.root_element(),
+                    }
                 }),
             ]
             .into_iter()
@@ -645,6 +899,44 @@ impl WebValidator {
             vec![]
         };
 
+        let missing_fragments = if suppression.link() {
+            vec![]
+        } else {
+            Self::find_missing_svg_fragments(context, document_context, element, &attributes)
+        };
+        let (mut style_links, style_errors) = Self::parse_inline_styles(element, &attributes);
+
+        if suppression.link() {
+            style_links.clear();
+        }
+
+        let style_errors = if suppression.markup() {
+            vec![]
+        } else {
+            style_errors
+        };
+        let style_error_attributes = style_errors
+            .iter()
+            .filter_map(|(name, _)| *name)
+            .collect::<Vec<_>>();
+
+        items.extend(Self::spawn_errors(&missing_fragments, |(_, fragment)| {
+            ItemError::ElementNotFound(fragment.clone())
+        }));
+        items.extend(Self::spawn_errors(style_errors, |(_, error)| error));
+
+        for (_, links) in &style_links {
+            for (link, document_type) in links {
+                items.push(spawn(self.cloned().validate_element_link(
+                    context.clone(),
+                    link.clone(),
+                    base.clone(),
+                    site.clone(),
+                    *document_type,
+                )));
+            }
+        }
+
         for name in &link_attributes {
             if let Some(value) = attributes.get(name) {
                 items.push(spawn(self.cloned().validate_element_link(
@@ -657,35 +949,140 @@ impl WebValidator {
             }
         }
 
-        let validation_result = if let Some(config) = config {
-            muffy_validation::validate_html_element(
-                element,
-                config.ignored_attributes(),
-                config.ignored_elements(),
+        let (validation_result, exclusion_result) = if let Some(config) = config {
+            (
+                language.validate(
+                    element,
+                    ancestors,
+                    config.ignored_attributes(),
+                    config.ignored_elements(),
+                    config.custom_elements(),
+                ),
+                muffy_validation::validate_html_exclusions(
+                    element,
+                    ancestors,
+                    config.ignored_elements(),
+                ),
             )
         } else {
-            Ok(())
+            (Ok(()), Ok(()))
         };
 
-        if let Err(error) = &validation_result {
-            items.extend(Self::spawn_markup_errors(error));
+        for result in [&validation_result, &exclusion_result] {
+            if let Err(error) = result {
+                items.extend(Self::spawn_markup_errors(error));
+            }
+        }
+
+        let duplicate_ids = Self::find_duplicate_ids(context, document_context, &attributes);
+        let undefined_ids = Self::find_undefined_ids(context, document_context, &attributes);
+
+        if !suppression.markup() {
+            items.extend(Self::spawn_errors(&duplicate_ids, |(_, id)| {
+                ItemError::DuplicateId(id.to_string())
+            }));
+            items.extend(Self::spawn_errors(&undefined_ids, |(_, id)| {
+                ItemError::UndefinedId(id.to_string())
+            }));
         }
 
+        items.extend(Self::spawn_errors(suppression.unknown_kinds(), |kind| {
+            ItemError::UnknownSuppressionKind(kind.into())
+        }));
+
         if !items.is_empty() {
             futures.push((
                 Self::create_output_element(
                     element,
                     &attributes,
-                    link_attributes.iter().copied(),
-                    &validation_result,
+                    link_attributes
+                        .iter()
+                        .copied()
+                        .chain(
+                            style_links.iter().flat_map(|(attributes, _)| {
+                                attributes.iter().map(|(name, _)| *name)
+                            }),
+                        )
+                        .chain(missing_fragments.iter().map(|(name, _)| *name))
+                        .chain(style_error_attributes)
+                        .chain(duplicate_ids.iter().map(|(name, _)| *name))
+                        .chain(undefined_ids.iter().map(|(name, _)| *name)),
+                    [&validation_result],
                 ),
                 items,
             ));
         }
 
+        ancestors.push(element);
+
         for node in element.children() {
-            self.validate_svg_element(context, base, site, node, false, futures);
+            self.validate_xml_element(
+                context,
+                document_context,
+                language,
+                node,
+                ancestors,
+                futures,
+            );
+        }
+
+        ancestors.pop();
+    }
+
+    async fn validate_schema(
+        &self,
+        context: &Arc<Context>,
+        response: &Arc<Response>,
+        site: &Arc<Url>,
+    ) -> Result<Vec<ElementFuture>, Error> {
+        let config = context.config().site(site);
+        let Some(schema) = Self::find_schema(config, response)? else {
+            return Ok(vec![]);
+        };
+        let document = self.0.document_parser.parse(response).await?;
+        let mut futures = Self::spawn_syntax_errors(document.errors(), &[], ItemError::XmlSyntax);
+
+        for node in document.children() {
+            let Node::Element(root) = node else { continue };
+
+            for (element, error) in schema.schema().validate(root) {
+                let items = Self::spawn_markup_errors(&error);
+
+                futures.push((
+                    Self::create_output_element(
+                        element,
+                        &HashMap::from_iter(element.attributes()),
+                        [],
+                        [&Err(error)],
+                    ),
+                    items,
+                ));
+            }
         }
+
+        Ok(futures)
+    }
+
+    // Checks if a response to a HEAD request needs to be confirmed by a GET
+    // request.
+    fn requires_get(site: &SiteConfig, response: &Response) -> bool {
+        matches!(
+            response.status(),
+            StatusCode::METHOD_NOT_ALLOWED | StatusCode::NOT_IMPLEMENTED
+        ) || site.method() == RequestMethod::Auto && !site.status().accepted(response.status())
+    }
+
+    fn find_schema<'a>(
+        site: &'a SiteConfig,
+        response: &Response,
+    ) -> Result<Option<&'a SchemaConfig>, ItemError> {
+        let media_type = response.media_type()?;
+
+        Ok(site
+            .validation()
+            .schemas()
+            .iter()
+            .find(|schema| schema.matches(response.url(), media_type)))
     }
 
     fn validate_document_type(
@@ -728,6 +1125,16 @@ impl WebValidator {
 
                 document_type
             }
+            Some(DocumentType::MathMl) => {
+                if media_type != MATHML_MEDIA_TYPE {
+                    return Err(ItemError::ContentTypeInvalid {
+                        actual: value.into(),
+                        expected: MATHML_MEDIA_TYPE,
+                    });
+                }
+
+                document_type
+            }
             Some(DocumentType::Sitemap) => {
                 if !media_type.ends_with("/xml") {
                     return Err(ItemError::ContentTypeInvalid {
@@ -748,10 +1155,14 @@ impl WebValidator {
 
                 document_type
             }
+            // Documents validated against schemas are matched by their media
+            // types or URLs beforehand.
+            Some(DocumentType::Xml) => document_type,
             None => match media_type.as_str() {
                 "text/css" => Some(DocumentType::Css),
                 "text/html" => Some(DocumentType::Html),
                 "image/svg+xml" => Some(DocumentType::Svg),
+                MATHML_MEDIA_TYPE => Some(DocumentType::MathMl),
                 _ => None,
             },
         })
@@ -780,28 +1191,237 @@ impl WebValidator {
         }
     }
 
-    fn parse_srcset(srcset: &str) -> impl Iterator<Item = &str> + '_ {
-        let mut rest = srcset;
+    fn collect_ids<'a>(
+        nodes: impl IntoIterator<Item = &'a Node>,
+        attributes: &[&str],
+    ) -> HashSet<String> {
+        let mut ids = HashSet::new();
+        let mut nodes = nodes.into_iter().collect::<Vec<_>>();
+
+        while let Some(node) = nodes.pop() {
+            if let Node::Element(element) = node {
+                ids.extend(
+                    element
+                        .attributes()
+                        .filter(|(name, _)| attributes.contains(name))
+                        .map(|(_, value)| value.to_owned()),
+                );
+                nodes.extend(element.children());
+            }
+        }
+
+        ids
+    }
+
+    // Extracts links from style sheets in `style` elements and declarations in
+    // `style` attributes together with their errors and names of attributes
+    // causing them.
+    fn parse_inline_styles<'a>(
+        element: &document::Element,
+        attributes: &HashMap<&'a str, &'a str>,
+    ) -> (Links<'a>, Vec<(Option<&'a str>, ItemError)>) {
+        let mut links = vec![];
+        let mut errors = vec![];
+
+        if element.name() == "style" {
+            match muffy_css::parse(
+                element
+                    .children()
+                    .filter_map(|node| match node {
+                        Node::Text(text) => Some(text.as_str()),
+                        Node::Comment(_) | Node::Element(_) => None,
+                    })
+                    .collect::<String>()
+                    .as_bytes(),
+            ) {
+                Ok((entries, syntax_errors)) => {
+                    links.push((
+                        vec![],
+                        entries
+                            .into_iter()
+                            .map(|entry| match entry {
+                                muffy_css::Entry::Import(url) => (url, Some(DocumentType::Css)),
+                                muffy_css::Entry::Url(url) => (url, None),
+                            })
+                            .collect(),
+                    ));
+                    errors.extend(
+                        syntax_errors
+                            .into_iter()
+                            .map(|error| (None, ItemError::CssSyntax(error))),
+                    );
+                }
+                Err(error) => errors.push((None, ItemError::Css(error))),
+            }
+        }
+
+        if let Some((name, value)) = attributes.get_key_value("style") {
+            match muffy_css::parse_declarations(value) {
+                Ok((urls, syntax_errors)) => {
+                    // Fragment-only URLs refer to elements in the same documents
+                    // rather than other documents.
+                    let urls = urls
+                        .into_iter()
+                        .filter(|url| !url.starts_with('#'))
+                        .map(|url| (url, None))
+                        .collect::<Vec<_>>();
+
+                    if !urls.is_empty() {
+                        links.push((vec![(*name, *value)], urls));
+                    }
+
+                    errors.extend(
+                        syntax_errors
+                            .into_iter()
+                            .map(|error| (Some(*name), ItemError::CssSyntax(error))),
+                    );
+                }
+                Err(error) => errors.push((Some(*name), ItemError::Css(error))),
+            }
+        }
+
+        (links, errors)
+    }
+
+    // Finds fragments of `url()` references in presentation attributes and
+    // `style` attributes of SVG elements not defined in documents.
+    fn find_missing_svg_fragments<'a>(
+        context: &Context,
+        document_context: &DocumentContext,
+        element: &document::Element,
+        attributes: &HashMap<&'a str, &'a str>,
+    ) -> Vec<(&'a str, String)> {
+        if element.namespace() != Some(SVG_NAMESPACE) {
+            return vec![];
+        }
+
+        let site = context.config().site(document_context.site());
+
+        SVG_REFERENCE_ATTRIBUTES
+            .iter()
+            .filter_map(|name| attributes.get_key_value(name))
+            .flat_map(|(name, value)| {
+                muffy_css::parse_declarations(&if *name == "style" {
+                    value.to_string()
+                } else {
+                    format!("{name}: {value}")
+                })
+                .map(|(urls, _)| urls)
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|url| Some(url.strip_prefix('#')?.to_owned()))
+                .map(|fragment| (*name, fragment))
+            })
+            .filter(|(_, fragment)| {
+                !site
+                    .ignored_fragments()
+                    .iter()
+                    .any(|pattern| pattern.is_match(fragment))
+                    && !document_context.has_fragment(fragment)
+            })
+            .unique()
+            .collect()
+    }
+
+    fn find_duplicate_ids<'a>(
+        context: &Context,
+        document_context: &DocumentContext,
+        attributes: &HashMap<&'a str, &'a str>,
+    ) -> Vec<(&'a str, &'a str)> {
+        if !context
+            .config()
+            .site(document_context.site())
+            .validation()
+            .duplicate_ids()
+        {
+            return vec![];
+        }
 
-        iter::from_fn(move || {
-            rest = rest.trim_start_matches(|char: char| char.is_whitespace() || char == ',');
+        ID_ATTRIBUTES
+            .iter()
+            .filter_map(|name| attributes.get_key_value(name))
+            .map(|(name, value)| (*name, *value))
+            .unique_by(|(_, value)| *value)
+            .filter(|(_, value)| !document_context.insert_id(value))
+            .collect()
+    }
 
-            let url =
-                rest[..rest.find(char::is_whitespace).unwrap_or(rest.len())].trim_end_matches(',');
+    fn find_undefined_ids<'a>(
+        context: &Context,
+        document_context: &DocumentContext,
+        attributes: &HashMap<&'a str, &'a str>,
+    ) -> Vec<(&'a str, &'a str)> {
+        let Some(config) = context
+            .config()
+            .site(document_context.site())
+            .validation()
+            .id_references()
+        else {
+            return vec![];
+        };
 
-            rest = &rest[rest[url.len()..]
-                .find(',')
-                .map_or(rest.len(), |index| url.len() + index)..];
+        ID_REFERENCE_ATTRIBUTES
+            .iter()
+            .copied()
+            .chain(config.attributes().iter().map(String::as_str))
+            .filter_map(|name| attributes.get_key_value(name))
+            .flat_map(|(name, value)| value.split_ascii_whitespace().map(|id| (*name, id)))
+            .filter(|(_, id)| !document_context.has_id(id))
+            .chain(
+                HASH_NAME_REFERENCE_ATTRIBUTES
+                    .iter()
+                    .filter_map(|name| attributes.get_key_value(name))
+                    .filter_map(|(name, value)| Some((*name, value.strip_prefix('#')?)))
+                    .filter(|(_, id)| !document_context.has_fragment(id)),
+            )
+            .unique()
+            .collect()
+    }
 
-            (!url.is_empty()).then_some(url)
+    fn spawn_errors<T>(
+        values: impl IntoIterator<Item = T>,
+        create_error: impl Fn(T) -> ItemError,
+    ) -> impl Iterator<Item = JoinHandle<Result<ItemOutput, ItemError>>> {
+        values.into_iter().map(move |value| {
+            let error = create_error(value);
+            spawn(async move { Err(error) })
         })
     }
 
+    fn spawn_syntax_errors<'a>(
+        errors: impl IntoIterator<Item = &'a ParseError>,
+        ignored_errors: &[Regex],
+        create_error: fn(String) -> ItemError,
+    ) -> Vec<ElementFuture> {
+        errors
+            .into_iter()
+            .filter(|error| {
+                !ignored_errors
+                    .iter()
+                    .any(|pattern| pattern.is_match(error.message()))
+            })
+            .unique()
+            .sorted()
+            .map(|error| {
+                // Parse errors span whole lines where they occur.
+                let span = error
+                    .line()
+                    .map(|line| Span::new(Position::new(line, 1), Position::new(line + 1, 1)));
+                let error = create_error(error.message().into());
+
+                (
+                    Element::new(PSEUDO_DOCUMENT_ELEMENT.into(), vec![]).set_span(span),
+                    vec![spawn(async move { Err(error) })],
+                )
+            })
+            .collect()
+    }
+
     fn spawn_markup_errors(error: &MarkupError) -> Vec<JoinHandle<Result<ItemOutput, ItemError>>> {
         let mut items = vec![];
 
         match error {
-            MarkupError::UnknownTag(_) => {
+            MarkupError::UnknownTag(_) | MarkupError::ExcludedElement(_) => {
                 items.push(spawn({
                     let error = ItemError::Markup(error.clone());
                     async move { Err(error) }
@@ -813,10 +1433,13 @@ impl WebValidator {
                 missing_attributes,
                 missing_children,
             } => {
-                for (name, errors) in invalid_attributes {
+                for (name, error) in invalid_attributes
+                    .iter()
+                    .flat_map(|(name, errors)| errors.iter().map(move |error| (name, error)))
+                {
                     items.push(spawn({
                         let error = ItemError::Markup(MarkupError::InvalidElement {
-                            invalid_attributes: [(name.clone(), errors.clone())].into(),
+                            invalid_attributes: [(name.clone(), [error.clone()].into())].into(),
                             invalid_children: Default::default(),
                             missing_attributes: Default::default(),
                             missing_children: Default::default(),
@@ -825,11 +1448,14 @@ impl WebValidator {
                     }));
                 }
 
-                for (name, errors) in invalid_children {
+                for (name, error) in invalid_children
+                    .iter()
+                    .flat_map(|(name, errors)| errors.iter().map(move |error| (name, error)))
+                {
                     items.push(spawn({
                         let error = ItemError::Markup(MarkupError::InvalidElement {
                             invalid_attributes: Default::default(),
-                            invalid_children: [(name.clone(), errors.clone())].into(),
+                            invalid_children: [(name.clone(), [error.clone()].into())].into(),
                             missing_attributes: Default::default(),
                             missing_children: Default::default(),
                         });
@@ -861,6 +1487,33 @@ impl WebValidator {
                     }));
                 }
             }
+            MarkupError::InvalidAria {
+                invalid_attributes,
+                missing_attributes,
+            } => {
+                for (name, error) in invalid_attributes
+                    .iter()
+                    .flat_map(|(name, errors)| errors.iter().map(move |error| (name, error)))
+                {
+                    items.push(spawn({
+                        let error = ItemError::Markup(MarkupError::InvalidAria {
+                            invalid_attributes: [(name.clone(), [error.clone()].into())].into(),
+                            missing_attributes: Default::default(),
+                        });
+                        async move { Err(error) }
+                    }));
+                }
+
+                if !missing_attributes.is_empty() {
+                    items.push(spawn({
+                        let error = ItemError::Markup(MarkupError::InvalidAria {
+                            invalid_attributes: Default::default(),
+                            missing_attributes: missing_attributes.clone(),
+                        });
+                        async move { Err(error) }
+                    }));
+                }
+            }
         }
 
         items
@@ -870,25 +1523,29 @@ impl WebValidator {
         element: &document::Element,
         attributes: &HashMap<&str, &str>,
         link_attributes: impl IntoIterator<Item = &'a str>,
-        validation_result: &'a Result<(), MarkupError>,
+        validation_results: impl IntoIterator<Item = &'a Result<(), MarkupError>>,
     ) -> Element {
         Element::new(
             element.name().into(),
             link_attributes
                 .into_iter()
-                .chain(
-                    if let Err(MarkupError::InvalidElement {
-                        invalid_attributes, ..
-                    }) = validation_result
-                    {
-                        invalid_attributes
+                .chain(validation_results.into_iter().flat_map(|result| {
+                    match result {
+                        Err(MarkupError::InvalidElement {
+                            invalid_attributes, ..
+                        }) => invalid_attributes
                             .keys()
                             .map(AsRef::as_ref)
-                            .collect::<Vec<_>>()
-                    } else {
-                        Default::default()
-                    },
-                )
+                            .collect::<Vec<_>>(),
+                        Err(MarkupError::InvalidAria {
+                            invalid_attributes, ..
+                        }) => invalid_attributes
+                            .keys()
+                            .map(AsRef::as_ref)
+                            .collect::<Vec<_>>(),
+                        _ => Default::default(),
+                    }
+                }))
                 .unique()
                 .filter_map(|name| {
                     attributes
@@ -898,6 +1555,7 @@ impl WebValidator {
                 .sorted()
                 .collect(),
         )
+        .set_span(element.span())
     }
 }
 
@@ -915,8 +1573,11 @@ mod tests {
     use futures::{Stream, StreamExt};
     use http::{HeaderMap, HeaderName, HeaderValue, StatusCode};
     use indoc::indoc;
+    use muffy_validation::{AriaError, AttributeError, ChildError, Schema};
     use pretty_assertions::assert_eq;
     use regex::Regex;
+    use std::fs::write;
+    use tempfile::tempdir;
     use url::Url;
 
     async fn validate(
@@ -954,6 +1615,41 @@ mod tests {
         .await
     }
 
+    async fn validate_duplicate_ids(
+        client: impl BareHttpClient + 'static,
+        url: &str,
+    ) -> Result<impl Stream<Item = Result<DocumentOutput, Error>>, Error> {
+        validate_with_site(
+            client,
+            url,
+            SiteConfig::default()
+                .set_validation(crate::ValidationConfig::default().set_duplicate_ids(true)),
+        )
+        .await
+    }
+
+    async fn collect_duplicate_ids(
+        documents: &mut (impl Stream<Item = Result<DocumentOutput, Error>> + Unpin),
+    ) -> Vec<(String, Vec<(String, String)>, String)> {
+        let mut ids = vec![];
+
+        while let Some(document) = documents.next().await {
+            for element in document.unwrap().elements() {
+                for result in element.results() {
+                    if let Err(error @ ItemError::DuplicateId(_)) = result {
+                        ids.push((
+                            element.element().name().into(),
+                            element.element().attributes().to_vec(),
+                            error.to_string(),
+                        ));
+                    }
+                }
+            }
+        }
+
+        ids
+    }
+
     async fn validate_with_site(
         client: impl BareHttpClient + 'static,
         url: &str,
@@ -1008,9 +1704,12 @@ mod tests {
                     if let Err(
                         error @ (ItemError::Css(_)
                         | ItemError::CssSyntax(_)
+                        | ItemError::DuplicateId(_)
+                        | ItemError::HtmlSyntax(_)
                         | ItemError::Markup(_)
                         | ItemError::InvalidNamespace { .. }
                         | ItemError::InvalidRootElement { .. }
+                        | ItemError::UnknownSuppressionKind(_)
                         | ItemError::XmlSyntax(_)),
                     ) = result
                     {
@@ -1309,6 +2008,7 @@ mod tests {
                             HeaderValue::from_static("text/html"),
                         )]),
                         indoc! {r#"
+                            <!DOCTYPE html>
                             <html>
                                 <head>
                                     <title>foo</title>
@@ -1364,6 +2064,7 @@ mod tests {
                             HeaderValue::from_static("text/html"),
                         )]),
                         indoc! {r#"
+                            <!DOCTYPE html>
                             <html>
                                 <head>
                                     <title>foo</title>
@@ -1385,10 +2086,1431 @@ mod tests {
         .await
         .unwrap();
 
-        assert_eq!(
-            collect_metrics(&mut documents).await,
-            (Metrics::new(2, 0), Metrics::new(0, 0))
-        );
+        assert_eq!(
+            collect_metrics(&mut documents).await,
+            (Metrics::new(2, 0), Metrics::new(0, 0))
+        );
+    }
+
+    #[tokio::test]
+    async fn validate_excluded_html_elements() {
+        let mut documents = validate_html_content(
+            StubHttpClient::new(
+                [
+                    build_stub_response(
+                        "https://foo.com/robots.txt",
+                        StatusCode::OK,
+                        Default::default(),
+                        Default::default(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com",
+                        StatusCode::OK,
+                        HeaderMap::from_iter([(
+                            HeaderName::from_static("content-type"),
+                            HeaderValue::from_static("text/html"),
+                        )]),
+                        indoc! {r#"
+                            <!DOCTYPE html>
+                            <html>
+                                <head>
+                                    <title>foo</title>
+                                </head>
+                                <body>
+                                    <a><span><button>foo</button></span></a>
+                                    <footer><div><header></header></div></footer>
+                                </body>
+                            </html>
+                        "#}
+                        .as_bytes()
+                        .to_vec(),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            "https://foo.com",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            collect_errors(&mut documents).await,
+            [
+                r#"not allowed as a descendant of "a""#.into(),
+                r#"not allowed as a descendant of "footer""#.into(),
+            ]
+            .into()
+        );
+    }
+
+    #[tokio::test]
+    async fn validate_excluded_invalid_html_elements() {
+        let mut documents = validate_html_content(
+            StubHttpClient::new(
+                [
+                    build_stub_response(
+                        "https://foo.com/robots.txt",
+                        StatusCode::OK,
+                        Default::default(),
+                        Default::default(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com",
+                        StatusCode::OK,
+                        HeaderMap::from_iter([(
+                            HeaderName::from_static("content-type"),
+                            HeaderValue::from_static("text/html"),
+                        )]),
+                        indoc! {r#"
+                            <!DOCTYPE html>
+                            <html>
+                                <head>
+                                    <title>foo</title>
+                                </head>
+                                <body>
+                                    <a href="/"><span><button foo="bar">foo</button></span></a>
+                                </body>
+                            </html>
+                        "#}
+                        .as_bytes()
+                        .to_vec(),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            "https://foo.com",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            collect_errors(&mut documents).await,
+            [
+                "invalid attributes: foo (not allowed)".into(),
+                r#"not allowed as a descendant of "a""#.into(),
+            ]
+            .into()
+        );
+    }
+
+    #[tokio::test]
+    async fn validate_xml_against_schema() {
+        let directory = tempdir().unwrap();
+        let path = directory.path().join("feed.rnc");
+
+        write(
+            &path,
+            indoc! {r#"
+                default namespace = "http://foo.example/"
+
+                start = element feed { element title { text }, element entry { empty }* }
+            "#},
+        )
+        .unwrap();
+
+        let mut documents = validate_with_site(
+            StubHttpClient::new(
+                [
+                    build_stub_response(
+                        "https://foo.com/robots.txt",
+                        StatusCode::OK,
+                        Default::default(),
+                        Default::default(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com",
+                        StatusCode::OK,
+                        HeaderMap::from_iter([(
+                            HeaderName::from_static("content-type"),
+                            HeaderValue::from_static("application/atom+xml"),
+                        )]),
+                        indoc! {r#"
+                            <feed xmlns="http://foo.example/">
+                                <entry foo="bar"/>
+                            </feed>
+                        "#}
+                        .as_bytes()
+                        .to_vec(),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            "https://foo.com",
+            SiteConfig::default().set_validation(crate::ValidationConfig::default().set_schemas(
+                vec![
+                    SchemaConfig::new(Schema::load(&path).unwrap().into())
+                        .set_media_type(Some("application/atom+xml".into())),
+                ],
+            )),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            collect_errors(&mut documents).await,
+            [
+                "invalid attributes: foo (not allowed)".into(),
+                "invalid children: entry (not allowed)".into(),
+                "missing children: title".into(),
+            ]
+            .into()
+        );
+    }
+
+    #[tokio::test]
+    async fn report_html_syntax_errors() {
+        let mut documents = validate_html_content(
+            StubHttpClient::new(
+                [
+                    build_stub_response(
+                        "https://foo.com/robots.txt",
+                        StatusCode::OK,
+                        Default::default(),
+                        Default::default(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com",
+                        StatusCode::OK,
+                        HeaderMap::from_iter([(
+                            HeaderName::from_static("content-type"),
+                            HeaderValue::from_static("text/html"),
+                        )]),
+                        indoc! {r#"
+                            <!DOCTYPE html>
+                            <html>
+                                <head>
+                                    <title>foo</title>
+                                </head>
+                                <body>
+                                    <div id="foo" id="bar"></span></div>
+                                </body>
+                            </html>
+                        "#}
+                        .as_bytes()
+                        .to_vec(),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            "https://foo.com",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            collect_errors(&mut documents).await,
+            [
+                "invalid HTML: Duplicate attribute".into(),
+                "invalid HTML: Found special tag while closing generic tag".into(),
+            ]
+            .into()
+        );
+    }
+
+    #[tokio::test]
+    async fn report_html_syntax_error_lines() {
+        let mut documents = validate_html_content(
+            StubHttpClient::new(
+                [
+                    build_stub_response(
+                        "https://foo.com/robots.txt",
+                        StatusCode::OK,
+                        Default::default(),
+                        Default::default(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com",
+                        StatusCode::OK,
+                        HeaderMap::from_iter([(
+                            HeaderName::from_static("content-type"),
+                            HeaderValue::from_static("text/html"),
+                        )]),
+                        indoc! {r#"
+                            <!DOCTYPE html>
+                            <html>
+                                <body>
+                                    <div id="foo" id="bar"></div>
+                                </body>
+                            </html>
+                        "#}
+                        .as_bytes()
+                        .to_vec(),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            "https://foo.com",
+        )
+        .await
+        .unwrap();
+        let mut spans = vec![];
+
+        while let Some(document) = documents.next().await {
+            for element in document.unwrap().elements() {
+                if element.element().name() == PSEUDO_DOCUMENT_ELEMENT {
+                    spans.push(element.element().span());
+                }
+            }
+        }
+
+        assert_eq!(
+            spans,
+            vec![Some(Span::new(Position::new(4, 1), Position::new(5, 1)))]
+        );
+    }
+
+    #[tokio::test]
+    async fn ignore_html_syntax_errors() {
+        let mut documents = validate_with_site(
+            StubHttpClient::new(
+                [
+                    build_stub_response(
+                        "https://foo.com/robots.txt",
+                        StatusCode::OK,
+                        Default::default(),
+                        Default::default(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com",
+                        StatusCode::OK,
+                        HeaderMap::from_iter([(
+                            HeaderName::from_static("content-type"),
+                            HeaderValue::from_static("text/html"),
+                        )]),
+                        indoc! {r#"
+                            <!DOCTYPE html>
+                            <html>
+                                <head>
+                                    <title>foo</title>
+                                </head>
+                                <body>
+                                    <div id="foo" id="bar"></span></div>
+                                </body>
+                            </html>
+                        "#}
+                        .as_bytes()
+                        .to_vec(),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            "https://foo.com",
+            SiteConfig::default().set_validation(crate::ValidationConfig::default().set_html(
+                Some(MarkupConfig::default().set_ignored_syntax_errors(vec![
+                    Regex::new("^(?:Duplicate attribute)$").unwrap(),
+                ])),
+            )),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            collect_errors(&mut documents).await,
+            ["invalid HTML: Found special tag while closing generic tag".into()].into()
+        );
+    }
+
+    #[tokio::test]
+    async fn skip_html_syntax_errors_without_html_validation() {
+        let mut documents = validate(
+            StubHttpClient::new(
+                [
+                    build_stub_response(
+                        "https://foo.com/robots.txt",
+                        StatusCode::OK,
+                        Default::default(),
+                        Default::default(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com",
+                        StatusCode::OK,
+                        HeaderMap::from_iter([(
+                            HeaderName::from_static("content-type"),
+                            HeaderValue::from_static("text/html"),
+                        )]),
+                        indoc! {r#"
+                            <!DOCTYPE html>
+                            <html>
+                                <head>
+                                    <title>foo</title>
+                                </head>
+                                <body>
+                                    <div id="foo" id="bar"></span></div>
+                                </body>
+                            </html>
+                        "#}
+                        .as_bytes()
+                        .to_vec(),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            "https://foo.com",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(collect_errors(&mut documents).await, Default::default());
+    }
+
+    #[tokio::test]
+    async fn report_duplicate_html_ids() {
+        let mut documents = validate_duplicate_ids(
+            StubHttpClient::new(
+                [
+                    build_stub_response(
+                        "https://foo.com/robots.txt",
+                        StatusCode::OK,
+                        Default::default(),
+                        Default::default(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com",
+                        StatusCode::OK,
+                        HeaderMap::from_iter([(
+                            HeaderName::from_static("content-type"),
+                            HeaderValue::from_static("text/html"),
+                        )]),
+                        indoc! {r#"
+                            <!DOCTYPE html>
+                            <html>
+                                <head>
+                                    <title>foo</title>
+                                </head>
+                                <body>
+                                    <div id="foo"></div>
+                                    <p id="bar"></p>
+                                    <span id="foo"></span>
+                                    <a id="foo"></a>
+                                </body>
+                            </html>
+                        "#}
+                        .as_bytes()
+                        .to_vec(),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            "https://foo.com",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            collect_duplicate_ids(&mut documents).await,
+            vec![
+                (
+                    "span".into(),
+                    vec![("id".into(), "foo".into())],
+                    r#"duplicate ID "foo""#.into()
+                ),
+                (
+                    "a".into(),
+                    vec![("id".into(), "foo".into())],
+                    r#"duplicate ID "foo""#.into()
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn report_duplicate_svg_ids() {
+        let mut documents = validate_duplicate_ids(
+            StubHttpClient::new(
+                [
+                    build_stub_response(
+                        "https://foo.com/robots.txt",
+                        StatusCode::OK,
+                        Default::default(),
+                        Default::default(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com",
+                        StatusCode::OK,
+                        HeaderMap::from_iter([(
+                            HeaderName::from_static("content-type"),
+                            HeaderValue::from_static("image/svg+xml"),
+                        )]),
+                        indoc! {r#"
+                            <svg xmlns="http://www.w3.org/2000/svg">
+                                <circle id="foo" />
+                                <rect xml:id="foo" />
+                                <g id="bar" xml:id="bar" />
+                            </svg>
+                        "#}
+                        .as_bytes()
+                        .to_vec(),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            "https://foo.com",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            collect_duplicate_ids(&mut documents).await,
+            vec![(
+                "rect".into(),
+                vec![("xml:id".into(), "foo".into())],
+                r#"duplicate ID "foo""#.into()
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn skip_duplicate_ids_without_validation() {
+        let mut documents = validate(
+            StubHttpClient::new(
+                [
+                    build_stub_response(
+                        "https://foo.com/robots.txt",
+                        StatusCode::OK,
+                        Default::default(),
+                        Default::default(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com",
+                        StatusCode::OK,
+                        HeaderMap::from_iter([(
+                            HeaderName::from_static("content-type"),
+                            HeaderValue::from_static("text/html"),
+                        )]),
+                        indoc! {r#"
+                            <!DOCTYPE html>
+                            <html>
+                                <head>
+                                    <title>foo</title>
+                                </head>
+                                <body>
+                                    <div id="foo"></div>
+                                    <p id="bar"></p>
+                                    <span id="foo"></span>
+                                    <a id="foo"></a>
+                                </body>
+                            </html>
+                        "#}
+                        .as_bytes()
+                        .to_vec(),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            "https://foo.com",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(collect_duplicate_ids(&mut documents).await, vec![]);
+    }
+
+    async fn collect_missing_fragments(
+        documents: &mut (impl Stream<Item = Result<DocumentOutput, Error>> + Unpin),
+    ) -> Vec<(String, Vec<(String, String)>, String)> {
+        let mut fragments = vec![];
+
+        while let Some(document) = documents.next().await {
+            for element in document.unwrap().elements() {
+                for result in element.results() {
+                    if let Err(error @ ItemError::ElementNotFound(_)) = result {
+                        fragments.push((
+                            element.element().name().into(),
+                            element.element().attributes().to_vec(),
+                            error.to_string(),
+                        ));
+                    }
+                }
+            }
+        }
+
+        fragments
+    }
+
+    #[tokio::test]
+    async fn report_missing_svg_references() {
+        let mut documents = validate(
+            StubHttpClient::new(
+                [
+                    build_stub_response(
+                        "https://foo.com/robots.txt",
+                        StatusCode::OK,
+                        Default::default(),
+                        Default::default(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com",
+                        StatusCode::OK,
+                        HeaderMap::from_iter([(
+                            HeaderName::from_static("content-type"),
+                            HeaderValue::from_static("image/svg+xml"),
+                        )]),
+                        indoc! {r##"
+                            <svg xmlns="http://www.w3.org/2000/svg">
+                                <linearGradient id="foo" />
+                                <rect fill="url(#foo)" stroke="url(#bar) red" />
+                                <path style="clip-path: url(#baz); mask: url('#foo')" />
+                                <line marker-end="url(#qux)" data-muffy-ignore="link" />
+                            </svg>
+                        "##}
+                        .as_bytes()
+                        .to_vec(),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            "https://foo.com",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            collect_missing_fragments(&mut documents).await,
+            vec![
+                (
+                    "rect".into(),
+                    vec![("stroke".into(), "url(#bar) red".into())],
+                    "element for #bar not found".into()
+                ),
+                (
+                    "path".into(),
+                    vec![(
+                        "style".into(),
+                        "clip-path: url(#baz); mask: url('#foo')".into()
+                    )],
+                    "element for #baz not found".into()
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn report_missing_inline_svg_references() {
+        let mut documents = validate(
+            StubHttpClient::new(
+                [
+                    build_stub_response(
+                        "https://foo.com/robots.txt",
+                        StatusCode::OK,
+                        Default::default(),
+                        Default::default(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com",
+                        StatusCode::OK,
+                        HeaderMap::from_iter([(
+                            HeaderName::from_static("content-type"),
+                            HeaderValue::from_static("text/html"),
+                        )]),
+                        indoc! {r##"
+                            <!DOCTYPE html>
+                            <html>
+                                <head>
+                                    <title>foo</title>
+                                </head>
+                                <body>
+                                    <div fill="url(#bar)"></div>
+                                    <svg>
+                                        <filter id="foo" />
+                                        <g filter="url(#foo)" />
+                                        <g filter="url(#bar)" />
+                                    </svg>
+                                </body>
+                            </html>
+                        "##}
+                        .as_bytes()
+                        .to_vec(),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            "https://foo.com",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            collect_missing_fragments(&mut documents).await,
+            vec![(
+                "g".into(),
+                vec![("filter".into(), "url(#bar)".into())],
+                "element for #bar not found".into()
+            )]
+        );
+    }
+
+    async fn collect_element_errors(
+        documents: &mut (impl Stream<Item = Result<DocumentOutput, Error>> + Unpin),
+    ) -> Vec<(String, Vec<(String, String)>, String)> {
+        let mut errors = vec![];
+
+        while let Some(document) = documents.next().await {
+            for element in document.unwrap().elements() {
+                for result in element.results() {
+                    if let Err(error) = result {
+                        errors.push((
+                            element.element().name().into(),
+                            element.element().attributes().to_vec(),
+                            error.to_string(),
+                        ));
+                    }
+                }
+            }
+        }
+
+        errors.sort();
+
+        errors
+    }
+
+    #[tokio::test]
+    async fn validate_inline_html_styles() {
+        let mut documents = validate(
+            StubHttpClient::new(
+                [
+                    build_stub_response(
+                        "https://foo.com/robots.txt",
+                        StatusCode::OK,
+                        Default::default(),
+                        Default::default(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com",
+                        StatusCode::OK,
+                        HeaderMap::from_iter([(
+                            HeaderName::from_static("content-type"),
+                            HeaderValue::from_static("text/html"),
+                        )]),
+                        indoc! {r#"
+                            <!DOCTYPE html>
+                            <html>
+                                <head>
+                                    <title>foo</title>
+                                    <style>
+                                        @import "/foo.css";
+                                        body { background: url(/bar.png); }
+                                        @unknown-rule { x }
+                                    </style>
+                                </head>
+                                <body>
+                                    <div style="background: url(/baz.png)"></div>
+                                    <p style="color: red"></p>
+                                    <em style="color red"></em>
+                                    <span style="background: url(/qux.png)" data-muffy-ignore="link"></span>
+                                </body>
+                            </html>
+                        "#}
+                        .as_bytes()
+                        .to_vec(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com/foo.css",
+                        StatusCode::OK,
+                        HeaderMap::from_iter([(
+                            HeaderName::from_static("content-type"),
+                            HeaderValue::from_static("text/css"),
+                        )]),
+                        Default::default(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com/bar.png",
+                        StatusCode::NOT_FOUND,
+                        Default::default(),
+                        Default::default(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com/baz.png",
+                        StatusCode::NOT_FOUND,
+                        Default::default(),
+                        Default::default(),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            "https://foo.com",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            collect_element_errors(&mut documents).await,
+            vec![
+                (
+                    "div".into(),
+                    vec![("style".into(), "background: url(/baz.png)".into())],
+                    "invalid status 404 Not Found".into()
+                ),
+                (
+                    "em".into(),
+                    vec![("style".into(), "color red".into())],
+                    "invalid CSS: Unexpected token Ident(\"red\") at 1:6".into()
+                ),
+                (
+                    "style".into(),
+                    vec![],
+                    "invalid CSS: Unknown at rule: @unknown-rule at 4:26".into()
+                ),
+                (
+                    "style".into(),
+                    vec![],
+                    "invalid status 404 Not Found".into()
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn validate_inline_svg_styles() {
+        let mut documents = validate(
+            StubHttpClient::new(
+                [
+                    build_stub_response(
+                        "https://foo.com/robots.txt",
+                        StatusCode::OK,
+                        Default::default(),
+                        Default::default(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com",
+                        StatusCode::OK,
+                        HeaderMap::from_iter([(
+                            HeaderName::from_static("content-type"),
+                            HeaderValue::from_static("image/svg+xml"),
+                        )]),
+                        indoc! {r#"
+                            <svg xmlns="http://www.w3.org/2000/svg">
+                                <style>rect { fill: url(/foo.svg); }</style>
+                                <rect style="fill: url(/bar.svg)" />
+                            </svg>
+                        "#}
+                        .as_bytes()
+                        .to_vec(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com/foo.svg",
+                        StatusCode::NOT_FOUND,
+                        Default::default(),
+                        Default::default(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com/bar.svg",
+                        StatusCode::NOT_FOUND,
+                        Default::default(),
+                        Default::default(),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            "https://foo.com",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            collect_element_errors(&mut documents).await,
+            vec![
+                (
+                    "rect".into(),
+                    vec![("style".into(), "fill: url(/bar.svg)".into())],
+                    "invalid status 404 Not Found".into()
+                ),
+                (
+                    "style".into(),
+                    vec![],
+                    "invalid status 404 Not Found".into()
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn report_srcset_errors() {
+        let mut documents = validate_html_content(
+            StubHttpClient::new(
+                [
+                    build_stub_response(
+                        "https://foo.com/robots.txt",
+                        StatusCode::OK,
+                        Default::default(),
+                        Default::default(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com",
+                        StatusCode::OK,
+                        HeaderMap::from_iter([(
+                            HeaderName::from_static("content-type"),
+                            HeaderValue::from_static("text/html"),
+                        )]),
+                        indoc! {r#"
+                            <!DOCTYPE html>
+                            <html>
+                                <head>
+                                    <title>foo</title>
+                                </head>
+                                <body>
+                                    <picture>
+                                        <source srcset="/foo.png 500w, /bar.png 2x" sizes="foo">
+                                        <img src="/foo.png" srcset="/foo.png 500w" alt="foo">
+                                    </picture>
+                                </body>
+                            </html>
+                        "#}
+                        .as_bytes()
+                        .to_vec(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com/foo.png",
+                        StatusCode::OK,
+                        Default::default(),
+                        Default::default(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com/bar.png",
+                        StatusCode::OK,
+                        Default::default(),
+                        Default::default(),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            "https://foo.com",
+        )
+        .await
+        .unwrap();
+        let mut errors = vec![];
+
+        while let Some(document) = documents.next().await {
+            for element in document.unwrap().elements() {
+                for result in element.results() {
+                    if let Err(error @ ItemError::Srcset(_)) = result {
+                        errors.push((
+                            element.element().name().to_owned(),
+                            element.element().attributes().to_vec(),
+                            error.code(),
+                        ));
+                    }
+                }
+            }
+        }
+
+        assert_eq!(
+            errors,
+            vec![
+                (
+                    "source".into(),
+                    vec![
+                        ("sizes".into(), "foo".into()),
+                        ("srcset".into(), "/foo.png 500w, /bar.png 2x".into())
+                    ],
+                    "srcset/invalid-sizes"
+                ),
+                (
+                    "source".into(),
+                    vec![
+                        ("sizes".into(), "foo".into()),
+                        ("srcset".into(), "/foo.png 500w, /bar.png 2x".into())
+                    ],
+                    "srcset/mixed-descriptors"
+                ),
+                (
+                    "img".into(),
+                    vec![
+                        ("src".into(), "/foo.png".into()),
+                        ("srcset".into(), "/foo.png 500w".into())
+                    ],
+                    "srcset/missing-sizes"
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn report_undefined_ids() {
+        let mut documents = validate_with_site(
+            StubHttpClient::new(
+                [
+                    build_stub_response(
+                        "https://foo.com/robots.txt",
+                        StatusCode::OK,
+                        Default::default(),
+                        Default::default(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com",
+                        StatusCode::OK,
+                        HeaderMap::from_iter([(
+                            HeaderName::from_static("content-type"),
+                            HeaderValue::from_static("text/html"),
+                        )]),
+                        indoc! {r##"
+                            <!DOCTYPE html>
+                            <html>
+                                <head>
+                                    <title>foo</title>
+                                </head>
+                                <body>
+                                    <label for="foo">foo</label>
+                                    <input id="foo" list="bar">
+                                    <div aria-controls="foo baz" data-target="qux"></div>
+                                    <img usemap="#map">
+                                    <img usemap="#nomap">
+                                    <map name="map"></map>
+                                </body>
+                            </html>
+                        "##}
+                        .as_bytes()
+                        .to_vec(),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            "https://foo.com",
+            SiteConfig::default().set_validation(
+                crate::ValidationConfig::default().set_id_references(Some(
+                    crate::IdReferenceConfig::new().set_attributes(vec!["data-target".into()]),
+                )),
+            ),
+        )
+        .await
+        .unwrap();
+        let mut errors = vec![];
+
+        while let Some(document) = documents.next().await {
+            for element in document.unwrap().elements() {
+                for result in element.results() {
+                    if let Err(error @ ItemError::UndefinedId(_)) = result {
+                        errors.push((
+                            element.element().name().to_owned(),
+                            element.element().attributes().to_vec(),
+                            error.to_string(),
+                        ));
+                    }
+                }
+            }
+        }
+
+        assert_eq!(
+            errors,
+            vec![
+                (
+                    "input".into(),
+                    vec![("list".into(), "bar".into())],
+                    r#"undefined ID "bar""#.into()
+                ),
+                (
+                    "div".into(),
+                    vec![
+                        ("aria-controls".into(), "foo baz".into()),
+                        ("data-target".into(), "qux".into())
+                    ],
+                    r#"undefined ID "baz""#.into()
+                ),
+                (
+                    "div".into(),
+                    vec![
+                        ("aria-controls".into(), "foo baz".into()),
+                        ("data-target".into(), "qux".into())
+                    ],
+                    r#"undefined ID "qux""#.into()
+                ),
+                (
+                    "img".into(),
+                    vec![("usemap".into(), "#nomap".into())],
+                    r#"undefined ID "nomap""#.into()
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn report_aria_errors() {
+        let mut documents = validate_with_site(
+            StubHttpClient::new(
+                [
+                    build_stub_response(
+                        "https://foo.com/robots.txt",
+                        StatusCode::OK,
+                        Default::default(),
+                        Default::default(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com",
+                        StatusCode::OK,
+                        HeaderMap::from_iter([(
+                            HeaderName::from_static("content-type"),
+                            HeaderValue::from_static("text/html"),
+                        )]),
+                        indoc! {r#"
+                            <!DOCTYPE html>
+                            <html>
+                                <head>
+                                    <title>foo</title>
+                                </head>
+                                <body>
+                                    <div role="checkbox" aria-labelledby="foo"></div>
+                                    <span aria-describedby="bar" aria-hidden="yes"></span>
+                                    <p id="foo">foo</p>
+                                </body>
+                            </html>
+                        "#}
+                        .as_bytes()
+                        .to_vec(),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            "https://foo.com",
+            SiteConfig::default().set_validation(crate::ValidationConfig::default().set_aria(true)),
+        )
+        .await
+        .unwrap();
+        let mut errors = vec![];
+
+        while let Some(document) = documents.next().await {
+            for element in document.unwrap().elements() {
+                for result in element.results() {
+                    if let Err(error @ ItemError::Markup(MarkupError::InvalidAria { .. })) = result
+                    {
+                        errors.push((
+                            element.element().name().to_owned(),
+                            element.element().attributes().to_vec(),
+                            error.code(),
+                            error.to_string(),
+                        ));
+                    }
+                }
+            }
+        }
+
+        assert_eq!(
+            errors,
+            vec![
+                (
+                    "div".into(),
+                    vec![],
+                    "aria/missing-attribute",
+                    "missing ARIA attributes: aria-checked".into()
+                ),
+                (
+                    "span".into(),
+                    vec![
+                        ("aria-describedby".into(), "bar".into()),
+                        ("aria-hidden".into(), "yes".into())
+                    ],
+                    "aria/undefined-reference",
+                    "invalid ARIA attributes: aria-describedby (undefined reference)".into()
+                ),
+                (
+                    "span".into(),
+                    vec![
+                        ("aria-describedby".into(), "bar".into()),
+                        ("aria-hidden".into(), "yes".into())
+                    ],
+                    "aria/invalid-value",
+                    "invalid ARIA attributes: aria-hidden (invalid value)".into()
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn suppress_invalid_html_content() {
+        let mut documents = validate_html_content(
+            StubHttpClient::new(
+                [
+                    build_stub_response(
+                        "https://foo.com/robots.txt",
+                        StatusCode::OK,
+                        Default::default(),
+                        Default::default(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com",
+                        StatusCode::OK,
+                        HeaderMap::from_iter([(
+                            HeaderName::from_static("content-type"),
+                            HeaderValue::from_static("text/html"),
+                        )]),
+                        indoc! {r#"
+                            <!DOCTYPE html>
+                            <html>
+                                <head>
+                                    <title>foo</title>
+                                    <!-- muffy-ignore-next-line markup -->
+                                    <meta name="description">
+                                </head>
+                                <body>
+                                    <div foo="bar" data-muffy-ignore="markup"></div>
+                                    <ul><p></p></ul>
+                                </body>
+                            </html>
+                        "#}
+                        .as_bytes()
+                        .to_vec(),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            "https://foo.com",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            collect_errors(&mut documents).await,
+            ["invalid children: p (not allowed)".into()].into()
+        );
+    }
+
+    #[tokio::test]
+    async fn suppress_invalid_links() {
+        let mut documents = validate(
+            StubHttpClient::new(
+                [
+                    build_stub_response(
+                        "https://foo.com/robots.txt",
+                        StatusCode::OK,
+                        Default::default(),
+                        Default::default(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com",
+                        StatusCode::OK,
+                        HeaderMap::from_iter([(
+                            HeaderName::from_static("content-type"),
+                            HeaderValue::from_static("text/html"),
+                        )]),
+                        indoc! {r#"
+                            <!-- muffy-ignore-next-line link -->
+                            <a href="https://foo.com/bar"></a>
+                            <a href="https://foo.com/bar" data-muffy-ignore="link"></a>
+                        "#}
+                        .as_bytes()
+                        .to_vec(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com/bar",
+                        StatusCode::NOT_FOUND,
+                        Default::default(),
+                        Default::default(),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            "https://foo.com",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            collect_metrics(&mut documents).await,
+            (Metrics::new(2, 0), Metrics::new(0, 0))
+        );
+    }
+
+    #[tokio::test]
+    async fn report_unknown_suppression_kinds() {
+        let mut documents = validate_html_content(
+            StubHttpClient::new(
+                [
+                    build_stub_response(
+                        "https://foo.com/robots.txt",
+                        StatusCode::OK,
+                        Default::default(),
+                        Default::default(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com",
+                        StatusCode::OK,
+                        HeaderMap::from_iter([(
+                            HeaderName::from_static("content-type"),
+                            HeaderValue::from_static("text/html"),
+                        )]),
+                        indoc! {r#"
+                            <!DOCTYPE html>
+                            <html>
+                                <head>
+                                    <title>foo</title>
+                                </head>
+                                <body>
+                                    <!-- muffy-ignore-next-line links -->
+                                    <div foo="bar"></div>
+                                    <div data-muffy-ignore="markup, foo" baz="qux"></div>
+                                </body>
+                            </html>
+                        "#}
+                        .as_bytes()
+                        .to_vec(),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            "https://foo.com",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            collect_errors(&mut documents).await,
+            [
+                "invalid attributes: foo (not allowed)".into(),
+                "unknown suppression kind \"foo\"".into(),
+                "unknown suppression kind \"links\"".into(),
+            ]
+            .into()
+        );
+    }
+
+    #[tokio::test]
+    async fn split_markup_errors_by_codes() {
+        let mut codes = vec![];
+
+        for item in WebValidator::spawn_markup_errors(&MarkupError::InvalidElement {
+            invalid_attributes: [(
+                "foo".into(),
+                [AttributeError::Conflict, AttributeError::InvalidValue].into(),
+            )]
+            .into(),
+            invalid_children: [(
+                "bar".into(),
+                [ChildError::Misplaced, ChildError::NotAllowed].into(),
+            )]
+            .into(),
+            missing_attributes: ["baz".into()].into(),
+            missing_children: Default::default(),
+        })
+        .into_iter()
+        .chain(WebValidator::spawn_markup_errors(
+            &MarkupError::InvalidAria {
+                invalid_attributes: [(
+                    "aria-label".into(),
+                    [AriaError::InvalidValue, AriaError::Prohibited].into(),
+                )]
+                .into(),
+                missing_attributes: Default::default(),
+            },
+        )) {
+            let Err(error) = item.await.unwrap() else {
+                panic!("error expected");
+            };
+
+            codes.push(error.code());
+        }
+
+        assert_eq!(
+            codes,
+            [
+                "markup/conflicting-attribute",
+                "markup/invalid-attribute-value",
+                "markup/misplaced-child",
+                "markup/child-not-allowed",
+                "markup/missing-attribute",
+                "aria/invalid-value",
+                "aria/prohibited-attribute",
+            ]
+        );
+    }
+
+    mod severity {
+        use super::*;
+        use crate::Severity;
+        use pretty_assertions::assert_eq;
+
+        async fn validate_broken_link(
+            severity: Severity,
+        ) -> impl Stream<Item = Result<DocumentOutput, Error>> {
+            validate_with_site(
+                StubHttpClient::new(
+                    [
+                        build_stub_response(
+                            "https://foo.com/robots.txt",
+                            StatusCode::OK,
+                            Default::default(),
+                            Default::default(),
+                        ),
+                        build_stub_response(
+                            "https://foo.com",
+                            StatusCode::OK,
+                            HeaderMap::from_iter([(
+                                HeaderName::from_static("content-type"),
+                                HeaderValue::from_static("text/html"),
+                            )]),
+                            r#"<a href="https://foo.com/bar"></a>"#.as_bytes().to_vec(),
+                        ),
+                        build_stub_response(
+                            "https://foo.com/bar",
+                            StatusCode::NOT_FOUND,
+                            Default::default(),
+                            Default::default(),
+                        ),
+                    ]
+                    .into_iter()
+                    .collect(),
+                ),
+                "https://foo.com",
+                SiteConfig::default().set_severities([("http-status".to_owned(), severity)].into()),
+            )
+            .await
+            .unwrap()
+        }
+
+        #[tokio::test]
+        async fn report_error() {
+            let mut documents = validate_broken_link(Severity::Error).await;
+
+            assert_eq!(
+                collect_metrics(&mut documents).await,
+                (Metrics::new(1, 1), Metrics::new(0, 1))
+            );
+        }
+
+        #[tokio::test]
+        async fn report_warning() {
+            let mut documents = validate_broken_link(Severity::Warning).await;
+            let mut warnings = vec![];
+
+            while let Some(document) = documents.next().await {
+                let document = document.unwrap();
+
+                assert!(!document.metrics().has_error());
+
+                for element in document.elements() {
+                    assert_eq!(element.results().len(), 0);
+                    warnings.extend(element.warnings().map(ToString::to_string));
+                }
+            }
+
+            assert_eq!(warnings, vec!["invalid status 404 Not Found".to_owned()]);
+        }
+
+        #[tokio::test]
+        async fn turn_off_error() {
+            let mut documents = validate_broken_link(Severity::Off).await;
+
+            while let Some(document) = documents.next().await {
+                assert_eq!(document.unwrap().elements().count(), 0);
+            }
+        }
     }
 
     #[tokio::test]
@@ -1801,6 +3923,170 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn validate_meta_refresh() {
+        let html_headers = HeaderMap::from_iter([(
+            HeaderName::from_static("content-type"),
+            HeaderValue::from_static("text/html"),
+        )]);
+        let mut documents = validate(
+            StubHttpClient::new(
+                [
+                    build_stub_response(
+                        "https://foo.com/robots.txt",
+                        StatusCode::OK,
+                        Default::default(),
+                        Default::default(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com",
+                        StatusCode::OK,
+                        html_headers.clone(),
+                        indoc!(
+                            r#"
+                            <meta http-equiv="refresh" content="0; url=/bar" />
+                            "#
+                        )
+                        .as_bytes()
+                        .into(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com/bar",
+                        StatusCode::OK,
+                        html_headers,
+                        r#"<a href="https://foo.com/baz"/>"#.as_bytes().to_vec(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com/baz",
+                        StatusCode::NOT_FOUND,
+                        Default::default(),
+                        Default::default(),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            "https://foo.com",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            collect_element_errors(&mut documents).await,
+            vec![(
+                "a".into(),
+                vec![("href".into(), "https://foo.com/baz".into())],
+                "invalid status 404 Not Found".into()
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn report_meta_refresh() {
+        let html_headers = HeaderMap::from_iter([(
+            HeaderName::from_static("content-type"),
+            HeaderValue::from_static("text/html"),
+        )]);
+        let mut documents = validate_with_site(
+            StubHttpClient::new(
+                [
+                    build_stub_response(
+                        "https://foo.com/robots.txt",
+                        StatusCode::OK,
+                        Default::default(),
+                        Default::default(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com",
+                        StatusCode::OK,
+                        html_headers.clone(),
+                        indoc!(
+                            r#"
+                            <meta http-equiv="refresh" content="0; url=/bar" />
+                            <meta http-equiv="Refresh" content="5" />
+                            "#
+                        )
+                        .as_bytes()
+                        .into(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com/bar",
+                        StatusCode::OK,
+                        html_headers,
+                        Default::default(),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            "https://foo.com",
+            SiteConfig::default()
+                .set_severities([("meta-refresh".to_owned(), crate::Severity::Error)].into())
+                .set_validation(ValidationConfig::default().set_meta_refresh(true)),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            collect_element_errors(&mut documents).await,
+            vec![(
+                "meta".into(),
+                vec![
+                    ("content".into(), "0; url=/bar".into()),
+                    ("http-equiv".into(), "refresh".into()),
+                ],
+                "meta refresh".into()
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn report_meta_refresh_as_warning() {
+        let mut documents = validate_with_site(
+            StubHttpClient::new(
+                [
+                    build_stub_response(
+                        "https://foo.com/robots.txt",
+                        StatusCode::OK,
+                        Default::default(),
+                        Default::default(),
+                    ),
+                    build_stub_response(
+                        "https://foo.com",
+                        StatusCode::OK,
+                        HeaderMap::from_iter([(
+                            HeaderName::from_static("content-type"),
+                            HeaderValue::from_static("text/html"),
+                        )]),
+                        r#"<meta http-equiv="refresh" content="0; url=data:,foo" />"#
+                            .as_bytes()
+                            .into(),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            "https://foo.com",
+            SiteConfig::default()
+                .set_validation(ValidationConfig::default().set_meta_refresh(true)),
+        )
+        .await
+        .unwrap();
+        let mut warnings = vec![];
+
+        while let Some(document) = documents.next().await {
+            let document = document.unwrap();
+
+            assert!(!document.metrics().has_error());
+
+            for element in document.elements() {
+                warnings.extend(element.warnings().map(ToString::to_string));
+            }
+        }
+
+        assert_eq!(warnings, vec!["meta refresh".to_owned()]);
+    }
+
     #[tokio::test]
     async fn validate_document_not_belonging_to_roots() {
         let html_headers = HeaderMap::from_iter([(
@@ -2242,6 +4528,137 @@ mod tests {
         );
     }
 
+    async fn validate_with_method(
+        method: RequestMethod,
+    ) -> Vec<(String, Vec<(String, String)>, String)> {
+        let url = Url::parse("https://foo.com").unwrap();
+        let html_headers = HeaderMap::from_iter([(
+            HeaderName::from_static("content-type"),
+            HeaderValue::from_static("text/html"),
+        )]);
+        let mut documents = WebValidator::new(
+            HttpClient::new(
+                StubHttpClient::new(
+                    [
+                        build_stub_response(
+                            "https://foo.com/robots.txt",
+                            StatusCode::OK,
+                            Default::default(),
+                            Default::default(),
+                        ),
+                        build_stub_response(
+                            url.as_str(),
+                            StatusCode::OK,
+                            html_headers.clone(),
+                            indoc!(
+                                r#"
+                                <a href="https://bar.com/foo"/>
+                                <a href="https://bar.com/bar"/>
+                                <a href="https://bar.com/baz"/>
+                                <a href="https://bar.com/qux#foo"/>
+                                "#
+                            )
+                            .as_bytes()
+                            .to_vec(),
+                        ),
+                        build_stub_response(
+                            "https://bar.com/robots.txt",
+                            StatusCode::OK,
+                            Default::default(),
+                            Default::default(),
+                        ),
+                        build_stub_response(
+                            "https://bar.com/bar",
+                            StatusCode::OK,
+                            Default::default(),
+                            Default::default(),
+                        ),
+                        build_stub_response(
+                            "https://bar.com/baz",
+                            StatusCode::OK,
+                            Default::default(),
+                            Default::default(),
+                        ),
+                        build_stub_response(
+                            "https://bar.com/qux",
+                            StatusCode::OK,
+                            html_headers,
+                            r#"<div id="foo"/>"#.as_bytes().to_vec(),
+                        ),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )
+                .set_head_results(
+                    [
+                        build_stub_response(
+                            "https://bar.com/robots.txt",
+                            StatusCode::OK,
+                            Default::default(),
+                            Default::default(),
+                        ),
+                        build_stub_response(
+                            "https://bar.com/foo",
+                            StatusCode::OK,
+                            Default::default(),
+                            Default::default(),
+                        ),
+                        build_stub_response(
+                            "https://bar.com/bar",
+                            StatusCode::METHOD_NOT_ALLOWED,
+                            Default::default(),
+                            Default::default(),
+                        ),
+                        build_stub_response(
+                            "https://bar.com/baz",
+                            StatusCode::NOT_FOUND,
+                            Default::default(),
+                            Default::default(),
+                        ),
+                    ]
+                    .into_iter()
+                    .collect(),
+                ),
+                StubTimer::new(),
+                Box::new(MokaCache::new(0)),
+            ),
+            DocumentParser::new(MokaCache::new(0)),
+        )
+        .validate(&Config::new(
+            vec![url.as_str().into()],
+            SiteConfig::default().set_method(method).into(),
+            [(
+                url.host_str().unwrap_or_default().into(),
+                [("".into(), SiteConfig::default().set_recursive(true).into())]
+                    .into_iter()
+                    .collect(),
+            )]
+            .into_iter()
+            .collect(),
+        ))
+        .await
+        .unwrap();
+
+        collect_element_errors(&mut documents).await
+    }
+
+    #[tokio::test]
+    async fn validate_links_with_head_requests() {
+        assert_eq!(
+            validate_with_method(RequestMethod::Head).await,
+            vec![(
+                "a".into(),
+                vec![("href".into(), "https://bar.com/baz".into())],
+                "invalid status 404 Not Found".into()
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn validate_links_with_automatic_method() {
+        assert_eq!(validate_with_method(RequestMethod::Auto).await, vec![]);
+    }
+
     #[tokio::test]
     async fn validate_ignored_link() {
         let url = Url::parse("https://foo.com").unwrap();
@@ -2374,70 +4791,6 @@ mod tests {
         );
     }
 
-    mod srcset {
-        use super::*;
-        use pretty_assertions::assert_eq;
-
-        #[test]
-        fn parse_url() {
-            assert_eq!(
-                WebValidator::parse_srcset("/foo.png").collect::<Vec<_>>(),
-                ["/foo.png"]
-            );
-        }
-
-        #[test]
-        fn parse_url_with_descriptor() {
-            assert_eq!(
-                WebValidator::parse_srcset("/foo.png 2x").collect::<Vec<_>>(),
-                ["/foo.png"]
-            );
-        }
-
-        #[test]
-        fn parse_multiple_urls() {
-            assert_eq!(
-                WebValidator::parse_srcset("/foo.png, /bar.png 2x, /baz.png 800w")
-                    .collect::<Vec<_>>(),
-                ["/foo.png", "/bar.png", "/baz.png"]
-            );
-        }
-
-        #[test]
-        fn skip_empty_entry() {
-            assert_eq!(
-                WebValidator::parse_srcset("/foo.png,, /bar.png").collect::<Vec<_>>(),
-                ["/foo.png", "/bar.png"]
-            );
-        }
-
-        #[test]
-        fn skip_trailing_comma() {
-            assert_eq!(
-                WebValidator::parse_srcset("/foo.png,").collect::<Vec<_>>(),
-                ["/foo.png"]
-            );
-        }
-
-        #[test]
-        fn parse_data_url() {
-            assert_eq!(
-                WebValidator::parse_srcset("data:image/png;base64,abc 2x, /bar.png")
-                    .collect::<Vec<_>>(),
-                ["data:image/png;base64,abc", "/bar.png"]
-            );
-        }
-
-        #[test]
-        fn parse_urls_with_width_descriptors() {
-            assert_eq!(
-                WebValidator::parse_srcset("small.jpg 500w, medium.jpg 1000w, large.jpg 1500w")
-                    .collect::<Vec<_>>(),
-                ["small.jpg", "medium.jpg", "large.jpg"]
-            );
-        }
-    }
-
     mod css {
         use super::*;
         use pretty_assertions::assert_eq;
@@ -3565,6 +5918,52 @@ mod tests {
             );
         }
 
+        #[tokio::test]
+        async fn suppress_invalid_svg_content() {
+            let mut documents = validate_svg_content(
+                StubHttpClient::new(
+                    [
+                        build_stub_response(
+                            "https://foo.com/robots.txt",
+                            StatusCode::OK,
+                            Default::default(),
+                            Default::default(),
+                        ),
+                        build_stub_response(
+                            "https://foo.com",
+                            StatusCode::OK,
+                            HeaderMap::from_iter([(
+                                HeaderName::from_static("content-type"),
+                                HeaderValue::from_static("image/svg+xml"),
+                            )]),
+                            indoc!(
+                                r#"
+                                <svg xmlns="http://www.w3.org/2000/svg">
+                                    <circle foo="bar" data-muffy-ignore="markup" />
+                                    <linearGradient><stop /></linearGradient>
+                                    <!-- muffy-ignore-next-line markup -->
+                                    <invalid />
+                                </svg>
+                                "#
+                            )
+                            .as_bytes()
+                            .to_vec(),
+                        ),
+                    ]
+                    .into_iter()
+                    .collect(),
+                ),
+                "https://foo.com",
+            )
+            .await
+            .unwrap();
+
+            assert_eq!(
+                collect_errors(&mut documents).await,
+                ["invalid children: invalid (not allowed)".into()].into()
+            );
+        }
+
         #[tokio::test]
         async fn validate_invalid_html_element_in_svg_content() {
             let mut documents = validate_svg_content(
@@ -4768,6 +7167,236 @@ mod tests {
         }
     }
 
+    mod mathml {
+        use super::*;
+        use crate::http_client::{BareResponse, HttpClientError};
+        use pretty_assertions::assert_eq;
+
+        fn build_page_response(body: &str) -> (String, Result<BareResponse, HttpClientError>) {
+            build_stub_response(
+                "https://foo.com",
+                StatusCode::OK,
+                HeaderMap::from_iter([(
+                    HeaderName::from_static("content-type"),
+                    HeaderValue::from_static("text/html"),
+                )]),
+                body.as_bytes().to_vec(),
+            )
+        }
+
+        async fn validate_mathml_content(
+            client: impl BareHttpClient + 'static,
+            url: &str,
+            html: Option<MarkupConfig>,
+            mathml: Option<MarkupConfig>,
+        ) -> Result<impl Stream<Item = Result<DocumentOutput, Error>>, Error> {
+            validate_with_site(
+                client,
+                url,
+                SiteConfig::default().set_validation(
+                    crate::ValidationConfig::default()
+                        .set_html(html)
+                        .set_mathml(mathml),
+                ),
+            )
+            .await
+        }
+
+        #[tokio::test]
+        async fn validate_valid_data_mathml_content() {
+            let mut documents = validate_mathml_content(
+                StubHttpClient::new(
+                    [
+                        build_stub_response(
+                            "https://foo.com/robots.txt",
+                            StatusCode::OK,
+                            Default::default(),
+                            Default::default(),
+                        ),
+                        build_page_response(
+                            r#"<a href="data:application/mathml+xml,<math xmlns='http://www.w3.org/1998/Math/MathML'><mfrac><mi>x</mi><mn>1</mn></mfrac></math>"/>"#,
+                        ),
+                    ]
+                    .into_iter()
+                    .collect(),
+                ),
+                "https://foo.com",
+                None,
+                Some(MarkupConfig::default()),
+            )
+            .await
+            .unwrap();
+
+            assert_eq!(collect_errors(&mut documents).await, Default::default());
+        }
+
+        #[tokio::test]
+        async fn validate_invalid_data_mathml_content() {
+            let mut documents = validate_mathml_content(
+                StubHttpClient::new(
+                    [
+                        build_stub_response(
+                            "https://foo.com/robots.txt",
+                            StatusCode::OK,
+                            Default::default(),
+                            Default::default(),
+                        ),
+                        build_page_response(
+                            r#"<a href="data:application/mathml+xml,<math xmlns='http://www.w3.org/1998/Math/MathML'><mi foo='bar'/></math>"/>"#,
+                        ),
+                    ]
+                    .into_iter()
+                    .collect(),
+                ),
+                "https://foo.com",
+                None,
+                Some(MarkupConfig::default()),
+            )
+            .await
+            .unwrap();
+
+            assert_eq!(
+                collect_errors(&mut documents).await,
+                ["invalid attributes: foo (not allowed)".into()].into()
+            );
+        }
+
+        #[tokio::test]
+        async fn report_invalid_root_element_in_data_mathml() {
+            let mut documents = validate_mathml_content(
+                StubHttpClient::new(
+                    [
+                        build_stub_response(
+                            "https://foo.com/robots.txt",
+                            StatusCode::OK,
+                            Default::default(),
+                            Default::default(),
+                        ),
+                        build_page_response(
+                            r#"<a href="data:application/mathml+xml,<svg xmlns='http://www.w3.org/2000/svg'/>"/>"#,
+                        ),
+                    ]
+                    .into_iter()
+                    .collect(),
+                ),
+                "https://foo.com",
+                None,
+                Some(MarkupConfig::default()),
+            )
+            .await
+            .unwrap();
+
+            assert_eq!(
+                collect_errors(&mut documents).await,
+                [
+                    "namespace expected http://www.w3.org/1998/Math/MathML but got http://www.w3.org/2000/svg".into(),
+                    "root element expected math but got svg".into(),
+                    "unknown tag \"svg\"".into(),
+                ]
+                .into()
+            );
+        }
+
+        #[tokio::test]
+        async fn validate_math_in_html_with_html_config() {
+            let mut documents = validate_mathml_content(
+                StubHttpClient::new(
+                    [
+                        build_stub_response(
+                            "https://foo.com/robots.txt",
+                            StatusCode::OK,
+                            Default::default(),
+                            Default::default(),
+                        ),
+                        build_page_response(concat!(
+                            "<!DOCTYPE html>",
+                            "<html><head><title>foo</title></head><body>",
+                            r#"<math><mi foo="bar">x</mi></math>"#,
+                            "</body></html>"
+                        )),
+                    ]
+                    .into_iter()
+                    .collect(),
+                ),
+                "https://foo.com",
+                Some(MarkupConfig::default()),
+                None,
+            )
+            .await
+            .unwrap();
+
+            assert_eq!(
+                collect_errors(&mut documents).await,
+                ["invalid attributes: foo (not allowed)".into()].into()
+            );
+        }
+
+        #[tokio::test]
+        async fn validate_math_in_html_with_mathml_config() {
+            let mut documents = validate_mathml_content(
+                StubHttpClient::new(
+                    [
+                        build_stub_response(
+                            "https://foo.com/robots.txt",
+                            StatusCode::OK,
+                            Default::default(),
+                            Default::default(),
+                        ),
+                        build_page_response(concat!(
+                            "<!DOCTYPE html>",
+                            "<html><head><title>foo</title></head><body>",
+                            r#"<math><mi foo="bar">x</mi></math>"#,
+                            "</body></html>"
+                        )),
+                    ]
+                    .into_iter()
+                    .collect(),
+                ),
+                "https://foo.com",
+                Some(MarkupConfig::default()),
+                Some(MarkupConfig::new(
+                    vec![Regex::new("^foo$").unwrap()],
+                    Default::default(),
+                )),
+            )
+            .await
+            .unwrap();
+
+            assert_eq!(collect_errors(&mut documents).await, Default::default());
+        }
+
+        #[tokio::test]
+        async fn validate_math_in_html_with_html_extensions() {
+            let mut documents = validate_mathml_content(
+                StubHttpClient::new(
+                    [
+                        build_stub_response(
+                            "https://foo.com/robots.txt",
+                            StatusCode::OK,
+                            Default::default(),
+                            Default::default(),
+                        ),
+                        build_page_response(concat!(
+                            "<!DOCTYPE html>",
+                            "<html><head><title>foo</title></head><body>",
+                            r#"<math role="math"><mtext><b>x</b></mtext></math>"#,
+                            "</body></html>"
+                        )),
+                    ]
+                    .into_iter()
+                    .collect(),
+                ),
+                "https://foo.com",
+                Some(MarkupConfig::default()),
+                Some(MarkupConfig::default()),
+            )
+            .await
+            .unwrap();
+
+            assert_eq!(collect_errors(&mut documents).await, Default::default());
+        }
+    }
+
     mod content_type {
         use super::*;
         use pretty_assertions::assert_eq;
@@ -4809,6 +7438,15 @@ mod tests {
             );
         }
 
+        #[test]
+        fn accept_mathml() {
+            assert_eq!(
+                WebValidator::validate_document_type(&response("application/mathml+xml"), None)
+                    .unwrap(),
+                Some(DocumentType::MathMl)
+            );
+        }
+
         #[test]
         fn accept_robots_with_charset_parameter() {
             assert_eq!(
@@ -0,0 +1,64 @@
+use super::suppression::LineSuppressions;
+use alloc::sync::Arc;
+use core::cell::RefCell;
+use std::collections::HashSet;
+use url::Url;
+
+pub struct DocumentContext {
+    base: Arc<Url>,
+    site: Arc<Url>,
+    suppressions: LineSuppressions,
+    defined_ids: HashSet<String>,
+    defined_fragments: HashSet<String>,
+    ids: RefCell<HashSet<String>>,
+}
+
+impl DocumentContext {
+    pub fn new(base: Arc<Url>, site: Arc<Url>, suppressions: LineSuppressions) -> Self {
+        Self {
+            base,
+            site,
+            suppressions,
+            defined_ids: Default::default(),
+            defined_fragments: Default::default(),
+            ids: Default::default(),
+        }
+    }
+
+    pub fn set_defined_ids(mut self, ids: HashSet<String>) -> Self {
+        self.defined_ids = ids;
+        self
+    }
+
+    pub fn set_defined_fragments(mut self, fragments: HashSet<String>) -> Self {
+        self.defined_fragments = fragments;
+        self
+    }
+
+    pub const fn base(&self) -> &Arc<Url> {
+        &self.base
+    }
+
+    pub const fn site(&self) -> &Arc<Url> {
+        &self.site
+    }
+
+    pub const fn suppressions(&self) -> &LineSuppressions {
+        &self.suppressions
+    }
+
+    /// Returns `true` if an ID is defined anywhere in a document.
+    pub fn has_id(&self, id: &str) -> bool {
+        self.defined_ids.contains(id)
+    }
+
+    /// Returns `true` if a fragment is defined by an ID or a name anywhere in a
+    /// document.
+    pub fn has_fragment(&self, fragment: &str) -> bool {
+        self.defined_fragments.contains(fragment)
+    }
+
+    pub fn insert_id(&self, id: &str) -> bool {
+        self.ids.borrow_mut().insert(id.into())
+    }
+}
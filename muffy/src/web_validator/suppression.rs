@@ -0,0 +1,280 @@
+use alloc::collections::BTreeSet;
+use core::mem::take;
+use muffy_document::document::{Element, Node};
+use std::collections::HashMap;
+
+const IGNORE_ATTRIBUTE: &str = "data-muffy-ignore";
+const IGNORE_NEXT_LINE_DIRECTIVE: &str = "muffy-ignore-next-line";
+const LINK_KIND: &str = "link";
+const MARKUP_KIND: &str = "markup";
+
+/// Kinds of validation suppressed for an element.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Suppression {
+    link: bool,
+    markup: bool,
+    unknown_kinds: BTreeSet<String>,
+}
+
+impl Suppression {
+    const ALL: Self = Self {
+        link: true,
+        markup: true,
+        unknown_kinds: BTreeSet::new(),
+    };
+
+    /// Returns whether link validation is suppressed.
+    pub const fn link(&self) -> bool {
+        self.link
+    }
+
+    /// Returns whether markup validation is suppressed.
+    pub const fn markup(&self) -> bool {
+        self.markup
+    }
+
+    /// Returns unknown kinds of validation.
+    pub fn unknown_kinds(&self) -> impl Iterator<Item = &str> {
+        self.unknown_kinds.iter().map(String::as_str)
+    }
+
+    /// Merges two suppressions.
+    pub fn merge(mut self, other: Self) -> Self {
+        self.link |= other.link;
+        self.markup |= other.markup;
+        self.unknown_kinds.extend(other.unknown_kinds);
+        self
+    }
+
+    /// Parses a suppression from an attribute of an element.
+    pub fn from_element(element: &Element) -> Self {
+        element
+            .attributes()
+            .find(|(name, _)| *name == IGNORE_ATTRIBUTE)
+            .map(|(_, value)| Self::parse(value))
+            .unwrap_or_default()
+    }
+
+    /// Parses a suppression from a comment.
+    pub fn from_comment(text: &str) -> Option<Self> {
+        let kinds = text.trim().strip_prefix(IGNORE_NEXT_LINE_DIRECTIVE)?;
+
+        (kinds.is_empty() || kinds.starts_with(char::is_whitespace)).then(|| Self::parse(kinds))
+    }
+
+    // An empty list of kinds suppresses every kind of validation.
+    fn parse(kinds: &str) -> Self {
+        let mut suppression = Self::default();
+        let mut empty = true;
+
+        for kind in kinds
+            .split(|character: char| character.is_whitespace() || character == ',')
+            .filter(|kind| !kind.is_empty())
+        {
+            empty = false;
+
+            match kind {
+                LINK_KIND => suppression.link = true,
+                MARKUP_KIND => suppression.markup = true,
+                _ => {
+                    suppression.unknown_kinds.insert(kind.into());
+                }
+            }
+        }
+
+        if empty { Self::ALL } else { suppression }
+    }
+}
+
+/// Suppressions of elements on lines next to suppression comments.
+#[derive(Debug, Default)]
+pub struct LineSuppressions(HashMap<usize, Suppression>);
+
+impl LineSuppressions {
+    /// Collects suppressions from comments in nodes.
+    pub fn new<'a>(nodes: impl IntoIterator<Item = &'a Node>) -> Self {
+        let mut suppressions = Self::default();
+
+        for node in nodes {
+            suppressions.collect(node);
+        }
+
+        suppressions
+    }
+
+    /// Returns a suppression of an element.
+    pub fn get(&self, element: &Element) -> Suppression {
+        element
+            .span()
+            .and_then(|span| self.0.get(&span.start().line()))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn collect(&mut self, node: &Node) {
+        match node {
+            Node::Comment(comment) => {
+                if let Some(span) = comment.span()
+                    && let Some(suppression) = Suppression::from_comment(comment.text())
+                {
+                    let entry = self.0.entry(span.end().line() + 1).or_default();
+                    *entry = take(entry).merge(suppression);
+                }
+            }
+            Node::Element(element) => {
+                for node in element.children() {
+                    self.collect(node);
+                }
+            }
+            Node::Text(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use muffy_document::html;
+    use pretty_assertions::assert_eq;
+
+    fn collect_links<'a>(node: &'a Node, elements: &mut Vec<&'a Element>) {
+        if let Node::Element(element) = node {
+            if element.name() == "a" {
+                elements.push(element);
+            }
+
+            for node in element.children() {
+                collect_links(node, elements);
+            }
+        }
+    }
+
+    #[test]
+    fn parse_comment() {
+        assert_eq!(
+            Suppression::from_comment(" muffy-ignore-next-line link "),
+            Some(Suppression {
+                link: true,
+                markup: false,
+                unknown_kinds: Default::default(),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_comment_with_multiple_kinds() {
+        assert_eq!(
+            Suppression::from_comment("muffy-ignore-next-line link, markup"),
+            Some(Suppression::ALL)
+        );
+    }
+
+    #[test]
+    fn parse_comment_without_kind() {
+        assert_eq!(
+            Suppression::from_comment("muffy-ignore-next-line"),
+            Some(Suppression::ALL)
+        );
+    }
+
+    #[test]
+    fn parse_comment_with_unknown_kinds() {
+        assert_eq!(
+            Suppression::from_comment("muffy-ignore-next-line link, foo bar"),
+            Some(Suppression {
+                link: true,
+                markup: false,
+                unknown_kinds: ["bar".into(), "foo".into()].into(),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_comment_with_only_unknown_kinds() {
+        assert_eq!(
+            Suppression::from_comment("muffy-ignore-next-line foo"),
+            Some(Suppression {
+                link: false,
+                markup: false,
+                unknown_kinds: ["foo".into()].into(),
+            })
+        );
+    }
+
+    #[test]
+    fn merge_unknown_kinds() {
+        assert_eq!(
+            Suppression::from_comment("muffy-ignore-next-line foo")
+                .unwrap()
+                .merge(Suppression::from_comment("muffy-ignore-next-line markup, bar").unwrap())
+                .unknown_kinds()
+                .collect::<Vec<_>>(),
+            ["bar", "foo"]
+        );
+    }
+
+    #[test]
+    fn parse_non_directive_comment() {
+        assert_eq!(Suppression::from_comment("foo"), None);
+        assert_eq!(Suppression::from_comment("muffy-ignore-next-lines"), None);
+    }
+
+    #[test]
+    fn parse_attribute() {
+        assert_eq!(
+            Suppression::from_element(&Element::new(
+                "a".into(),
+                vec![("data-muffy-ignore".into(), "markup".into())],
+                vec![],
+            )),
+            Suppression {
+                link: false,
+                markup: true,
+                unknown_kinds: Default::default(),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_empty_attribute() {
+        assert_eq!(
+            Suppression::from_element(&Element::new(
+                "a".into(),
+                vec![("data-muffy-ignore".into(), "".into())],
+                vec![],
+            )),
+            Suppression::ALL
+        );
+    }
+
+    #[test]
+    fn suppress_next_line() {
+        let document = html::parse(concat!(
+            "<!-- muffy-ignore-next-line link -->\n",
+            "<a href=\"/foo\"></a>\n",
+            "<a href=\"/bar\"></a>\n",
+        ))
+        .unwrap();
+        let suppressions = LineSuppressions::new(document.children());
+        let mut elements = vec![];
+
+        for node in document.children() {
+            collect_links(node, &mut elements);
+        }
+
+        assert_eq!(
+            elements
+                .into_iter()
+                .map(|element| suppressions.get(element))
+                .collect::<Vec<_>>(),
+            vec![
+                Suppression {
+                    link: true,
+                    markup: false,
+                    unknown_kinds: Default::default(),
+                },
+                Suppression::default(),
+            ]
+        );
+    }
+}
@@ -0,0 +1,65 @@
+use crate::config::{MarkupConfig, ValidationConfig};
+use alloc::collections::BTreeMap;
+use muffy_document::document::Element;
+use muffy_validation::{CustomElement, MarkupError};
+use regex::Regex;
+
+type ElementValidator = fn(
+    &Element,
+    &[&Element],
+    &[Regex],
+    &[Regex],
+    &BTreeMap<String, CustomElement>,
+) -> Result<(), MarkupError>;
+
+pub struct XmlLanguage {
+    namespace: &'static str,
+    root_element: &'static str,
+    config: fn(&ValidationConfig) -> Option<&MarkupConfig>,
+    validate: ElementValidator,
+}
+
+impl XmlLanguage {
+    pub const fn new(
+        namespace: &'static str,
+        root_element: &'static str,
+        config: fn(&ValidationConfig) -> Option<&MarkupConfig>,
+        validate: ElementValidator,
+    ) -> Self {
+        Self {
+            namespace,
+            root_element,
+            config,
+            validate,
+        }
+    }
+
+    pub const fn namespace(&self) -> &'static str {
+        self.namespace
+    }
+
+    pub const fn root_element(&self) -> &'static str {
+        self.root_element
+    }
+
+    pub fn config<'a>(&self, config: &'a ValidationConfig) -> Option<&'a MarkupConfig> {
+        (self.config)(config)
+    }
+
+    pub fn validate(
+        &self,
+        element: &Element,
+        ancestors: &[&Element],
+        ignored_attributes: &[Regex],
+        ignored_elements: &[Regex],
+        custom_elements: &BTreeMap<String, CustomElement>,
+    ) -> Result<(), MarkupError> {
+        (self.validate)(
+            element,
+            ancestors,
+            ignored_attributes,
+            ignored_elements,
+            custom_elements,
+        )
+    }
+}